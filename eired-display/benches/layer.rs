@@ -0,0 +1,220 @@
+//! Benchmarks of building a 200x60 [Layer] and composing [Canvas], and scrolling a 10,000 lines
+//! document by [Viewport].
+//!
+//! Compares with `NaiveLayer` that resolves conflicts by re-pushing all spans, the same as before
+//! the row index.
+//!
+//! Run by `cargo bench -p eired-display`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use eired_display::{Annot, Annotate, Canvas, Layer, Span, Viewport};
+
+const WIDTH: u16 = 200;
+const HEIGHT: u16 = 60;
+const WORD: u16 = 10;
+const DOCUMENT_LINES: u16 = 10_000;
+
+#[derive(Default)]
+struct NaiveLayer {
+    spans: Vec<Annot<Span>>,
+}
+
+impl NaiveLayer {
+    fn resolve_conflict(base: Annot<Span>, overlap: &Annot<Span>) -> Vec<Annot<Span>> {
+        if !base.is_conflict(overlap) {
+            return vec![base];
+        }
+
+        let (overlap_begin, overlap_end) = (overlap.base_pos().0, overlap.outer_apex_pos().0);
+        let (base_x, base_y) = base.base_pos();
+        let base_end = base.outer_apex_pos().0;
+        let mut solved = vec![];
+
+        if base_x < overlap_begin {
+            let left = base.inner().split_by(&[overlap_begin - base_x])[0].take();
+
+            solved.extend(left.map(|span| span.annotate((base_x, base_y))));
+        }
+
+        if overlap_end < base_end {
+            let right = base.inner().split_by(&[overlap_end - base_x])[1].take();
+
+            solved.extend(right.map(|span| span.annotate((overlap_end, base_y))));
+        }
+
+        solved
+    }
+
+    fn push_span_write(&mut self, span: Annot<Span>) {
+        let mut tmp = vec![];
+
+        while let Some(i_span) = self.spans.pop() {
+            tmp.extend(Self::resolve_conflict(i_span, &span));
+        }
+
+        self.spans.extend(tmp.into_iter().filter(|s| !s.is_empty()));
+        self.spans.push(span);
+    }
+}
+
+/// Words of a full screen by row major order.
+fn words() -> Vec<Annot<Span>> {
+    (0..HEIGHT)
+        .flat_map(|y| {
+            (0..WIDTH / WORD).map(move |x| Span::from("0123456789").annotate((x * WORD, y)))
+        })
+        .collect()
+}
+
+/// Words that shifted by half a word, conflicts with 2 words of `words`.
+fn shifted_words() -> Vec<Annot<Span>> {
+    (0..HEIGHT)
+        .flat_map(|y| {
+            (0..WIDTH / WORD - 1)
+                .map(move |x| Span::from("abcdefghij").annotate((x * WORD + WORD / 2, y)))
+        })
+        .collect()
+}
+
+fn bench<F: FnMut()>(name: &str, mut f: F) -> Duration {
+    let iters = 5;
+
+    f();
+
+    let begin = Instant::now();
+
+    for _ in 0..iters {
+        f();
+    }
+
+    let elapsed = begin.elapsed() / iters;
+
+    println!("{name:<40} {elapsed:>12.3?}");
+
+    elapsed
+}
+
+fn main() {
+    let words = words();
+    let shifted = shifted_words();
+
+    println!("{WIDTH}x{HEIGHT} screen, {} spans", words.len());
+
+    let mut layer = Layer::default();
+    let mut naive = NaiveLayer::default();
+
+    for span in words.iter().chain(shifted.iter()) {
+        layer.push_span_write(span.clone());
+        naive.push_span_write(span.clone());
+    }
+
+    naive
+        .spans
+        .sort_by_key(|s| (s.base_pos().1, s.base_pos().0));
+
+    assert_eq!(layer.inner(), naive.spans, "results differ from NaiveLayer");
+
+    let indexed = bench("Layer::push_span_write", || {
+        let mut layer = Layer::default();
+
+        for span in words.iter().chain(shifted.iter()) {
+            layer.push_span_write(span.clone());
+        }
+
+        black_box(layer);
+    });
+    let naive = bench("NaiveLayer::push_span_write", || {
+        let mut layer = NaiveLayer::default();
+
+        for span in words.iter().chain(shifted.iter()) {
+            layer.push_span_write(span.clone());
+        }
+
+        black_box(layer.spans);
+    });
+
+    println!(
+        "{:<40} {:>11.1}x",
+        "speedup",
+        naive.as_secs_f64() / indexed.as_secs_f64()
+    );
+
+    bench("Layer::push_span_fixed", || {
+        let mut layer = Layer::default();
+
+        for span in words.iter().chain(shifted.iter()) {
+            layer.push_span_fixed(span.clone());
+        }
+
+        black_box(layer);
+    });
+    bench("Layer::push_span_only_valid", || {
+        let mut layer = Layer::default();
+
+        for span in words.iter().chain(shifted.iter()) {
+            layer.push_span_only_valid(span.clone());
+        }
+
+        black_box(layer);
+    });
+
+    let mut screen = Layer::default();
+
+    for span in words.iter() {
+        screen.push_span_write(span.clone());
+    }
+
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, screen.annotate((0, 0)));
+    canvas.create_view();
+
+    bench("Canvas::create_view (1 cell changed)", || {
+        let mut cursor = Layer::default();
+
+        cursor.push_span_write(Span::from("_").annotate((0, 0)));
+        canvas.insert(1, cursor.annotate((WIDTH / 2, HEIGHT / 2)));
+
+        black_box(canvas.create_view());
+    });
+    bench("Canvas::view (1 cell changed)", || {
+        let mut cursor = Layer::default();
+
+        cursor.push_span_write(Span::from("_").annotate((0, 0)));
+        canvas.insert(1, cursor.annotate((WIDTH / 2, HEIGHT / 2)));
+
+        black_box(canvas.view());
+    });
+    bench("Canvas::create_view (invalidated)", || {
+        canvas.invalidate();
+
+        black_box(canvas.create_view());
+    });
+
+    let mut document = Layer::default();
+
+    for y in 0..DOCUMENT_LINES {
+        document.push_span_write(Span::from("0123456789".repeat(8)).annotate((0, y)));
+    }
+
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, document.annotate((0, 0)));
+
+    let mut viewport = Viewport::new(80, 24);
+
+    viewport.set_content_size(canvas.size());
+
+    bench("Viewport::create_view (scrolled)", || {
+        viewport.scroll_by((0, 24));
+
+        black_box(viewport.create_view(&canvas));
+    });
+    bench("Canvas::create_view (whole document)", || {
+        canvas.invalidate();
+
+        black_box(canvas.create_view());
+    });
+}
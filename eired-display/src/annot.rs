@@ -7,6 +7,7 @@ use crate::Rect;
 /// A simply struct, it only has `base-coords` and `inner`.
 /// - `base-coords` is reperesents the position on a terminal.
 ///   The "position" is able to apply with whatever absolute and relative.
+///   It may be signed for off-screen positions, see [`signed_pos`](Annot::signed_pos).
 /// - `inner` is just a target of annotation.
 ///   Can takes `&` and `&mut` by `inner_*` function, or `into_inner()` can take an ownership.
 ///
@@ -39,8 +40,8 @@ use crate::Rect;
 ///
 /// See: [Annotate]
 pub struct Annot<T> {
-    base_x: u16,
-    base_y: u16,
+    base_x: i32,
+    base_y: i32,
     inner: T,
 }
 
@@ -58,6 +59,22 @@ impl<T> Annot<T> {
     /// assert_eq!(annot.inner(), &Rect(1, 1));
     /// ```
     pub fn new(base: (u16, u16), inner: T) -> Self {
+        Self::new_signed((base.0 as i32, base.1 as i32), inner)
+    }
+
+    /// Wrap struct with signed annot, negative coords are left or above of the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annot;
+    /// # use eired_display::Rect;
+    /// let annot = Annot::new_signed((-3, 2), Rect(1, 1));
+    ///
+    /// assert_eq!(annot.signed_pos(), (-3, 2));
+    /// assert_eq!(annot.base_pos(), (0, 2));
+    /// ```
+    pub fn new_signed(base: (i32, i32), inner: T) -> Self {
         Self {
             base_x: base.0,
             base_y: base.1,
@@ -65,7 +82,7 @@ impl<T> Annot<T> {
         }
     }
 
-    /// Returns base position of annot.
+    /// Returns base position of annot, negative coords are clamped to `0`.
     ///
     /// # Examples
     ///
@@ -78,6 +95,22 @@ impl<T> Annot<T> {
     /// assert_eq!(annot.inner(), &Rect(1, 1));
     /// ```
     pub fn base_pos(&self) -> (u16, u16) {
+        (clamp_coord(self.base_x), clamp_coord(self.base_y))
+    }
+
+    /// Returns signed base position of annot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annotate;
+    /// use eired_display::Rect;
+    ///
+    /// let annot = Rect(1, 1).annotate_signed((-1, -2));
+    ///
+    /// assert_eq!(annot.signed_pos(), (-1, -2));
+    /// ```
+    pub fn signed_pos(&self) -> (i32, i32) {
         (self.base_x, self.base_y)
     }
 
@@ -132,6 +165,9 @@ impl<T> Annot<T> {
 
     /// Rebase annotated position.
     ///
+    /// Negative coords are clamped to `0` before `f`, use [`translate`](Annot::translate) to keep
+    /// them.
+    ///
     /// # Examples
     ///
     /// ```
@@ -147,7 +183,47 @@ impl<T> Annot<T> {
     /// assert_eq!(annot.base_pos(), (4, 7));
     /// ```
     pub fn rebase<F: Fn(&mut u16, &mut u16)>(&mut self, f: F) {
-        f(&mut self.base_x, &mut self.base_y);
+        let (mut x, mut y) = self.base_pos();
+
+        f(&mut x, &mut y);
+
+        self.base_x = x as i32;
+        self.base_y = y as i32;
+    }
+
+    /// Moves annotated position by signed `delta`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annot;
+    /// # use eired_display::Rect;
+    /// let mut annot = Annot::new((1, 4), Rect(1, 1));
+    ///
+    /// annot.translate((-3, 1));
+    ///
+    /// assert_eq!(annot.signed_pos(), (-2, 5));
+    /// ```
+    pub fn translate(&mut self, delta: (i32, i32)) {
+        self.base_x = self.base_x.saturating_add(delta.0);
+        self.base_y = self.base_y.saturating_add(delta.1);
+    }
+
+    /// Sets annotated position to signed `pos`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annot;
+    /// # use eired_display::Rect;
+    /// let mut annot = Annot::new_signed((-1, 4), Rect(1, 1));
+    ///
+    /// annot.set_signed_pos((i32::MAX, -2));
+    ///
+    /// assert_eq!(annot.signed_pos(), (i32::MAX, -2));
+    /// ```
+    pub fn set_signed_pos(&mut self, pos: (i32, i32)) {
+        (self.base_x, self.base_y) = pos;
     }
 }
 
@@ -227,12 +303,12 @@ impl<T: Annotate> Annot<T> {
     /// ```
     pub fn inner_apex_pos(&self) -> (u16, u16) {
         (
-            self.base_x + self.width().max(1) - 1,
-            self.base_y + self.height().max(1) - 1,
+            clamp_coord(self.base_x.saturating_add(self.width().max(1) as i32 - 1)),
+            clamp_coord(self.base_y.saturating_add(self.height().max(1) as i32 - 1)),
         )
     }
 
-    /// Returns upeer bound apex position of annot.
+    /// Returns upeer bound apex position of annot, negative coords are clamped to `0`.
     ///
     /// # Examples
     ///
@@ -256,7 +332,29 @@ impl<T: Annotate> Annot<T> {
     /// assert_eq!(rect.outer_apex_pos(), (15, 12));
     /// ```
     pub fn outer_apex_pos(&self) -> (u16, u16) {
-        (self.base_x + self.width(), self.base_y + self.height())
+        let (outer_x, outer_y) = self.signed_outer_apex_pos();
+
+        (clamp_coord(outer_x), clamp_coord(outer_y))
+    }
+
+    /// Returns signed upper bound apex position of annot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annotate;
+    /// use eired_display::Rect;
+    ///
+    /// let rect = Rect(10, 10).annotate_signed((-15, 2));
+    ///
+    /// assert_eq!(rect.signed_outer_apex_pos(), (-5, 12));
+    /// assert_eq!(rect.outer_apex_pos(), (0, 12));
+    /// ```
+    pub fn signed_outer_apex_pos(&self) -> (i32, i32) {
+        (
+            self.base_x.saturating_add(self.width() as i32),
+            self.base_y.saturating_add(self.height() as i32),
+        )
     }
 
     /// Returns `true` with conflicts is `self` and `other`.
@@ -294,10 +392,10 @@ impl<T: Annotate> Annot<T> {
             return false;
         }
 
-        let (self_base_x, self_base_y) = self.base_pos();
-        let (other_base_x, other_base_y) = other.base_pos();
-        let (self_outer_x, self_outer_y) = self.outer_apex_pos();
-        let (other_outer_x, other_outer_y) = other.outer_apex_pos();
+        let (self_base_x, self_base_y) = self.signed_pos();
+        let (other_base_x, other_base_y) = other.signed_pos();
+        let (self_outer_x, self_outer_y) = self.signed_outer_apex_pos();
+        let (other_outer_x, other_outer_y) = other.signed_outer_apex_pos();
 
         self_outer_x > other_base_x
             && other_outer_x > self_base_x
@@ -328,6 +426,40 @@ impl<T: Annotate> Annot<T> {
 
         self.is_conflict(&dummy)
     }
+
+    /// Returns the overlapped area of `self` and `other`, or `None` if not conflict.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annotate;
+    /// use eired_display::Rect;
+    ///
+    /// let rect = Rect(5, 5).annotate((0, 0));
+    ///
+    /// assert_eq!(rect.intersection(&Rect(5, 2).annotate((3, 1))), Some(Rect(2, 2).annotate((3, 1))));
+    /// assert_eq!(rect.intersection(&Rect(5, 5).annotate((5, 0))), None);
+    /// ```
+    pub fn intersection<A: Annotate>(&self, other: &Annot<A>) -> Option<Annot<Rect>> {
+        if !self.is_conflict(other) {
+            return None;
+        }
+
+        let (self_base_x, self_base_y) = self.signed_pos();
+        let (other_base_x, other_base_y) = other.signed_pos();
+        let (self_outer_x, self_outer_y) = self.signed_outer_apex_pos();
+        let (other_outer_x, other_outer_y) = other.signed_outer_apex_pos();
+        let (base_x, base_y) = (self_base_x.max(other_base_x), self_base_y.max(other_base_y));
+        let (outer_x, outer_y) = (
+            self_outer_x.min(other_outer_x),
+            self_outer_y.min(other_outer_y),
+        );
+
+        Some(
+            Rect((outer_x - base_x) as u16, (outer_y - base_y) as u16)
+                .annotate_signed((base_x, base_y)),
+        )
+    }
 }
 
 impl<T: Copy> Copy for Annot<T> {}
@@ -406,6 +538,29 @@ pub trait Annotate {
         Annot::new(root, self)
     }
 
+    /// Create new annot with signed root.
+    ///
+    /// Not recommended override this function.
+    /// Default implement is equals to [`Annot::new_signed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Annotate;
+    /// # use eired_display::Annot;
+    /// use eired_display::Rect;
+    ///
+    /// let rect = Rect(10, 10).annotate_signed((-1, 2));
+    ///
+    /// assert_eq!(rect, Annot::new_signed((-1, 2), Rect(10, 10)));
+    /// ```
+    fn annotate_signed(self, root: (i32, i32)) -> Annot<Self>
+    where
+        Self: Sized,
+    {
+        Annot::new_signed(root, self)
+    }
+
     /// Returns (`width`, `height`).
     ///
     /// # Examples
@@ -452,3 +607,8 @@ pub trait Annotate {
         self.get_size().1
     }
 }
+
+/// Clamps signed `coord` to the range of `u16`.
+fn clamp_coord(coord: i32) -> u16 {
+    coord.clamp(0, u16::MAX as i32) as u16
+}
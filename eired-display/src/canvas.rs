@@ -1,15 +1,40 @@
-use std::collections::BTreeMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 
-use crate::{Annot, Cell, Layer, View};
+use crate::{Annot, Annotate, Cell, Layer, Rect, View};
 
-#[derive(Default, PartialEq, Eq)]
+/// Dirty regions over this count are merged into the bounding rect.
+const MAX_DIRTY_REGIONS: usize = 16;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A stable handle of a layer in [Canvas].
+///
+/// A handle is returned on insertion, and keeps pointing the same layer while the layer is
+/// replaced or moved to other `z_index`. Handles are not reused in the canvas.
+pub struct LayerId(u64);
+
+impl Debug for LayerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LayerId").field(&self.0).finish()
+    }
+}
+
+#[derive(Default)]
 /// A canvas of non merged layers.
 ///
 /// Each layers in canvas has a `z_index`, keeps data and order as long as a `z_index` different else.
 /// On [`create_view`](Canvas::create_view) calls, stacks each layer from lower `z_index` then
 /// returns created view.
 ///
+/// Transparent parts of cells (see [`Blend`](crate::Blend)) are taken from lower layers.
+/// Cells outside of the clip rect are not stacked.
+///
+/// The last view is cached, and areas of changed layers are tracked as dirty regions.
+/// So [`create_view`](Canvas::create_view) only re-stacks the dirty regions, and
+/// [`view`](Canvas::view) borrows the cached view without copying it.
+/// The cache is kept in a [`RefCell`], so canvas is not [`Sync`].
+///
 /// # Examples
 ///
 /// ```
@@ -73,6 +98,28 @@ pub struct Canvas {
     width: u16,
     height: u16,
     layers: BTreeMap<usize, Annot<Layer>>,
+    hidden: BTreeSet<usize>,
+    opacities: BTreeMap<usize, u8>,
+    clip: Option<Annot<Rect>>,
+    ids: BTreeMap<usize, LayerId>,
+    names: BTreeMap<String, LayerId>,
+    next_id: u64,
+    cache: RefCell<Cache>,
+}
+
+/// A cached view and areas to re-stack on it.
+struct Cache {
+    view: View,
+    dirty: Vec<Annot<Rect>>,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self {
+            view: View::new(0, 0, vec![]),
+            dirty: vec![],
+        }
+    }
 }
 
 impl Canvas {
@@ -90,14 +137,54 @@ impl Canvas {
         self.layers.iter().collect::<Vec<_>>()
     }
 
-    fn apply_layer(&mut self, z_index: usize, layer: Annot<Layer>) {
-        let (layer_margin_x, layer_margin_y) = layer.base_pos();
+    fn apply_layer(&mut self, z_index: usize, layer: Annot<Layer>) -> LayerId {
+        let old_area = self.layers.get(&z_index).map(Self::layer_area);
+
+        if let Some(old_area) = old_area {
+            self.mark_dirty(old_area);
+        }
+
+        self.mark_dirty(Self::layer_area(&layer));
 
-        self.width = self.width.max(layer_margin_x + layer.width());
-        self.height = self.height.max(layer_margin_y + layer.height());
+        if !self.hidden.contains(&z_index) {
+            let (outer_x, outer_y) = Self::layer_extent(&layer);
+
+            self.width = self.width.max(outer_x);
+            self.height = self.height.max(outer_y);
+        }
 
         self.layers.insert(z_index, layer);
-        self.front = self.front.max(z_index + 1);
+        self.front = self.front.max(z_index.saturating_add(1));
+
+        if old_area.is_some() {
+            self.update_extent();
+        }
+
+        *self.ids.entry(z_index).or_insert_with(|| {
+            self.next_id += 1;
+
+            LayerId(self.next_id)
+        })
+    }
+
+    /// Recalculates `front` and the extent of visible layers, shrinks if layers are gone.
+    fn update_extent(&mut self) {
+        let (width, height) = self
+            .layers
+            .iter()
+            .filter(|(z_index, _)| !self.hidden.contains(z_index))
+            .map(|(_, layer)| Self::layer_extent(layer))
+            .fold((0, 0), |(width, height), (x, y)| {
+                (width.max(x), height.max(y))
+            });
+
+        self.width = width;
+        self.height = height;
+        self.front = self
+            .layers
+            .keys()
+            .next_back()
+            .map_or(0, |z_index| z_index.saturating_add(1));
     }
 
     /// Overlaps `layer` to top.
@@ -131,11 +218,13 @@ impl Canvas {
     /// assert_eq!(canvas.inner_vec()[1].0, &1);
     /// assert_eq!(canvas.inner_vec()[1].1.base_pos(), (2, 1));
     /// ```
-    pub fn overlap_layer(&mut self, layer: Annot<Layer>) {
-        self.apply_layer(self.front, layer);
+    pub fn overlap_layer(&mut self, layer: Annot<Layer>) -> LayerId {
+        self.apply_layer(self.front, layer)
     }
 
-    /// Insert `layer` to `z_index`.
+    /// Insert `layer` to `z_index`, returns the handle of `z_index`.
+    ///
+    /// A replaced layer keeps its handle.
     ///
     /// # Examples
     ///
@@ -157,8 +246,8 @@ impl Canvas {
     /// assert_eq!(canvas.inner_vec()[0].0, &5);
     /// assert_eq!(canvas.inner_vec()[0].1.base_pos(), (0, 0));
     /// ```
-    pub fn insert(&mut self, z_index: usize, layer: Annot<Layer>) {
-        self.apply_layer(z_index, layer);
+    pub fn insert(&mut self, z_index: usize, layer: Annot<Layer>) -> LayerId {
+        self.apply_layer(z_index, layer)
     }
 
     /// Merge `layer` to `z_index` if found `z_index` layer.
@@ -244,17 +333,563 @@ impl Canvas {
     /// assert_eq!(canvas.inner_vec()[1].1.width(), 1);
     /// assert_eq!(canvas.inner_vec()[1].1.height(), 1);
     /// ```
-    pub fn insert_or_merge(&mut self, z_index: usize, layer: Annot<Layer>) {
+    pub fn insert_or_merge(&mut self, z_index: usize, layer: Annot<Layer>) -> LayerId {
         if self.layers.contains_key(&z_index) {
             self.merge(z_index, layer);
+
+            self.ids[&z_index]
         } else {
-            self.insert(z_index, layer);
+            self.insert(z_index, layer)
+        }
+    }
+
+    /// Takes the layer of `z_index` out of canvas.
+    ///
+    /// The canvas extent shrinks to the remaining visible layers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XXX").annotate((0, 0)));
+    ///
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("POPUP").annotate((0, 0)));
+    ///
+    /// canvas.insert(1, layer.annotate((2, 2)));
+    ///
+    /// assert_eq!(canvas.create_view().get_size(), (7, 3));
+    ///
+    /// let popup = canvas.take(1).unwrap();
+    ///
+    /// assert_eq!(popup.base_pos(), (2, 2));
+    /// assert_eq!(canvas.create_view().get_size(), (3, 1));
+    /// assert!(canvas.take(1).is_none());
+    /// ```
+    pub fn take(&mut self, z_index: usize) -> Option<Annot<Layer>> {
+        let layer = self.layers.remove(&z_index)?;
+
+        self.mark_dirty(Self::layer_area(&layer));
+        self.hidden.remove(&z_index);
+        self.opacities.remove(&z_index);
+
+        if let Some(id) = self.ids.remove(&z_index) {
+            self.names.retain(|_, named| *named != id);
+        }
+
+        self.update_extent();
+
+        Some(layer)
+    }
+
+    /// Removes the layer of `z_index`, returns `true` was removed.
+    pub fn remove(&mut self, z_index: usize) -> bool {
+        self.take(z_index).is_some()
+    }
+
+    /// Hides the layer of `z_index`, returns `true` was found.
+    ///
+    /// Hidden layers are kept, but not stacked, hit and counted as the canvas extent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    /// use eired_display::Cell;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("X").annotate((0, 0)));
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("O").annotate((0, 0)));
+    /// canvas.insert(1, layer.annotate((0, 0)));
+    ///
+    /// assert!(canvas.hide(1));
+    /// assert!(!canvas.is_visible(1));
+    /// assert_eq!(canvas.create_view().get_line(0), &[Some(Cell::new('X'))]);
+    ///
+    /// assert!(canvas.show(1));
+    /// assert_eq!(canvas.create_view().get_line(0), &[Some(Cell::new('O'))]);
+    /// ```
+    pub fn hide(&mut self, z_index: usize) -> bool {
+        self.set_visible(z_index, false)
+    }
+
+    /// Shows the hidden layer of `z_index`, returns `true` was found.
+    pub fn show(&mut self, z_index: usize) -> bool {
+        self.set_visible(z_index, true)
+    }
+
+    /// Returns `true` was the layer of `z_index` found and not hidden.
+    pub fn is_visible(&self, z_index: usize) -> bool {
+        self.layers.contains_key(&z_index) && !self.hidden.contains(&z_index)
+    }
+
+    fn set_visible(&mut self, z_index: usize, is_visible: bool) -> bool {
+        let Some(layer) = self.layers.get(&z_index) else {
+            return false;
+        };
+
+        let is_changed = if is_visible {
+            self.hidden.remove(&z_index)
+        } else {
+            self.hidden.insert(z_index)
+        };
+
+        if is_changed {
+            self.mark_dirty(Self::layer_area(layer));
+            self.update_extent();
+        }
+
+        true
+    }
+
+    /// Sets `opacity` of the layer of `z_index`, returns `true` was found.
+    ///
+    /// `255` is opaque and `0` is invisible, colors of the layer are mixed with lower layers by
+    /// [`Cell::blend_over_alpha`](crate::Cell::blend_over_alpha).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use crossterm::style::Color;
+    /// use eired_display::{Annotate, Cell, Layer, Span};
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::new_with_bg("text", Color::Rgb { r: 200, g: 200, b: 200 }).annotate((0, 0)));
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// // Dims the background
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::new_overlay_bg(4, Color::Rgb { r: 0, g: 0, b: 0 }).annotate((0, 0)));
+    /// canvas.insert(1, layer.annotate((0, 0)));
+    ///
+    /// assert!(canvas.set_opacity(1, 128));
+    /// assert_eq!(canvas.opacity(1), 128);
+    /// assert_eq!(
+    ///     canvas.create_view().get_line(0)[0],
+    ///     Some(Cell::new_bg('t', Color::Rgb { r: 100, g: 100, b: 100 }))
+    /// );
+    /// ```
+    pub fn set_opacity(&mut self, z_index: usize, opacity: u8) -> bool {
+        let Some(layer) = self.layers.get(&z_index) else {
+            return false;
+        };
+
+        let area = Self::layer_area(layer);
+        let old = if opacity == u8::MAX {
+            self.opacities.remove(&z_index)
+        } else {
+            self.opacities.insert(z_index, opacity)
+        };
+
+        if old.unwrap_or(u8::MAX) != opacity {
+            self.mark_dirty(area);
+        }
+
+        true
+    }
+
+    /// Returns opacity of the layer of `z_index`, `255` is opaque.
+    pub fn opacity(&self, z_index: usize) -> u8 {
+        self.opacities.get(&z_index).copied().unwrap_or(u8::MAX)
+    }
+
+    /// Moves the layer of `from` to `to` with visibility, returns `false` was `from` not found or
+    /// `to` is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    ///
+    /// canvas.insert(0, Layer::default().annotate((0, 0)));
+    /// canvas.insert(1, Layer::default().annotate((0, 0)));
+    ///
+    /// assert!(canvas.move_layer(0, 5));
+    /// assert!(!canvas.move_layer(1, 5));
+    /// assert_eq!(canvas.inner_vec().iter().map(|(z, _)| **z).collect::<Vec<_>>(), vec![1, 5]);
+    /// ```
+    pub fn move_layer(&mut self, from: usize, to: usize) -> bool {
+        if from == to {
+            return self.layers.contains_key(&from);
+        }
+
+        if self.layers.contains_key(&to) {
+            return false;
+        }
+
+        let Some(layer) = self.layers.remove(&from) else {
+            return false;
+        };
+
+        self.mark_dirty(Self::layer_area(&layer));
+        self.layers.insert(to, layer);
+
+        if self.hidden.remove(&from) {
+            self.hidden.insert(to);
+        }
+
+        if let Some(opacity) = self.opacities.remove(&from) {
+            self.opacities.insert(to, opacity);
+        }
+
+        if let Some(id) = self.ids.remove(&from) {
+            self.ids.insert(to, id);
+        }
+
+        self.update_extent();
+
+        true
+    }
+
+    /// Moves the layer of `z_index` above all layers, returns the new `z_index`.
+    pub fn bring_to_front(&mut self, z_index: usize) -> Option<usize> {
+        if self.layers.keys().next_back() == Some(&z_index) {
+            return Some(z_index);
+        }
+
+        let front = self.front;
+
+        self.move_layer(z_index, front).then_some(front)
+    }
+
+    /// Moves the layer of `z_index` to `pos`, returns `true` was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XX").annotate((0, 0)));
+    /// canvas.insert(0, layer.annotate((5, 5)));
+    ///
+    /// assert!(canvas.set_position(0, (1, 0)));
+    /// assert_eq!(canvas.create_view().get_size(), (3, 1));
+    /// ```
+    pub fn set_position(&mut self, z_index: usize, pos: (u16, u16)) -> bool {
+        self.set_signed_position(z_index, (pos.0 as i32, pos.1 as i32))
+    }
+
+    /// Moves the layer of `z_index` to signed `pos`, returns `true` was found.
+    ///
+    /// The part of the layer over the left top is not stacked, e.g. for sliding animations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::{Annotate, Cell, Layer, Span};
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("Slide").annotate((0, 0)));
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// assert!(canvas.set_signed_position(0, (-3, 0)));
+    /// assert_eq!(canvas.create_view().get_line(0), &[Some(Cell::new('d')), Some(Cell::new('e'))]);
+    /// ```
+    pub fn set_signed_position(&mut self, z_index: usize, pos: (i32, i32)) -> bool {
+        let Some(layer) = self.layers.get_mut(&z_index) else {
+            return false;
+        };
+
+        let old_area = Self::layer_area(layer);
+
+        layer.set_signed_pos(pos);
+
+        let new_area = Self::layer_area(layer);
+
+        self.mark_dirty(old_area);
+        self.mark_dirty(new_area);
+        self.update_extent();
+
+        true
+    }
+
+    /// Insert `layer` to `z_index` with `name`, the name is moved if used by other layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    ///
+    /// let mut canvas = Canvas::default();
+    ///
+    /// let status = canvas.insert_named(9, "status", Layer::default().annotate((0, 0)));
+    ///
+    /// canvas.insert(3, Layer::default().annotate((0, 0)));
+    /// canvas.move_layer(9, 1);
+    ///
+    /// assert_eq!(canvas.find("status"), Some(status));
+    /// assert_eq!(canvas.name_of(status), Some("status"));
+    /// assert_eq!(canvas.z_index(status), Some(1));
+    /// ```
+    pub fn insert_named<S: Into<String>>(
+        &mut self,
+        z_index: usize,
+        name: S,
+        layer: Annot<Layer>,
+    ) -> LayerId {
+        let id = self.apply_layer(z_index, layer);
+
+        self.set_name(id, name);
+
+        id
+    }
+
+    /// Sets `name` of the layer of `id`, returns `false` was not found.
+    ///
+    /// A layer has one name, the old name is released.
+    pub fn set_name<S: Into<String>>(&mut self, id: LayerId, name: S) -> bool {
+        if self.z_index(id).is_none() {
+            return false;
         }
+
+        self.names.retain(|_, named| *named != id);
+        self.names.insert(name.into(), id);
+
+        true
+    }
+
+    /// Returns the handle of `name`.
+    pub fn find(&self, name: &str) -> Option<LayerId> {
+        self.names.get(name).copied()
+    }
+
+    /// Returns the name of the layer of `id`.
+    pub fn name_of(&self, id: LayerId) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|(_, named)| **named == id)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the handle of the layer of `z_index`.
+    pub fn layer_id(&self, z_index: usize) -> Option<LayerId> {
+        self.ids.get(&z_index).copied()
+    }
+
+    /// Returns the current `z_index` of the layer of `id`.
+    pub fn z_index(&self, id: LayerId) -> Option<usize> {
+        self.ids
+            .iter()
+            .find(|(_, layer_id)| **layer_id == id)
+            .map(|(z_index, _)| *z_index)
+    }
+
+    /// Returns handles of layers from lower `z_index`.
+    pub fn layer_ids(&self) -> Vec<LayerId> {
+        self.ids.values().copied().collect()
+    }
+
+    /// Get the layer of `id`.
+    pub fn get(&self, id: LayerId) -> Option<&Annot<Layer>> {
+        self.layers.get(&self.z_index(id)?)
+    }
+
+    /// Replaces the layer of `id` by `layer`, returns the old layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("Loading").annotate((0, 0)));
+    ///
+    /// let status = canvas.overlap_layer(layer.annotate((0, 0)));
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("Done").annotate((0, 0)));
+    ///
+    /// let old = canvas.replace(status, layer.annotate((0, 0))).unwrap();
+    ///
+    /// assert_eq!(old.width(), 7);
+    /// assert_eq!(canvas.get(status).unwrap().width(), 4);
+    /// assert_eq!(canvas.create_view().get_size(), (4, 1));
+    /// ```
+    pub fn replace(&mut self, id: LayerId, layer: Annot<Layer>) -> Option<Annot<Layer>> {
+        let z_index = self.z_index(id)?;
+        let old = self.layers.remove(&z_index)?;
+
+        self.mark_dirty(Self::layer_area(&old));
+        self.apply_layer(z_index, layer);
+        self.update_extent();
+
+        Some(old)
+    }
+
+    /// Takes the layer of `id` out of canvas, the handle and the name are released.
+    pub fn take_layer(&mut self, id: LayerId) -> Option<Annot<Layer>> {
+        self.take(self.z_index(id)?)
+    }
+
+    /// Sets the clip rect, cells of layers outside `clip` are not stacked and hit.
+    ///
+    /// Unlike [`Layer::set_clip`], layers are kept as is and trimmed on
+    /// [`create_view`](Canvas::create_view).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::{Annotate, Cell, Layer, Rect, Span};
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("abc").annotate((0, 0)));
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    /// canvas.set_clip(Rect(1, 1).annotate((1, 0)));
+    ///
+    /// assert_eq!(canvas.create_view().get_line(0), &[None, Some(Cell::new('b')), None]);
+    /// assert!(canvas.hits(0, 0).is_empty());
+    ///
+    /// canvas.clear_clip();
+    ///
+    /// assert_eq!(canvas.create_view().get_line(0)[0], Some(Cell::new('a')));
+    /// ```
+    pub fn set_clip(&mut self, clip: Annot<Rect>) {
+        if self.clip != Some(clip) {
+            self.clip = Some(clip);
+            self.mark_dirty(Rect(self.width, self.height).annotate((0, 0)));
+        }
+    }
+
+    /// Removes the clip rect.
+    pub fn clear_clip(&mut self) {
+        if self.clip.take().is_some() {
+            self.mark_dirty(Rect(self.width, self.height).annotate((0, 0)));
+        }
+    }
+
+    /// Returns the clip rect.
+    pub fn clip(&self) -> Option<Annot<Rect>> {
+        self.clip
+    }
+
+    /// Returns `z_index` of layers that drawn a cell at coords, from the top.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XXX").annotate((0, 0)));
+    ///
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("O").annotate((0, 0)));
+    ///
+    /// canvas.insert(3, layer.annotate((1, 0)));
+    ///
+    /// assert_eq!(canvas.hits(1, 0), vec![3, 0]);
+    /// assert_eq!(canvas.hits(2, 0), vec![0]);
+    /// assert!(canvas.hits(0, 1).is_empty());
+    /// ```
+    pub fn hits(&self, x: u16, y: u16) -> Vec<usize> {
+        self.layers
+            .iter()
+            .rev()
+            .filter(|(z_index, layer)| {
+                let (layer_x, layer_y) = layer.signed_pos();
+                let rel_x = u16::try_from((x as i32).saturating_sub(layer_x));
+                let rel_y = u16::try_from((y as i32).saturating_sub(layer_y));
+
+                if self.hidden.contains(z_index)
+                    || self.clip.is_some_and(|clip| !clip.contains_pos(x, y))
+                {
+                    return false;
+                }
+
+                let (Ok(rel_x), Ok(rel_y)) = (rel_x, rel_y) else {
+                    return false;
+                };
+
+                layer.inner().span_at(rel_x, rel_y).is_some()
+            })
+            .map(|(z_index, _)| *z_index)
+            .collect()
+    }
+
+    /// Returns `z_index` of the top layer that drawn a cell at coords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XXX").annotate((0, 0)));
+    ///
+    /// canvas.overlap_layer(layer.annotate((2, 1)));
+    ///
+    /// assert_eq!(canvas.hit_test(3, 1), Some(0));
+    /// assert_eq!(canvas.hit_test(1, 1), None);
+    /// ```
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<usize> {
+        self.hits(x, y).first().copied()
     }
 
     /// Create a [View] from `self`.
     ///
-    /// Stacks each layer from lower `z_index`.
+    /// Stacks each layer from lower `z_index`, only the dirty regions are re-stacked on the cached
+    /// view.
     ///
     /// # Examples
     ///
@@ -302,34 +937,257 @@ impl Canvas {
     /// assert_eq!(view_iter.next(), None);
     /// ```
     pub fn create_view(&self) -> View {
-        let mut view: Vec<Option<Cell>> = vec![None; self.height as usize * self.width as usize];
-
-        for (_, layer) in self.layers.iter() {
-            let (layer_margin_x, layer_margin_y) = layer.base_pos();
-            let layer_spans = layer.inner().inner();
-
-            for span in layer_spans.iter() {
-                let (span_x, span_y) = span.base_pos();
-                let (span_x, span_y) = (span_x + layer_margin_x, span_y + layer_margin_y);
-                let line_pad = span_y * self.width;
-                let replace_slice = &mut view
-                    [(span_x + line_pad) as usize..(span_x + span.width() + line_pad) as usize];
-
-                let cells = span
-                    .inner()
-                    .to_vec()
-                    .into_iter()
-                    .map(Option::Some)
-                    .collect::<Vec<_>>();
-
-                replace_slice.copy_from_slice(&cells);
+        let mut cache = self.cache.borrow_mut();
+
+        self.restack(&mut cache);
+
+        cache.view.clone()
+    }
+
+    /// Returns the cached view, re-stacks the dirty regions like [`create_view`](Canvas::create_view).
+    ///
+    /// The view is borrowed, so the cost is proportional to the dirty regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::{Annotate, Cell, Layer, Span};
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("ab").annotate((0, 0)));
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// assert_eq!(canvas.view().get_line(0), &[Some(Cell::new('a')), Some(Cell::new('b'))]);
+    /// let copy = canvas.create_view();
+    ///
+    /// assert_eq!(canvas.view(), &copy);
+    /// ```
+    pub fn view(&mut self) -> &View {
+        self.restack(&mut self.cache.borrow_mut());
+
+        &self.cache.get_mut().view
+    }
+
+    /// Re-stacks the dirty regions on the cached view, whole view if the size changed.
+    fn restack(&self, cache: &mut Cache) {
+        let (width, height) = (self.width, self.height);
+
+        if cache.view.get_size() != (width, height) {
+            cache.view = View::new(width, height, vec![None; height as usize * width as usize]);
+            cache.dirty = vec![Rect(width, height).annotate((0, 0))];
+        }
+
+        let canvas_area = Rect(width, height).annotate((0, 0));
+
+        for area in std::mem::take(&mut cache.dirty) {
+            if let Some(area) = area.intersection(&canvas_area) {
+                self.composite(cache.view.cells_mut(), (0, 0), width, area);
+            }
+        }
+    }
+
+    /// Returns the extent of visible layers.
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Create new view of `area` in canvas, e.g. a scrolled window over a large document.
+    ///
+    /// Only layers in `area` are stacked, so the cost is proportional to the size of `area`.
+    /// The cached view of [`create_view`](Canvas::create_view) is not used and not changed.
+    /// Cells of outside the canvas are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::{Annotate, Cell, Layer, Rect, Span};
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// for y in 0..10_000 {
+    ///     layer.push_span_write(Span::from(format!("{y:05}")).annotate((0, y)));
+    /// }
+    ///
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// let view = canvas.create_viewport(Rect(3, 2).annotate((2, 5000)));
+    ///
+    /// assert_eq!(view.get_size(), (3, 2));
+    /// assert_eq!(view.get_line(1), &[Some(Cell::new('0')), Some(Cell::new('0')), Some(Cell::new('1'))]);
+    /// ```
+    pub fn create_viewport(&self, area: Annot<Rect>) -> View {
+        let (width, height) = (area.width(), area.height());
+        let mut cells = vec![None; width as usize * height as usize];
+        let canvas_area = Rect(self.width, self.height).annotate((0, 0));
+
+        if let Some(visible) = area.intersection(&canvas_area) {
+            self.composite(&mut cells, area.signed_pos(), width, visible);
+        }
+
+        View::new(width, height, cells)
+    }
+
+    /// Returns a copy of areas that changed since the last [`create_view`](Canvas::create_view).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Canvas;
+    /// use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Rect;
+    /// use eired_display::Span;
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XXX").annotate((0, 0)));
+    /// canvas.overlap_layer(layer.annotate((1, 1)));
+    ///
+    /// assert_eq!(canvas.dirty_regions(), &[Rect(3, 1).annotate((1, 1))]);
+    ///
+    /// canvas.create_view();
+    ///
+    /// assert!(canvas.dirty_regions().is_empty());
+    /// ```
+    pub fn dirty_regions(&self) -> Vec<Annot<Rect>> {
+        self.cache.borrow().dirty.clone()
+    }
+
+    /// Marks `area` to re-stack on next [`create_view`](Canvas::create_view).
+    ///
+    /// The part of negative coords is ignored.
+    pub fn mark_dirty(&mut self, area: Annot<Rect>) {
+        let (begin_x, begin_y) = area.base_pos();
+        let (end_x, end_y) = area.outer_apex_pos();
+
+        if begin_x >= end_x || begin_y >= end_y {
+            return;
+        }
+
+        let dirty = &mut self.cache.get_mut().dirty;
+
+        dirty.push(Rect(end_x - begin_x, end_y - begin_y).annotate((begin_x, begin_y)));
+
+        if dirty.len() > MAX_DIRTY_REGIONS {
+            let begin = dirty.iter().fold((u16::MAX, u16::MAX), |(x, y), a| {
+                (x.min(a.base_pos().0), y.min(a.base_pos().1))
+            });
+            let end = dirty.iter().fold((0, 0), |(x, y), a| {
+                (x.max(a.outer_apex_pos().0), y.max(a.outer_apex_pos().1))
+            });
+
+            *dirty = vec![Rect(end.0 - begin.0, end.1 - begin.1).annotate(begin)];
+        }
+    }
+
+    /// Marks whole canvas to re-stack on next [`create_view`](Canvas::create_view).
+    pub fn invalidate(&mut self) {
+        *self.cache.get_mut() = Cache::default();
+    }
+
+    /// Returns the extent that `layer` needs, a layer hidden over the left top needs nothing.
+    fn layer_extent(layer: &Annot<Layer>) -> (u16, u16) {
+        let (base_x, base_y) = layer.signed_pos();
+        let (outer_x, outer_y) = layer.signed_outer_apex_pos();
+
+        if (base_x < 0 && outer_x <= 0) || (base_y < 0 && outer_y <= 0) {
+            return (0, 0);
+        }
+
+        layer.outer_apex_pos()
+    }
+
+    fn layer_area(layer: &Annot<Layer>) -> Annot<Rect> {
+        Rect(layer.width(), layer.height()).annotate_signed(layer.signed_pos())
+    }
+
+    /// Re-stacks cells of `area` from lower `z_index`, into `cells` of `width` that starts at
+    /// `origin` of canvas.
+    ///
+    /// `area` must be inside of `cells`.
+    fn composite(
+        &self,
+        cells: &mut [Option<Cell>],
+        origin: (i32, i32),
+        width: u16,
+        area: Annot<Rect>,
+    ) {
+        let (begin_x, begin_y) = area.base_pos();
+        let (end_x, end_y) = area.outer_apex_pos();
+        let index = |x: u16, y: u16| {
+            (y as i32 - origin.1) as usize * width as usize + (x as i32 - origin.0) as usize
+        };
+
+        if begin_x >= end_x || begin_y >= end_y {
+            return;
+        }
+
+        for y in begin_y..end_y {
+            cells[index(begin_x, y)..index(end_x, y)].fill(None);
+        }
+
+        let Some(area) = self
+            .clip
+            .map_or(Some(area), |clip| area.intersection(&clip))
+        else {
+            return;
+        };
+        let (begin_x, begin_y) = area.base_pos();
+        let (end_x, end_y) = area.outer_apex_pos();
+
+        for (z_index, layer) in self
+            .layers
+            .iter()
+            .filter(|(z_index, _)| !self.hidden.contains(z_index))
+        {
+            let (layer_x, layer_y) = layer.signed_pos();
+            let opacity = self.opacity(*z_index);
+            let (begin_x, end_x) = (begin_x as i32, end_x as i32);
+            let begin_y = (begin_y as i32).max(layer_y);
+
+            for y in begin_y..end_y as i32 {
+                let Ok(row) = u16::try_from(y.saturating_sub(layer_y)) else {
+                    break;
+                };
+
+                for span in layer.inner().row(row) {
+                    let span_x = layer_x.saturating_add(span.base_pos().0 as i32);
+                    let span_end = span_x.saturating_add(span.width() as i32);
+
+                    for x in span_x.max(begin_x)..span_end.min(end_x) {
+                        let Some(cell) = span.inner().get((x - span_x) as usize) else {
+                            continue;
+                        };
+                        let target = &mut cells[index(x as u16, y as u16)];
+
+                        *target = cell.blend_over_alpha(*target, opacity);
+                    }
+                }
             }
         }
+    }
+}
 
-        View::new(self.width, self.height, view)
+impl PartialEq for Canvas {
+    fn eq(&self, other: &Self) -> bool {
+        self.front == other.front
+            && self.width == other.width
+            && self.height == other.height
+            && self.layers == other.layers
+            && self.hidden == other.hidden
+            && self.opacities == other.opacities
+            && self.clip == other.clip
     }
 }
 
+impl Eq for Canvas {}
+
 impl Debug for Canvas {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Canvas")
@@ -337,6 +1195,12 @@ impl Debug for Canvas {
             .field("width", &self.width)
             .field("height", &self.height)
             .field("layers", &self.layers)
+            .field("hidden", &self.hidden)
+            .field("opacities", &self.opacities)
+            .field("clip", &self.clip)
+            .field("ids", &self.ids)
+            .field("names", &self.names)
+            .field("dirty", &self.cache.borrow().dirty)
             .finish()
     }
 }
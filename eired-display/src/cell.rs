@@ -1,13 +1,13 @@
 use std::fmt::Debug;
 
-use crossterm::style::Color;
+use crossterm::style::{Attributes, Color};
 
 use crate::Annotate;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// A struct that corresponds terminal 1 pixel.
 ///
-/// Includes character, foreground color, background color.
+/// Includes character, foreground color, background color, attributes and [Blend].
 ///
 /// # Note
 ///
@@ -18,11 +18,12 @@ use crate::Annotate;
 ///
 /// ```
 /// # use eired_display::Cell;
-/// use crossterm::style::Color;
+/// use crossterm::style::{Attributes, Color};
+/// use eired_display::Blend;
 ///
 /// let cell = Cell::new('A');
 ///
-/// assert_eq!(cell, Cell { ch: 'A', fg: Color::Reset, bg: Color::Reset });
+/// assert_eq!(cell, Cell { ch: 'A', fg: Color::Reset, bg: Color::Reset, attrs: Attributes::none(), blend: Blend::OPAQUE });
 /// ```
 pub struct Cell {
     /// A character that corresponds terminal pixel.
@@ -45,6 +46,12 @@ pub struct Cell {
     /// `color` is the [`crossterm::style::Color`](https://docs.rs/crossterm/latest/crossterm/style/enum.Color.html).  
     /// This may change in the future.
     pub bg: Color,
+
+    /// Text attributes of pixel, e.g. bold and underlined.
+    pub attrs: Attributes,
+
+    /// Transparent parts of pixel on compositing layers.
+    pub blend: Blend,
 }
 
 impl Cell {
@@ -54,11 +61,12 @@ impl Cell {
     ///
     /// ```
     /// # use eired_display::Cell;
-    /// use crossterm::style::Color;
+    /// use crossterm::style::{Attributes, Color};
+    /// use eired_display::Blend;
     ///
     /// let cell = Cell::new('A');
     ///
-    /// assert_eq!(cell, Cell { ch: 'A', fg: Color::Reset, bg: Color::Reset });
+    /// assert_eq!(cell, Cell { ch: 'A', fg: Color::Reset, bg: Color::Reset, attrs: Attributes::none(), blend: Blend::OPAQUE });
     /// ```
     pub fn new(ch: char) -> Self {
         Self::from(ch)
@@ -70,11 +78,12 @@ impl Cell {
     ///
     /// ```
     /// # use eired_display::Cell;
-    /// use crossterm::style::Color;
+    /// use crossterm::style::{Attributes, Color};
+    /// use eired_display::Blend;
     ///
     /// let cell = Cell::new_fg('A', Color::Red);
     ///
-    /// assert_eq!(cell, Cell { ch: 'A', fg: Color::Red, bg: Color::Reset });
+    /// assert_eq!(cell, Cell { ch: 'A', fg: Color::Red, bg: Color::Reset, attrs: Attributes::none(), blend: Blend::OPAQUE });
     /// ```
     pub fn new_fg(ch: char, fg: Color) -> Self {
         Self {
@@ -90,11 +99,12 @@ impl Cell {
     ///
     /// ```
     /// # use eired_display::Cell;
-    /// use crossterm::style::Color;
+    /// use crossterm::style::{Attributes, Color};
+    /// use eired_display::Blend;
     ///
     /// let cell = Cell::new_bg('B', Color::Blue);
     ///
-    /// assert_eq!(cell, Cell { ch: 'B', fg: Color::Reset, bg: Color::Blue });
+    /// assert_eq!(cell, Cell { ch: 'B', fg: Color::Reset, bg: Color::Blue, attrs: Attributes::none(), blend: Blend::OPAQUE });
     /// ```
     pub fn new_bg(ch: char, bg: Color) -> Self {
         Self {
@@ -103,6 +113,204 @@ impl Cell {
             ..Self::default()
         }
     }
+
+    /// Create new fully transparent cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Cell;
+    /// assert_eq!(Cell::transparent().blend_over(Some(Cell::new('A'))), Some(Cell::new('A')));
+    /// assert_eq!(Cell::transparent().blend_over(None), None);
+    /// ```
+    pub fn transparent() -> Self {
+        Self {
+            blend: Blend::TRANSPARENT,
+            ..Self::default()
+        }
+    }
+
+    /// Create new cell that changes only background of lower cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Cell;
+    /// use crossterm::style::Color;
+    ///
+    /// let cell = Cell::new_overlay_bg(Color::Blue).blend_over(Some(Cell::new_fg('A', Color::Red)));
+    /// let mut expected = Cell::new_fg('A', Color::Red);
+    ///
+    /// expected.bg = Color::Blue;
+    ///
+    /// assert_eq!(cell, Some(expected));
+    /// ```
+    pub fn new_overlay_bg(bg: Color) -> Self {
+        Self {
+            bg,
+            blend: Blend {
+                ch: true,
+                fg: true,
+                bg: false,
+            },
+            ..Self::default()
+        }
+    }
+
+    /// Returns a cell of self stacked on `below`.
+    ///
+    /// Transparent parts are taken from `below`, the attributes follow the character.
+    /// Parts that are transparent on both cells are kept transparent.
+    /// A fully transparent cell on nothing is nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Cell;
+    /// use crossterm::style::Color;
+    /// use eired_display::Blend;
+    ///
+    /// let mut upper = Cell::new_fg('|', Color::Green);
+    ///
+    /// upper.blend.bg = true;
+    ///
+    /// let cell = upper.blend_over(Some(Cell::new_bg('A', Color::Blue))).unwrap();
+    ///
+    /// assert_eq!((cell.ch, cell.fg, cell.bg), ('|', Color::Green, Color::Blue));
+    /// assert_eq!(cell.blend, Blend::OPAQUE);
+    /// assert_eq!(upper.blend_over(None), Some(upper));
+    /// ```
+    pub fn blend_over(self, below: Option<Cell>) -> Option<Cell> {
+        if self.blend.is_opaque() {
+            return Some(self);
+        }
+
+        let Some(below) = below else {
+            return (!self.blend.is_transparent()).then_some(self);
+        };
+
+        let (ch, attrs, ch_blend) = if self.blend.ch {
+            (below.ch, below.attrs, below.blend.ch)
+        } else {
+            (self.ch, self.attrs, false)
+        };
+        let (fg, fg_blend) = if self.blend.fg {
+            (below.fg, below.blend.fg)
+        } else {
+            (self.fg, false)
+        };
+        let (bg, bg_blend) = if self.blend.bg {
+            (below.bg, below.blend.bg)
+        } else {
+            (self.bg, false)
+        };
+
+        Some(Self {
+            ch,
+            fg,
+            bg,
+            attrs,
+            blend: Blend {
+                ch: ch_blend,
+                fg: fg_blend,
+                bg: bg_blend,
+            },
+        })
+    }
+
+    /// Returns a cell of self stacked on `below` with `alpha`, `255` is opaque.
+    ///
+    /// Transparent parts are resolved by [`blend_over`](Cell::blend_over) first, then colors are
+    /// mixed with `below` by `alpha`.
+    /// The character and attributes are of self while `alpha` is `128` or more, else of `below`.
+    ///
+    /// Only [`Color::Rgb`] pairs are mixed, other colors are switched at `128` as the character.
+    /// The same rule is applied when nothing is below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Cell;
+    /// use crossterm::style::Color;
+    ///
+    /// let upper = Cell::new_bg('A', Color::Rgb { r: 255, g: 0, b: 0 });
+    /// let below = Cell::new_bg('B', Color::Rgb { r: 0, g: 0, b: 255 });
+    ///
+    /// let cell = upper.blend_over_alpha(Some(below), 64).unwrap();
+    ///
+    /// assert_eq!(cell.ch, 'B');
+    /// assert_eq!(cell.bg, Color::Rgb { r: 64, g: 0, b: 191 });
+    ///
+    /// // Not RGB colors are not mixed
+    /// let cell = Cell::new_bg('A', Color::Red).blend_over_alpha(Some(below), 192).unwrap();
+    ///
+    /// assert_eq!(cell, Cell::new_bg('A', Color::Red));
+    /// assert_eq!(upper.blend_over_alpha(None, 64), None);
+    /// ```
+    pub fn blend_over_alpha(self, below: Option<Cell>, alpha: u8) -> Option<Cell> {
+        match alpha {
+            u8::MAX => return self.blend_over(below),
+            0 => return below,
+            _ => {}
+        }
+
+        let is_upper = alpha >= 128;
+        let Some(below) = below else {
+            return if is_upper {
+                self.blend_over(None)
+            } else {
+                None
+            };
+        };
+        let upper = self.blend_over(Some(below))?;
+        let (ch, attrs, ch_blend) = if is_upper {
+            (upper.ch, upper.attrs, upper.blend.ch)
+        } else {
+            (below.ch, below.attrs, below.blend.ch)
+        };
+
+        Some(Self {
+            ch,
+            fg: mix_color(upper.fg, below.fg, alpha),
+            bg: mix_color(upper.bg, below.bg, alpha),
+            attrs,
+            blend: Blend {
+                ch: ch_blend,
+                ..upper.blend
+            },
+        })
+    }
+}
+
+/// Mixes `upper` to `lower` by `alpha`, colors other than RGB are switched at `128`.
+fn mix_color(upper: Color, lower: Color, alpha: u8) -> Color {
+    let (
+        Color::Rgb {
+            r: upper_r,
+            g: upper_g,
+            b: upper_b,
+        },
+        Color::Rgb {
+            r: lower_r,
+            g: lower_g,
+            b: lower_b,
+        },
+    ) = (upper, lower)
+    else {
+        return if alpha >= 128 { upper } else { lower };
+    };
+
+    let mix = |upper: u8, lower: u8| {
+        let alpha = alpha as u16;
+
+        ((upper as u16 * alpha + lower as u16 * (255 - alpha) + 127) / 255) as u8
+    };
+
+    Color::Rgb {
+        r: mix(upper_r, lower_r),
+        g: mix(upper_g, lower_g),
+        b: mix(upper_b, lower_b),
+    }
 }
 
 impl From<char> for Cell {
@@ -120,6 +328,8 @@ impl Default for Cell {
             ch: ' ',
             fg: Color::Reset,
             bg: Color::Reset,
+            attrs: Attributes::none(),
+            blend: Blend::OPAQUE,
         }
     }
 }
@@ -130,6 +340,8 @@ impl Debug for Cell {
             .field("ch", &self.ch)
             .field("fg", &self.fg)
             .field("bg", &self.bg)
+            .field("attrs", &self.attrs)
+            .field("blend", &self.blend)
             .finish()
     }
 }
@@ -139,3 +351,64 @@ impl Annotate for Cell {
         (1, 1)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+/// Transparent parts of a [Cell].
+///
+/// On compositing, transparent parts are taken from the cell of lower layers, e.g. a highlight
+/// layer that changes only background.
+///
+/// # Examples
+///
+/// ```
+/// # use eired_display::Blend;
+/// assert!(Blend::OPAQUE.is_opaque());
+/// assert!(Blend::TRANSPARENT.is_transparent());
+/// assert!(!Blend { ch: true, fg: false, bg: false }.is_transparent());
+/// ```
+pub struct Blend {
+    /// The character and attributes are transparent.
+    pub ch: bool,
+
+    /// The foreground is transparent.
+    pub fg: bool,
+
+    /// The background is transparent.
+    pub bg: bool,
+}
+
+impl Blend {
+    /// No parts are transparent.
+    pub const OPAQUE: Self = Self {
+        ch: false,
+        fg: false,
+        bg: false,
+    };
+
+    /// All parts are transparent.
+    pub const TRANSPARENT: Self = Self {
+        ch: true,
+        fg: true,
+        bg: true,
+    };
+
+    /// Returns `true` was no parts transparent.
+    pub fn is_opaque(&self) -> bool {
+        *self == Self::OPAQUE
+    }
+
+    /// Returns `true` was all parts transparent.
+    pub fn is_transparent(&self) -> bool {
+        *self == Self::TRANSPARENT
+    }
+}
+
+impl Debug for Blend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Blend")
+            .field("ch", &self.ch)
+            .field("fg", &self.fg)
+            .field("bg", &self.bg)
+            .finish()
+    }
+}
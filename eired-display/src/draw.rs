@@ -26,9 +26,11 @@ impl DrawableSpan {
     /// Apply styles by crossterm.
     pub fn styled_content(&self) -> String {
         self.span.iter().fold("".to_string(), |acc, cell| {
-            let cell = cell.ch.with(cell.fg).on(cell.bg);
+            let mut styled = cell.ch.with(cell.fg).on(cell.bg);
 
-            format!("{}{}", acc, cell)
+            styled.style_mut().attributes = cell.attrs;
+
+            format!("{}{}", acc, styled)
         })
     }
 
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An error of `eired_display`.
+pub enum Error {
+    /// A length of cells does not match to `width * height`.
+    InvalidLength {
+        /// A width of area.
+        width: u16,
+
+        /// A height of area.
+        height: u16,
+
+        /// A length of given cells.
+        len: usize,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength { width, height, len } => {
+                write!(f, "expected {width}x{height} cells, but got {len} cells")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Returns `Ok` if `len` is `width * height`.
+pub(crate) fn check_length(width: u16, height: u16, len: usize) -> Result<(), Error> {
+    if width as usize * height as usize == len {
+        Ok(())
+    } else {
+        Err(Error::InvalidLength { width, height, len })
+    }
+}
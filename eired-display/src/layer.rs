@@ -1,7 +1,7 @@
-use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::ops::Range;
 
-use crate::{Annot, Annotate, Span};
+use crate::{Annot, Annotate, Rect, Span};
 
 #[derive(Default, PartialEq, Eq)]
 /// A layer of merged spans.
@@ -9,6 +9,11 @@ use crate::{Annot, Annotate, Span};
 /// Includes spans has annotated for hold coords of relative on terminal.
 /// Spans only uses to push a [Canvas](crate::Canvas), may changes coords and size in always.
 ///
+/// Spans are indexed by rows: sorted by y, then by x within a row, and never overlap each other.
+/// So pushing a span only touches spans of the same row that overlap it.
+///
+/// A layer may have a clip rect, spans are trimmed by it on push.
+///
 /// # Examples
 ///
 /// ```
@@ -31,12 +36,13 @@ pub struct Layer {
     width: u16,
     height: u16,
     spans: Vec<Annot<Span>>,
+    clip: Option<Annot<Rect>>,
 }
 
 impl Layer {
     /// Get inner slice.
     ///
-    /// Slice not sorted.
+    /// Slice sorted by y, then by x.
     ///
     /// # Examples
     ///
@@ -57,13 +63,136 @@ impl Layer {
         &self.spans
     }
 
-    fn push_span(&mut self, span: Annot<Span>) {
+    /// Returns the top span that drawn a cell at coords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XXX").annotate((0, 0)));
+    /// layer.push_span_write(Span::from("O").annotate((1, 0)));
+    ///
+    /// assert_eq!(layer.span_at(1, 0), Some(&Span::from("O").annotate((1, 0))));
+    /// assert_eq!(layer.span_at(3, 0), None);
+    /// ```
+    pub fn span_at(&self, x: u16, y: u16) -> Option<&Annot<Span>> {
+        let row = &self.spans[self.row_range(y)];
+        let idx = row.partition_point(|s| s.outer_apex_pos().0 <= x);
+
+        row.get(idx).filter(|s| s.contains_pos(x, y))
+    }
+
+    /// Returns spans on row `y` sorted by x.
+    pub(crate) fn row(&self, y: u16) -> &[Annot<Span>] {
+        &self.spans[self.row_range(y)]
+    }
+
+    /// Returns index range of spans on row `y`.
+    fn row_range(&self, y: u16) -> Range<usize> {
+        let begin = self.spans.partition_point(|s| s.base_pos().1 < y);
+        let len = self.spans[begin..].partition_point(|s| s.base_pos().1 == y);
+
+        begin..begin + len
+    }
+
+    /// Sets the clip rect, spans are trimmed by `clip` from now and also pushed ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Layer;
+    /// use eired_display::{Annotate, Rect, Span};
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("Hello").annotate((0, 0)));
+    /// layer.set_clip(Rect(4, 2).annotate((1, 0)));
+    /// layer.push_span_write(Span::from("World!").annotate((0, 1)));
+    /// layer.push_span_write(Span::from("Outside").annotate((0, 2)));
+    ///
+    /// assert_eq!(layer.inner()[0], Span::from("ello").annotate((1, 0)));
+    /// assert_eq!(layer.inner()[1], Span::from("orld").annotate((1, 1)));
+    /// assert_eq!(layer.inner().len(), 2);
+    /// assert_eq!(layer.get_size(), (5, 2));
+    /// ```
+    pub fn set_clip(&mut self, clip: Annot<Rect>) {
+        self.spans = std::mem::take(&mut self.spans)
+            .into_iter()
+            .filter_map(|span| clip_span(span, &clip))
+            .collect();
+        self.clip = Some(clip);
+
+        (self.width, self.height) = self
+            .spans
+            .iter()
+            .map(|span| span.outer_apex_pos())
+            .fold((0, 0), |(width, height), (x, y)| {
+                (width.max(x), height.max(y))
+            });
+    }
+
+    /// Removes the clip rect, trimmed spans are not restored.
+    pub fn clear_clip(&mut self) {
+        self.clip = None;
+    }
+
+    /// Returns the clip rect.
+    pub fn clip(&self) -> Option<Annot<Rect>> {
+        self.clip
+    }
+
+    /// Returns `span` trimmed by the clip rect and coords out of `u16`, or `None` if nothing is left.
+    fn trim(&self, span: Annot<Span>) -> Option<Annot<Span>> {
+        let span = match &self.clip {
+            Some(clip) => clip_span(span, clip)?,
+            None => span,
+        };
+        let (x, y) = span.signed_pos();
+        let (end_x, end_y) = span.signed_outer_apex_pos();
+        let max = u16::MAX as i32;
+
+        if x < 0 || y < 0 || end_x > max || end_y > max {
+            clip_span(span, &Rect(u16::MAX, u16::MAX).annotate((0, 0)))
+        } else {
+            Some(span).filter(|span| !span.is_empty())
+        }
+    }
+
+    /// Returns index range of spans that conflict with `span`.
+    ///
+    /// If no conflict, the range is empty and starts at the position to insert `span`.
+    fn conflict_range(&self, span: &Annot<Span>) -> Range<usize> {
+        let (begin_x, y) = span.base_pos();
+        let end_x = span.outer_apex_pos().0;
+        let row = self.row_range(y);
+        let spans = &self.spans[row.clone()];
+        let begin = spans.partition_point(|s| s.outer_apex_pos().0 <= begin_x);
+        let end = spans.partition_point(|s| s.base_pos().0 < end_x).max(begin);
+
+        row.start + begin..row.start + end
+    }
+
+    fn extend_size(&mut self, span: &Annot<Span>) {
         let end_pos = span.outer_apex_pos();
 
         self.width = self.width.max(end_pos.0);
         self.height = self.height.max(end_pos.1);
+    }
+
+    /// Replaces spans of `range` by `spans`.
+    ///
+    /// `spans` must be non-empty, sorted by x and in the row of range.
+    fn splice_row(&mut self, range: Range<usize>, spans: Vec<Annot<Span>>) {
+        for span in spans.iter() {
+            self.extend_size(span);
+        }
 
-        self.spans.push(span);
+        self.spans.splice(range, spans);
     }
 
     fn resolve_conflict(base: Annot<Span>, overlap: &Annot<Span>) -> Vec<Annot<Span>> {
@@ -105,11 +234,10 @@ impl Layer {
 
                 solved.push(parts[1].take().map(|p| p.annotate((overlap_end, base_y))));
             }
+            // `overlap` covers whole `base`
             (false, false) => {}
         }
 
-        debug_assert!(!solved.is_empty(), "unsolved conflict");
-
         solved.into_iter().flatten().collect()
     }
 
@@ -142,28 +270,28 @@ impl Layer {
     /// assert!(layer.take_with(|s| s.inner() == &Span::from("OOO")).is_some());
     /// ```
     pub fn push_span_write(&mut self, span: Annot<Span>) {
-        if span.is_empty() {
+        let Some(span) = self.trim(span) else {
             return;
-        }
+        };
 
-        let mut tmp = vec![];
+        let range = self.conflict_range(&span);
+        let begin_x = span.base_pos().0;
+        let mut solved = self
+            .spans
+            .drain(range.clone())
+            .flat_map(|i_span| Self::resolve_conflict(i_span, &span))
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        let idx = solved.partition_point(|s| s.base_pos().0 < begin_x);
 
-        while let Some(i_span) = self.spans.pop() {
-            tmp.extend(Self::resolve_conflict(i_span, &span));
-        }
+        solved.insert(idx, span);
 
-        for elem in tmp {
-            if !elem.is_empty() {
-                self.push_span(elem);
-            }
-        }
+        self.splice_row(range.start..range.start, solved);
 
         debug_assert!(
-            self.spans.iter().all(|s| !s.is_conflict(&span)),
+            self.conflict_range(&self.spans[range.start + idx]).len() == 1,
             "Layer::push_span_write impl error"
         );
-
-        self.push_span(span);
     }
 
     /// Pushes span that overlaps to below.
@@ -195,50 +323,33 @@ impl Layer {
     /// assert!(layer.take_with(|s| s.inner() == &Span::from("OOO")).is_some());
     /// ```
     pub fn push_span_fixed(&mut self, span: Annot<Span>) {
-        if span.is_empty() {
+        let Some(span) = self.trim(span) else {
             return;
-        }
+        };
 
-        let mut tmp = vec![];
-        let mut tmp_deque = VecDeque::new();
-        let spans = self
-            .spans
-            .iter()
-            .filter(|s| s.base_pos().1 == span.base_pos().1)
-            .collect::<Vec<_>>();
-
-        tmp_deque.push_back(span);
+        let range = self.conflict_range(&span);
+        let mut spans = self.spans.drain(range.clone()).collect::<Vec<_>>();
+        let mut rest = Some(span);
+        let mut solved = vec![];
 
-        loop {
-            let Some(tmp_elem) = tmp_deque.pop_front() else {
+        for i_span in spans.iter() {
+            let Some(tmp_elem) = rest.take() else {
                 break;
             };
 
-            let conflicts = spans
-                .iter()
-                .filter(|s| s.is_conflict(&tmp_elem))
-                .collect::<Vec<_>>();
-
-            if conflicts.is_empty() {
-                tmp.push(tmp_elem);
-
-                continue;
-            }
-
-            for i_span in conflicts.iter() {
-                tmp_deque.extend(Self::resolve_conflict(tmp_elem.clone(), i_span).into_iter());
+            for part in Self::resolve_conflict(tmp_elem, i_span) {
+                if part.base_pos().0 < i_span.base_pos().0 {
+                    solved.push(part);
+                } else {
+                    rest = Some(part);
+                }
             }
         }
 
-        debug_assert!(tmp_deque.is_empty(), "Layer::push_span_fixed impl error");
+        spans.extend(solved.into_iter().chain(rest).filter(|s| !s.is_empty()));
+        spans.sort_by_key(|s| s.base_pos().0);
 
-        self.spans.retain(|s| !s.is_empty());
-
-        for elem in tmp {
-            if !elem.is_empty() {
-                self.push_span(elem);
-            }
-        }
+        self.splice_row(range.start..range.start, spans);
     }
 
     /// Pushes span if not conflict other spans.
@@ -268,16 +379,21 @@ impl Layer {
     /// assert!(layer.take_with(|s| s.inner() == &Span::from("OOO")).is_some());
     /// ```
     pub fn push_span_only_valid(&mut self, span: Annot<Span>) {
-        if span.is_empty() || self.spans.iter().any(|s| s.is_conflict(&span)) {
+        let Some(span) = self.trim(span) else {
             return;
-        }
+        };
+
+        let range = self.conflict_range(&span);
 
-        self.spans.retain(|s| !s.is_empty());
-        self.push_span(span);
+        if range.is_empty() {
+            self.splice_row(range, vec![span]);
+        }
     }
 
     /// Create overlapped another layer with `self`.
     ///
+    /// `upper` may have negative coords, the parts over the left top are clipped.
+    ///
     /// # Examples
     ///
     /// ```
@@ -307,23 +423,28 @@ impl Layer {
     /// assert!(layer.inner_mut().take_with(|s| s.inner() == &Span::from("OOO")).is_some());
     /// ```
     pub fn overlap(&self, self_root: (u16, u16), upper: Annot<Layer>) -> Annot<Layer> {
-        let mut new_layer = Layer::default();
-        let init_spans = self.spans.to_vec();
-        let (upper_x, upper_y) = upper.base_pos();
-
-        for i_span in init_spans {
-            new_layer.push_span(i_span);
+        let mut new_layer = Layer {
+            spans: self.spans.to_vec(),
+            clip: self.clip,
+            ..Layer::default()
+        };
+        let (upper_x, upper_y) = upper.signed_pos();
+
+        for i_span in self.spans.iter() {
+            new_layer.extend_size(i_span);
         }
 
         for overlap_span in upper.into_inner().spans {
-            let (rel_x, rel_y) = overlap_span.base_pos();
+            let (rel_x, rel_y) = overlap_span.signed_pos();
             let overlap_span = overlap_span
                 .into_inner()
-                .annotate((upper_x + rel_x, upper_y + rel_y));
+                .annotate_signed((upper_x.saturating_add(rel_x), upper_y.saturating_add(rel_y)));
 
             new_layer.push_span_write(overlap_span);
         }
 
+        let (upper_x, upper_y) = (upper_x.max(0) as u16, upper_y.max(0) as u16);
+
         new_layer.annotate((self_root.0.min(upper_x), self_root.1.min(upper_y)))
     }
 
@@ -349,14 +470,68 @@ impl Layer {
     /// assert!(layer.take_with(|s| s == &Span::from("XXX").annotate((3, 5))).is_some());
     /// ```
     pub fn add_margin(&mut self, margin: (u16, u16)) {
-        self.width += margin.0;
-        self.height += margin.1;
+        self.width = self.width.saturating_add(margin.0);
+        self.height = self.height.saturating_add(margin.1);
+
+        if let Some(clip) = self.clip.as_mut() {
+            clip.rebase(|x, y| {
+                *x = x.saturating_add(margin.0);
+                *y = y.saturating_add(margin.1);
+            })
+        }
 
-        for span in self.spans.iter_mut() {
-            span.rebase(|x, y| {
-                *x += margin.0;
-                *y += margin.1;
+        // Spans moved over the far edge are trimmed
+        let spans = std::mem::take(&mut self.spans)
+            .into_iter()
+            .filter_map(|mut span| {
+                span.translate((margin.0 as i32, margin.1 as i32));
+                self.trim(span)
             })
+            .collect();
+
+        self.spans = spans;
+    }
+
+    /// Moves all spans by signed `delta`, the parts moved over the left top are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Layer;
+    /// use eired_display::Annotate;
+    /// use eired_display::Span;
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// layer.push_span_write(Span::from("XXX").annotate((1, 0)));
+    /// layer.push_span_write(Span::from("OOO").annotate((1, 1)));
+    ///
+    /// layer.shift((-2, -1));
+    ///
+    /// assert_eq!(layer.inner(), &[Span::from("OO").annotate((0, 0))]);
+    /// assert_eq!(layer.get_size(), (2, 1));
+    /// ```
+    pub fn shift(&mut self, delta: (i32, i32)) {
+        let spans = std::mem::take(&mut self.spans);
+        let clip = self.clip.take().map(|mut clip| {
+            clip.translate(delta);
+            clip
+        });
+
+        self.width = 0;
+        self.height = 0;
+
+        for mut span in spans {
+            span.translate(delta);
+
+            if let Some(span) = self.trim(span) {
+                self.extend_size(&span);
+                self.spans.push(span);
+            }
+        }
+
+        if let Some(clip) = clip {
+            self.set_clip(clip);
         }
     }
 
@@ -382,7 +557,7 @@ impl Layer {
     pub fn take_with<F: Fn(&Annot<Span>) -> bool>(&mut self, f: F) -> Option<Annot<Span>> {
         let pos = self.spans.iter().position(f);
 
-        pos.map(|pos| self.spans.swap_remove(pos))
+        pos.map(|pos| self.spans.remove(pos))
     }
 }
 
@@ -392,6 +567,7 @@ impl Debug for Layer {
             .field("width", &self.width)
             .field("height", &self.height)
             .field("spans", &self.spans)
+            .field("clip", &self.clip)
             .finish()
     }
 }
@@ -401,3 +577,22 @@ impl Annotate for Layer {
         (self.width, self.height)
     }
 }
+
+/// Returns the part of `span` inside `clip`, or `None` if nothing is left.
+pub(crate) fn clip_span(span: Annot<Span>, clip: &Annot<Rect>) -> Option<Annot<Span>> {
+    let area = span.intersection(clip)?;
+    let (span_x, span_y) = span.signed_pos();
+    let (begin_x, end_x) = (area.signed_pos().0, area.signed_outer_apex_pos().0);
+
+    if (begin_x, end_x) == (span_x, span.signed_outer_apex_pos().0) {
+        return Some(span);
+    }
+
+    let mut parts = span
+        .inner()
+        .split_by(&[(begin_x - span_x) as u16, (end_x - span_x) as u16]);
+
+    parts[1]
+        .take()
+        .map(|part| part.annotate_signed((begin_x, span_y)))
+}
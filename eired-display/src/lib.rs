@@ -2,23 +2,27 @@ mod annot;
 mod canvas;
 mod cell;
 mod draw;
+mod error;
 mod layer;
 mod span;
 mod view;
+mod viewport;
 mod window;
 
 use std::fmt::Debug;
 
 pub use annot::{Annot, Annotate};
-pub use canvas::Canvas;
-pub use cell::Cell;
+pub use canvas::{Canvas, LayerId};
+pub use cell::{Blend, Cell};
 pub use draw::DrawableSpan;
+pub use error::Error;
 pub use layer::Layer;
 pub use span::Span;
 pub use view::View;
+pub use viewport::Viewport;
 pub use window::{VTerm, Window, convert_to_spans, create_virtual_terminal};
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 /// A marker struct that represents area.
 pub struct Rect(pub u16, pub u16);
 
@@ -4,7 +4,7 @@ use std::mem;
 
 use crossterm::style::Color;
 
-use crate::{Annotate, Cell};
+use crate::{Annotate, Blend, Cell};
 
 #[derive(Default, PartialEq, Eq)]
 /// A list wrapper of lined cells.
@@ -81,6 +81,42 @@ impl Span {
         span
     }
 
+    /// Create new span of `len` cells that changes only background of lower layers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Span;
+    /// use eired_display::Cell;
+    /// use crossterm::style::Color;
+    ///
+    /// let span = Span::new_overlay_bg(3, Color::Blue);
+    ///
+    /// assert_eq!(span.len(), 3);
+    /// assert_eq!(span.get(0), Some(&Cell::new_overlay_bg(Color::Blue)));
+    /// ```
+    pub fn new_overlay_bg(len: u16, color: Color) -> Self {
+        Span::from_iter((0..len).map(|_| Cell::new_overlay_bg(color)))
+    }
+
+    /// Sets `blend` to all cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Span;
+    /// use eired_display::Blend;
+    ///
+    /// let mut span = Span::from("  x ");
+    ///
+    /// span.set_blend(Blend::TRANSPARENT);
+    ///
+    /// assert!(span.get(2).unwrap().blend.is_transparent());
+    /// ```
+    pub fn set_blend(&mut self, blend: Blend) {
+        self.cells.iter_mut().for_each(|cell| cell.blend = blend);
+    }
+
     /// Get 1 cell ref by `idx`.
     ///
     /// # Examples
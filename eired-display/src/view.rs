@@ -2,7 +2,8 @@ use std::fmt::Debug;
 use std::slice::Iter;
 use std::vec::IntoIter;
 
-use crate::{Annotate, Cell};
+use crate::error::check_length;
+use crate::{Annotate, Cell, Error};
 
 #[derive(PartialEq, Eq)]
 /// An immutable list of cells for terminal area.
@@ -61,12 +62,14 @@ pub struct View {
     width: u16,
     height: u16,
     cells: Vec<Option<Cell>>,
+    opacity: u8,
 }
 
 impl View {
     /// Create new struct.
     ///
     /// Wraps [`Vec<Option<Cell>>`] to a View.
+    /// `cells` is not validated, use [`try_new`](View::try_new) for untrusted lengths.
     ///
     /// # Examples
     ///
@@ -81,9 +84,56 @@ impl View {
             width,
             height,
             cells,
+            opacity: u8::MAX,
         }
     }
 
+    /// Sets `opacity` of view on [`create_virtual_terminal`](crate::create_virtual_terminal),
+    /// `255` is opaque.
+    ///
+    /// Colors are mixed with lower views by [`Cell::blend_over_alpha`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::View;
+    /// use eired_display::Cell;
+    ///
+    /// let view = View::new(1, 1, vec![Some(Cell::new('A'))]).with_opacity(128);
+    ///
+    /// assert_eq!(view.opacity(), 128);
+    /// ```
+    pub fn with_opacity(mut self, opacity: u8) -> Self {
+        self.opacity = opacity;
+
+        self
+    }
+
+    /// Returns opacity of view, `255` is opaque.
+    pub fn opacity(&self) -> u8 {
+        self.opacity
+    }
+
+    /// Create new struct, returns an error if length of `cells` is not `width * height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::View;
+    /// use eired_display::Error;
+    ///
+    /// assert!(View::try_new(2, 1, vec![None, None]).is_ok());
+    /// assert_eq!(
+    ///     View::try_new(2, 2, vec![None]),
+    ///     Err(Error::InvalidLength { width: 2, height: 2, len: 1 })
+    /// );
+    /// ```
+    pub fn try_new(width: u16, height: u16, cells: Vec<Option<Cell>>) -> Result<Self, Error> {
+        check_length(width, height, cells.len())?;
+
+        Ok(Self::new(width, height, cells))
+    }
+
     /// Returns cell list length.
     ///
     /// # Examples
@@ -151,10 +201,14 @@ impl View {
             return &[];
         }
 
-        let start = (self.width * rows) as usize;
-        let end = (self.width * (rows + 1)) as usize;
+        let start = self.width as usize * rows as usize;
+        let end = start + self.width as usize;
+
+        self.cells.get(start..end).unwrap_or_default()
+    }
 
-        &self.cells[start..end]
+    pub(crate) fn cells_mut(&mut self) -> &mut [Option<Cell>] {
+        &mut self.cells
     }
 }
 
@@ -182,6 +236,7 @@ impl Clone for View {
             height: self.height,
             width: self.width,
             cells: self.cells.clone(),
+            opacity: self.opacity,
         }
     }
 }
@@ -192,6 +247,7 @@ impl Debug for View {
             .field("width", &self.width)
             .field("height", &self.height)
             .field("cells", &self.cells)
+            .field("opacity", &self.opacity)
             .finish()
     }
 }
@@ -0,0 +1,185 @@
+use std::fmt::Debug;
+
+use crate::{Annot, Annotate, Canvas, Rect, View};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+/// A scroll position of a window over [Canvas] in both axes.
+///
+/// The offset is kept in `0..=content_size - size` of each axis.
+///
+/// # Examples
+///
+/// ```
+/// # use eired_display::Viewport;
+/// use eired_display::{Annotate, Rect};
+///
+/// let mut viewport = Viewport::new(80, 24);
+///
+/// viewport.set_content_size((100, 10_000));
+/// viewport.scroll_by((50, 9_990));
+/// assert_eq!(viewport.offset(), (20, 9_976));
+///
+/// viewport.ensure_visible((0, 3));
+/// assert_eq!(viewport.area(), Rect(80, 24).annotate((0, 3)));
+/// ```
+pub struct Viewport {
+    width: u16,
+    height: u16,
+    content_width: u16,
+    content_height: u16,
+    offset_x: u16,
+    offset_y: u16,
+}
+
+impl Viewport {
+    /// Create new viewport at the left top.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+
+    /// Returns size of viewport.
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Returns size of content.
+    pub fn content_size(&self) -> (u16, u16) {
+        (self.content_width, self.content_height)
+    }
+
+    /// Returns offset of the left top visible cell.
+    pub fn offset(&self) -> (u16, u16) {
+        (self.offset_x, self.offset_y)
+    }
+
+    /// Returns upper bound of offset.
+    pub fn max_offset(&self) -> (u16, u16) {
+        (
+            self.content_width.saturating_sub(self.width),
+            self.content_height.saturating_sub(self.height),
+        )
+    }
+
+    /// Sets size of viewport, the offset is clamped.
+    pub fn set_size(&mut self, (width, height): (u16, u16)) {
+        self.width = width;
+        self.height = height;
+        self.clamp();
+    }
+
+    /// Sets size of content, the offset is clamped.
+    ///
+    /// This is usually [`Canvas::size`].
+    pub fn set_content_size(&mut self, (width, height): (u16, u16)) {
+        self.content_width = width;
+        self.content_height = height;
+        self.clamp();
+    }
+
+    /// Sets offset that clamped into `0..=max_offset`.
+    pub fn set_offset(&mut self, (x, y): (u16, u16)) {
+        let (max_x, max_y) = self.max_offset();
+
+        self.offset_x = x.min(max_x);
+        self.offset_y = y.min(max_y);
+    }
+
+    /// Clamps offset into `0..=max_offset`.
+    pub fn clamp(&mut self) {
+        self.set_offset(self.offset());
+    }
+
+    /// Scrolls by `(dx, dy)`, negative values scroll toward the left top.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Viewport;
+    /// let mut viewport = Viewport::new(2, 2);
+    ///
+    /// viewport.set_content_size((10, 10));
+    /// viewport.scroll_by((3, 5));
+    /// viewport.scroll_by((-5, -1));
+    ///
+    /// assert_eq!(viewport.offset(), (0, 4));
+    /// ```
+    pub fn scroll_by(&mut self, (dx, dy): (i32, i32)) {
+        let scroll = |offset: u16, d: i32| {
+            (offset as i32).saturating_add(d).clamp(0, u16::MAX as i32) as u16
+        };
+
+        self.set_offset((scroll(self.offset_x, dx), scroll(self.offset_y, dy)));
+    }
+
+    /// Scrolls minimally to make cell at `pos` visible.
+    pub fn ensure_visible(&mut self, (x, y): (u16, u16)) {
+        let scroll = |offset: u16, len: u16, idx: u16| {
+            if idx < offset {
+                idx
+            } else if len > 0 && idx >= offset.saturating_add(len) {
+                idx - len + 1
+            } else {
+                offset
+            }
+        };
+
+        self.set_offset((
+            scroll(self.offset_x, self.width, x),
+            scroll(self.offset_y, self.height, y),
+        ));
+    }
+
+    /// Returns area of canvas in viewport.
+    pub fn area(&self) -> Annot<Rect> {
+        Rect(self.width, self.height).annotate((self.offset_x, self.offset_y))
+    }
+
+    /// Create new view of visible area in `canvas`.
+    ///
+    /// The cost is proportional to the size of viewport, see [`Canvas::create_viewport`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Viewport;
+    /// use eired_display::{Annotate, Canvas, Cell, Layer, Span};
+    ///
+    /// let mut canvas = Canvas::default();
+    /// let mut layer = Layer::default();
+    ///
+    /// for y in 0..100 {
+    ///     layer.push_span_write(Span::from(format!("{y:02}")).annotate((0, y)));
+    /// }
+    ///
+    /// canvas.insert(0, layer.annotate((0, 0)));
+    ///
+    /// let mut viewport = Viewport::new(2, 1);
+    ///
+    /// viewport.set_content_size(canvas.size());
+    /// viewport.scroll_by((0, 42));
+    ///
+    /// let view = viewport.create_view(&canvas);
+    ///
+    /// assert_eq!(view.get_line(0), &[Some(Cell::new('4')), Some(Cell::new('2'))]);
+    /// ```
+    pub fn create_view(&self, canvas: &Canvas) -> View {
+        canvas.create_viewport(self.area())
+    }
+}
+
+impl Debug for Viewport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Viewport")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("content_width", &self.content_width)
+            .field("content_height", &self.content_height)
+            .field("offset_x", &self.offset_x)
+            .field("offset_y", &self.offset_y)
+            .finish()
+    }
+}
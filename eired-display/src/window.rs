@@ -3,7 +3,8 @@ use std::fmt::Debug;
 use std::mem;
 use std::slice::Iter;
 
-use crate::{Annot, Annotate, Cell, DrawableSpan, View};
+use crate::error::check_length;
+use crate::{Annot, Annotate, Cell, DrawableSpan, Error, Rect, View};
 
 #[derive(PartialEq, Eq)]
 /// A rect of used by actual rendering.
@@ -153,6 +154,52 @@ impl Window {
     pub fn overlap(&mut self, view: Annot<View>) {
         self.views.push_back(view);
     }
+
+    /// Returns views in overlapped order, the last displays on top.
+    pub fn views(&self) -> &VecDeque<Annot<View>> {
+        &self.views
+    }
+
+    /// Returns index of the top view that covers coords, and the coords relative to the view.
+    ///
+    /// A view covers its whole area, include empty cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired_display::Window;
+    /// use eired_display::Annotate;
+    /// use eired_display::View;
+    ///
+    /// let window = Window::from_views(5, 5, vec![
+    ///     View::new(3, 3, vec![None; 9]).annotate((0, 0)),
+    ///     View::new(2, 2, vec![None; 4]).annotate((2, 2)),
+    /// ]);
+    ///
+    /// assert_eq!(window.hit_test(2, 2), Some((1, (0, 0))));
+    /// assert_eq!(window.hit_test(1, 2), Some((0, (1, 2))));
+    /// assert_eq!(window.hit_test(4, 0), None);
+    /// assert_eq!(window.hit_test(5, 5), None);
+    /// ```
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<(usize, (u16, u16))> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.views
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, view)| view.contains_pos(x, y))
+            .map(|(idx, view)| {
+                let (view_x, view_y) = view.signed_pos();
+
+                (
+                    idx,
+                    ((x as i32 - view_x) as u16, (y as i32 - view_y) as u16),
+                )
+            })
+    }
 }
 
 impl Debug for Window {
@@ -169,8 +216,10 @@ impl Annotate for Window {
 
 /// Convert to annotated [VTerm] from annotated [Window].
 ///
-/// [`VTerm`] inherit the size of [`Window`] and truncates the invisible sides.
+/// [`VTerm`] inherit the size of [`Window`] and truncates the invisible sides, views may have
+/// negative coords by [`annotate_signed`](Annotate::annotate_signed).
 /// Write the layers in order, the last view displays on top.
+/// Transparent parts and [`View::opacity`] are blended with lower views.
 ///
 /// # Examples
 ///
@@ -214,34 +263,38 @@ pub fn create_virtual_terminal(window: Annot<Window>) -> Annot<VTerm> {
     let window_width = window.width;
     let window_height = window.height;
 
-    let mut holder = vec![None; (window_width * window_height) as usize];
-
-    while let Some(view) = window.views.pop_front() {
-        let (view_margin_x, view_margin_y) = view.base_pos();
+    let mut holder = vec![None; window_width as usize * window_height as usize];
 
-        let drawable_width = window_width
-            .min(view.width() + view_margin_x)
-            .saturating_sub(view_margin_x) as usize;
-        let drawable_height = window_height
-            .min(view.height() + view_margin_y)
-            .saturating_sub(view_margin_y);
+    let screen = Rect(window_width, window_height).annotate((0, 0));
 
-        if drawable_width == 0 || drawable_height == 0 {
+    while let Some(view) = window.views.pop_front() {
+        let Some(drawable) = view.intersection(&screen) else {
             continue;
-        }
+        };
 
-        let view = view.into_inner();
+        let (view_x, view_y) = view.signed_pos();
+        let (begin_x, begin_y) = drawable.base_pos();
+        let (end_x, end_y) = drawable.outer_apex_pos();
+        let (src_begin, src_end) = (
+            (begin_x as i32 - view_x) as usize,
+            (end_x as i32 - view_x) as usize,
+        );
 
-        for rel_y in 0..drawable_height {
-            let line = &view.get_line(rel_y);
-            let view_margin_x = view_margin_x as usize;
+        let view = view.into_inner();
+        let opacity = view.opacity();
 
-            let src = &line[..drawable_width];
-            let dst_begin = (window_width * (view_margin_y + rel_y)) as usize + view_margin_x;
+        for y in begin_y..end_y {
+            let line = view.get_line((y as i32 - view_y) as u16);
+            let Some(src) = line.get(src_begin..src_end) else {
+                continue;
+            };
+            let dst_begin = (window_width as usize * y as usize) + begin_x as usize;
 
-            let dst = &mut holder[dst_begin..dst_begin + drawable_width];
+            let dst = &mut holder[dst_begin..dst_begin + src.len()];
 
-            dst.copy_from_slice(src);
+            for (dst, src) in dst.iter_mut().zip(src) {
+                *dst = src.and_then(|cell| cell.blend_over_alpha(*dst, opacity));
+            }
         }
     }
 
@@ -326,6 +379,24 @@ impl VTerm {
         }
     }
 
+    /// Create new struct, returns an error if length of `cells` is not `width * height`.
+    ///
+    /// ```
+    /// # use eired_display::VTerm;
+    /// use eired_display::Error;
+    ///
+    /// assert!(VTerm::try_new(1, 1, vec![None]).is_ok());
+    /// assert_eq!(
+    ///     VTerm::try_new(3, 1, vec![None]).err(),
+    ///     Some(Error::InvalidLength { width: 3, height: 1, len: 1 })
+    /// );
+    /// ```
+    pub fn try_new(width: u16, height: u16, cells: Vec<Option<Cell>>) -> Result<Self, Error> {
+        check_length(width, height, cells.len())?;
+
+        Ok(Self::new(width, height, cells))
+    }
+
     /// Returns inner length.
     ///
     /// ```
@@ -451,7 +522,7 @@ impl Annotate for VTerm {
 /// ```
 pub fn convert_to_spans(vterm: Annot<VTerm>) -> Vec<DrawableSpan> {
     let (rel_base_x, rel_base_y) = vterm.base_pos();
-    let term_width = vterm.width();
+    let term_width = vterm.width().max(1) as usize;
     let vterm = vterm.into_inner();
 
     let mut res = vec![];
@@ -460,7 +531,7 @@ pub fn convert_to_spans(vterm: Annot<VTerm>) -> Vec<DrawableSpan> {
     let mut start_y = rel_base_y;
 
     for (i, cell) in vterm.iter().enumerate() {
-        if (i as u16).is_multiple_of(term_width) && !buffer.is_empty() {
+        if i.is_multiple_of(term_width) && !buffer.is_empty() {
             let cmd = DrawableSpan::new((start_x, start_y), mem::take(&mut buffer));
 
             res.push(cmd);
@@ -469,8 +540,8 @@ pub fn convert_to_spans(vterm: Annot<VTerm>) -> Vec<DrawableSpan> {
         match cell {
             Some(cell) => {
                 if buffer.is_empty() {
-                    start_x = rel_base_x + (i as u16 % term_width);
-                    start_y = rel_base_y + (i as u16 / term_width);
+                    start_x = rel_base_x.saturating_add((i % term_width) as u16);
+                    start_y = rel_base_y.saturating_add((i / term_width) as u16);
                 }
 
                 buffer.push(*cell);
@@ -0,0 +1,433 @@
+use std::fmt::Debug;
+
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect};
+
+use crate::sparkline::{draw_vertical, scale};
+use crate::symbols::{FULL, HORIZONTAL_EIGHTHS};
+use crate::widget::Grid;
+use crate::{Orientation, Style, Theme, Themed, Widget};
+
+const BAR_STYLE: Style = Style::new().fg(Color::Cyan);
+const VALUE_STYLE: Style = Style::new().fg(Color::Black);
+
+#[derive(Clone, Default, PartialEq, Eq)]
+/// A bar of [BarChart].
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Bar;
+/// use crossterm::style::Color;
+/// use eired::Style;
+///
+/// let bar = Bar::new(42)
+///     .label("Mon")
+///     .text_value("42 req/s")
+///     .style(Style::new().fg(Color::Yellow));
+///
+/// assert_eq!(bar.value(), 42);
+/// ```
+pub struct Bar {
+    value: u64,
+    label: Option<String>,
+    text_value: Option<String>,
+    style: Option<Style>,
+}
+
+impl Bar {
+    /// Create new bar.
+    pub fn new(value: u64) -> Self {
+        Self {
+            value,
+            ..Self::default()
+        }
+    }
+
+    /// Sets label that drawn below or left of bar.
+    pub fn label<S: Into<String>>(self, label: S) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Sets text of value, the number is used by default.
+    pub fn text_value<S: Into<String>>(self, text: S) -> Self {
+        Self {
+            text_value: Some(text.into()),
+            ..self
+        }
+    }
+
+    /// Sets style that overrides bar style of chart.
+    pub fn style(self, style: Style) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Returns value.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    fn text(&self) -> String {
+        self.text_value
+            .clone()
+            .unwrap_or_else(|| self.value.to_string())
+    }
+}
+
+impl Debug for Bar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Bar")
+            .field("value", &self.value)
+            .field("label", &self.label)
+            .field("text_value", &self.text_value)
+            .finish()
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq)]
+/// A group of bars that drawn side by side.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::BarGroup;
+/// use eired::Bar;
+///
+/// let group = BarGroup::new(vec![Bar::new(1), Bar::new(2)]).label("Week 1");
+///
+/// assert_eq!(group.bars().len(), 2);
+/// ```
+pub struct BarGroup {
+    label: Option<String>,
+    bars: Vec<Bar>,
+}
+
+impl BarGroup {
+    /// Create new group.
+    pub fn new(bars: Vec<Bar>) -> Self {
+        Self { label: None, bars }
+    }
+
+    /// Sets label of group.
+    pub fn label<S: Into<String>>(self, label: S) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Returns bars ref.
+    pub fn bars(&self) -> &[Bar] {
+        &self.bars
+    }
+}
+
+impl Debug for BarGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BarGroup")
+            .field("label", &self.label)
+            .field("bars", &self.bars)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A chart of grouped bars.
+///
+/// Values are scaled into the available length of `area`, bar labels and group labels take a row
+/// (or columns on horizontal) when any of them is set.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::BarChart;
+/// use eired::{Bar, BarGroup, Widget};
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut chart = BarChart::new(vec![BarGroup::new(vec![Bar::new(2), Bar::new(4)])]);
+/// let mut layer = Layer::default();
+///
+/// chart.set_bar_width(1);
+/// chart.set_value_visible(false);
+/// chart.render(&mut layer, Rect(3, 2).annotate((0, 0)));
+///
+/// // Row 0: "  █"
+/// // Row 1: "█ █"
+/// assert_eq!(layer.inner().len(), 3);
+/// ```
+pub struct BarChart {
+    groups: Vec<BarGroup>,
+    orientation: Orientation,
+    bar_width: u16,
+    bar_gap: u16,
+    group_gap: u16,
+    max: Option<u64>,
+    value_visible: bool,
+    bar_style: Style,
+    value_style: Style,
+    label_style: Style,
+}
+
+impl BarChart {
+    /// Create new vertical chart.
+    pub fn new(groups: Vec<BarGroup>) -> Self {
+        Self {
+            groups,
+            orientation: Orientation::Vertical,
+            bar_width: 3,
+            bar_gap: 1,
+            group_gap: 2,
+            max: None,
+            value_visible: true,
+            bar_style: BAR_STYLE,
+            value_style: VALUE_STYLE,
+            label_style: Style::new(),
+        }
+    }
+
+    /// Pushes group to back.
+    pub fn push_group(&mut self, group: BarGroup) {
+        self.groups.push(group);
+    }
+
+    /// Sets direction of bars growing.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    /// Sets thickness of bar.
+    pub fn set_bar_width(&mut self, width: u16) {
+        self.bar_width = width.max(1);
+    }
+
+    /// Sets gap between bars in group.
+    pub fn set_bar_gap(&mut self, gap: u16) {
+        self.bar_gap = gap;
+    }
+
+    /// Sets gap between groups.
+    pub fn set_group_gap(&mut self, gap: u16) {
+        self.group_gap = gap;
+    }
+
+    /// Sets upper bound of scale, the max of values is used by default.
+    pub fn set_max(&mut self, max: Option<u64>) {
+        self.max = max;
+    }
+
+    /// Shows value texts on bars.
+    pub fn set_value_visible(&mut self, visible: bool) {
+        self.value_visible = visible;
+    }
+
+    /// Sets default style of bars, the bar color is the foreground.
+    pub fn set_bar_style(&mut self, style: Style) {
+        self.bar_style = style;
+    }
+
+    /// Sets style of value texts.
+    pub fn set_value_style(&mut self, style: Style) {
+        self.value_style = style;
+    }
+
+    /// Sets style of labels.
+    pub fn set_label_style(&mut self, style: Style) {
+        self.label_style = style;
+    }
+
+    fn max_value(&self) -> u64 {
+        self.max.unwrap_or_else(|| {
+            self.groups
+                .iter()
+                .flat_map(|g| g.bars.iter().map(|b| b.value))
+                .max()
+                .unwrap_or(0)
+        })
+    }
+
+    fn value_cell_style(&self, bar_style: Style) -> Style {
+        Style {
+            fg: self.value_style.fg,
+            bg: self.value_style.bg.or(bar_style.fg),
+            attrs: self.value_style.attrs,
+        }
+    }
+
+    fn render_vertical(&self, grid: &mut Grid) {
+        let has_label = self
+            .groups
+            .iter()
+            .any(|g| g.bars.iter().any(|b| b.label.is_some()));
+        let has_group_label = self.groups.iter().any(|g| g.label.is_some());
+        let label_y = grid
+            .height()
+            .saturating_sub(has_label as u16 + has_group_label as u16);
+        let group_label_y = label_y + has_label as u16;
+        let max = self.max_value();
+        let mut x = 0u16;
+
+        for group in self.groups.iter() {
+            let group_begin = x;
+
+            for bar in group.bars.iter() {
+                if x >= grid.width() {
+                    return;
+                }
+
+                let style = bar.style.unwrap_or(self.bar_style);
+                let eighths = scale(bar.value, max, label_y as u64 * 8);
+
+                for bx in x..x.saturating_add(self.bar_width) {
+                    draw_vertical(grid, (bx, label_y), eighths, style);
+                }
+
+                let text = bar.text();
+                let text_len = text.chars().count() as u16;
+
+                if self.value_visible && eighths >= 8 && text_len <= self.bar_width {
+                    let text_x = x + (self.bar_width - text_len) / 2;
+
+                    grid.set_str(text_x, label_y - 1, &text, self.value_cell_style(style));
+                }
+
+                if let Some(label) = &bar.label {
+                    let label = truncate(label, self.bar_width);
+                    let label_x = x + (self.bar_width - label.chars().count() as u16) / 2;
+
+                    grid.set_str(label_x, label_y, &label, self.label_style);
+                }
+
+                x = x.saturating_add(self.bar_width + self.bar_gap);
+            }
+
+            let group_end = x.saturating_sub(self.bar_gap).max(group_begin);
+            let group_width = group_end - group_begin;
+
+            if let Some(label) = &group.label {
+                let label = truncate(label, group_width);
+                let label_x = group_begin + (group_width - label.chars().count() as u16) / 2;
+
+                grid.set_str(label_x, group_label_y, &label, self.label_style);
+            }
+
+            x = group_end.saturating_add(self.group_gap);
+        }
+    }
+
+    fn render_horizontal(&self, grid: &mut Grid) {
+        let label_width = self
+            .groups
+            .iter()
+            .flat_map(|g| g.bars.iter().filter_map(|b| b.label.as_ref()))
+            .map(|l| l.chars().count() as u16 + 1)
+            .max()
+            .unwrap_or(0);
+        let bar_len = grid.width().saturating_sub(label_width);
+        let max = self.max_value();
+        let mut y = 0u16;
+
+        for group in self.groups.iter() {
+            if let Some(label) = &group.label {
+                grid.set_str(0, y, label, self.label_style);
+                y = y.saturating_add(1);
+            }
+
+            for bar in group.bars.iter() {
+                if y >= grid.height() {
+                    return;
+                }
+
+                let style = bar.style.unwrap_or(self.bar_style);
+                let eighths = scale(bar.value, max, bar_len as u64 * 8);
+
+                for by in y..y.saturating_add(self.bar_width) {
+                    draw_horizontal(grid, (label_width, by), eighths, style);
+                }
+
+                let text = bar.text();
+                let center_y = y + (self.bar_width - 1) / 2;
+
+                if self.value_visible && text.chars().count() as u64 <= eighths / 8 {
+                    grid.set_str(label_width, center_y, &text, self.value_cell_style(style));
+                }
+
+                if let Some(label) = &bar.label {
+                    grid.set_str(0, center_y, label, self.label_style);
+                }
+
+                y = y.saturating_add(self.bar_width + self.bar_gap);
+            }
+
+            y = y
+                .saturating_sub(self.bar_gap)
+                .saturating_add(self.group_gap);
+        }
+    }
+}
+
+impl Widget for BarChart {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let mut grid = Grid::new(area.width(), area.height());
+
+        match self.orientation {
+            Orientation::Vertical => self.render_vertical(&mut grid),
+            Orientation::Horizontal => self.render_horizontal(&mut grid),
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Themed for BarChart {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.bar_style, &["barchart.bar", "chart"], BAR_STYLE);
+        theme.assign(
+            &mut self.value_style,
+            &["barchart.value", "value"],
+            VALUE_STYLE,
+        );
+        theme.assign(
+            &mut self.label_style,
+            &["barchart.label", "label"],
+            Style::new(),
+        );
+    }
+}
+
+impl Debug for BarChart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BarChart")
+            .field("groups", &self.groups)
+            .field("orientation", &self.orientation)
+            .field("bar_width", &self.bar_width)
+            .field("bar_gap", &self.bar_gap)
+            .field("group_gap", &self.group_gap)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+fn truncate(s: &str, width: u16) -> String {
+    s.chars().take(width as usize).collect()
+}
+
+/// Draws a row of `eighths` length rightward from `begin`.
+fn draw_horizontal(grid: &mut Grid, begin: (u16, u16), eighths: u64, style: Style) {
+    let (x, y) = begin;
+    let full = (eighths / 8) as u16;
+    let rem = (eighths % 8) as usize;
+
+    for i in 0..full {
+        grid.set(x + i, y, style.cell(FULL));
+    }
+
+    if rem > 0 {
+        grid.set(x + full, y, style.cell(HORIZONTAL_EIGHTHS[rem]));
+    }
+}
@@ -0,0 +1,370 @@
+use std::f64::consts::PI;
+use std::fmt::Debug;
+
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect};
+
+use crate::widget::Grid;
+use crate::{Style, Widget};
+
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// Bits of braille dot by `[row][col]` in a cell.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+#[derive(Clone, PartialEq)]
+/// A drawing surface that has the 2x4 braille dots per cell.
+///
+/// The floating-point coordinate space of `x_bounds` and `y_bounds` is mapped onto the dots, `y`
+/// grows upward.
+/// Colors are kept per cell, so the last drawn shape wins when shapes share a cell.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::BrailleCanvas;
+/// use crossterm::style::Color;
+/// use eired_display::Span;
+///
+/// let mut canvas = BrailleCanvas::new(2, 1);
+///
+/// canvas.set_x_bounds(0.0, 3.0);
+/// canvas.set_y_bounds(0.0, 3.0);
+/// canvas.line((0.0, 0.0), (3.0, 0.0), Color::Red);
+///
+/// let layer = canvas.to_layer();
+///
+/// assert_eq!(layer.inner()[0].inner(), &Span::new_with_fg("⣀⣀", Color::Red));
+/// ```
+pub struct BrailleCanvas {
+    width: u16,
+    height: u16,
+    x_bounds: (f64, f64),
+    y_bounds: (f64, f64),
+    dots: Vec<u8>,
+    colors: Vec<Color>,
+}
+
+impl BrailleCanvas {
+    /// Create new canvas of `width * height` cells.
+    ///
+    /// Bounds are initialized to the dot grid, `0.0..=width * 2 - 1` and `0.0..=height * 4 - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::BrailleCanvas;
+    /// let canvas = BrailleCanvas::new(10, 5);
+    ///
+    /// assert_eq!(canvas.x_bounds(), (0.0, 19.0));
+    /// assert_eq!(canvas.y_bounds(), (0.0, 19.0));
+    /// ```
+    pub fn new(width: u16, height: u16) -> Self {
+        let len = width as usize * height as usize;
+
+        Self {
+            width,
+            height,
+            x_bounds: (0.0, (width as f64 * 2.0 - 1.0).max(0.0)),
+            y_bounds: (0.0, (height as f64 * 4.0 - 1.0).max(0.0)),
+            dots: vec![0; len],
+            colors: vec![Color::Reset; len],
+        }
+    }
+
+    /// Returns size in cells.
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Returns bounds of x.
+    pub fn x_bounds(&self) -> (f64, f64) {
+        self.x_bounds
+    }
+
+    /// Returns bounds of y.
+    pub fn y_bounds(&self) -> (f64, f64) {
+        self.y_bounds
+    }
+
+    /// Sets bounds of x that mapped to left and right edge.
+    pub fn set_x_bounds(&mut self, min: f64, max: f64) {
+        self.x_bounds = (min, max);
+    }
+
+    /// Sets bounds of y that mapped to bottom and top edge.
+    pub fn set_y_bounds(&mut self, min: f64, max: f64) {
+        self.y_bounds = (min, max);
+    }
+
+    /// Removes all dots.
+    pub fn clear(&mut self) {
+        self.dots.fill(0);
+        self.colors.fill(Color::Reset);
+    }
+
+    fn dot_size(&self) -> (f64, f64) {
+        (self.width as f64 * 2.0, self.height as f64 * 4.0)
+    }
+
+    fn to_dot(&self, pos: (f64, f64)) -> (f64, f64) {
+        let (dot_w, dot_h) = self.dot_size();
+        let (x_min, x_max) = self.x_bounds;
+        let (y_min, y_max) = self.y_bounds;
+        let x_range = x_max - x_min;
+        let y_range = y_max - y_min;
+
+        let dx = if x_range == 0.0 {
+            0.0
+        } else {
+            (pos.0 - x_min) / x_range * (dot_w - 1.0)
+        };
+        let dy = if y_range == 0.0 {
+            0.0
+        } else {
+            (y_max - pos.1) / y_range * (dot_h - 1.0)
+        };
+
+        (dx, dy)
+    }
+
+    fn set_dot(&mut self, dx: f64, dy: f64, color: Color) {
+        let (dot_w, dot_h) = self.dot_size();
+        let (dx, dy) = (dx.round(), dy.round());
+
+        if !(0.0..dot_w).contains(&dx) || !(0.0..dot_h).contains(&dy) {
+            return;
+        }
+
+        let (dx, dy) = (dx as usize, dy as usize);
+        let idx = (dy / 4) * self.width as usize + dx / 2;
+
+        self.dots[idx] |= BRAILLE_DOTS[dy % 4][dx % 2];
+        self.colors[idx] = color;
+    }
+
+    fn dot_line(&mut self, begin: (f64, f64), end: (f64, f64), color: Color) {
+        let (dot_w, dot_h) = self.dot_size();
+        let Some((begin, end)) = clip_line(begin, end, (dot_w - 0.5, dot_h - 0.5)) else {
+            return;
+        };
+
+        let steps = (end.0 - begin.0).abs().max((end.1 - begin.1).abs()).ceil();
+
+        if steps == 0.0 {
+            self.set_dot(begin.0, begin.1, color);
+
+            return;
+        }
+
+        for i in 0..=steps as usize {
+            let t = i as f64 / steps;
+
+            self.set_dot(
+                begin.0 + (end.0 - begin.0) * t,
+                begin.1 + (end.1 - begin.1) * t,
+                color,
+            );
+        }
+    }
+
+    /// Draws a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::BrailleCanvas;
+    /// use crossterm::style::Color;
+    ///
+    /// let mut canvas = BrailleCanvas::new(1, 1);
+    ///
+    /// canvas.point(0.0, 3.0, Color::Reset);
+    /// canvas.point(1.0, 0.0, Color::Reset);
+    ///
+    /// assert_eq!(canvas.to_layer().inner()[0].inner().get(0).unwrap().ch, '⢁');
+    /// ```
+    pub fn point(&mut self, x: f64, y: f64, color: Color) {
+        let (dx, dy) = self.to_dot((x, y));
+
+        self.set_dot(dx, dy, color);
+    }
+
+    /// Draws a line between `begin` and `end`.
+    pub fn line(&mut self, begin: (f64, f64), end: (f64, f64), color: Color) {
+        let begin = self.to_dot(begin);
+        let end = self.to_dot(end);
+
+        self.dot_line(begin, end, color);
+    }
+
+    /// Draws lines through `points` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::BrailleCanvas;
+    /// use crossterm::style::Color;
+    ///
+    /// let mut canvas = BrailleCanvas::new(1, 1);
+    ///
+    /// canvas.polyline(&[(0.0, 3.0), (0.0, 0.0), (1.0, 0.0)], Color::Reset);
+    ///
+    /// assert_eq!(canvas.to_layer().inner()[0].inner().get(0).unwrap().ch, '⣇');
+    /// ```
+    pub fn polyline(&mut self, points: &[(f64, f64)], color: Color) {
+        if let [point] = points {
+            self.point(point.0, point.1, color);
+        }
+
+        for pair in points.windows(2) {
+            self.line(pair[0], pair[1], color);
+        }
+    }
+
+    /// Draws outline of rectangle that has the bottom left corner at `(x, y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::BrailleCanvas;
+    /// use crossterm::style::Color;
+    ///
+    /// let mut canvas = BrailleCanvas::new(1, 1);
+    ///
+    /// canvas.rectangle(0.0, 0.0, 1.0, 3.0, Color::Reset);
+    ///
+    /// assert_eq!(canvas.to_layer().inner()[0].inner().get(0).unwrap().ch, '⣿');
+    /// ```
+    pub fn rectangle(&mut self, x: f64, y: f64, width: f64, height: f64, color: Color) {
+        self.polyline(
+            &[
+                (x, y),
+                (x + width, y),
+                (x + width, y + height),
+                (x, y + height),
+                (x, y),
+            ],
+            color,
+        );
+    }
+
+    /// Draws outline of circle by `radius` in coordinate space.
+    ///
+    /// The circle becomes an ellipse on screen when scales of bounds are different.
+    pub fn circle(&mut self, center: (f64, f64), radius: f64, color: Color) {
+        let (cx, cy) = self.to_dot(center);
+        let (rx, _) = self.to_dot((center.0 + radius, center.1));
+        let (_, ry) = self.to_dot((center.0, center.1 + radius));
+        let (rx, ry) = ((rx - cx).abs(), (ry - cy).abs());
+        let steps = ((rx.max(ry) * 2.0 * PI * 2.0).ceil() as usize).clamp(8, 4096);
+
+        for i in 0..steps {
+            let theta = i as f64 / steps as f64 * 2.0 * PI;
+
+            self.set_dot(cx + rx * theta.cos(), cy + ry * theta.sin(), color);
+        }
+    }
+
+    /// Create new layer of dots, cells without dots are not included.
+    pub fn to_layer(&self) -> Layer {
+        let mut layer = Layer::default();
+
+        self.to_grid().write_to(&mut layer, (0, 0));
+
+        layer
+    }
+
+    pub(crate) fn to_grid(&self) -> Grid {
+        let mut grid = Grid::new(self.width, self.height);
+
+        for (i, (mask, color)) in self.dots.iter().zip(self.colors.iter()).enumerate() {
+            if *mask == 0 {
+                continue;
+            }
+
+            let x = (i % self.width as usize) as u16;
+            let y = (i / self.width as usize) as u16;
+            let ch = char::from_u32(BRAILLE_BASE + *mask as u32).unwrap_or(' ');
+
+            grid.set(x, y, Style::new().fg(*color).cell(ch));
+        }
+
+        grid
+    }
+}
+
+impl Widget for BrailleCanvas {
+    /// Renders dots from the left top of `area`, the canvas is truncated by `area`.
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let source = self.to_grid();
+        let mut grid = Grid::new(area.width(), area.height());
+
+        for y in 0..grid.height().min(self.height) {
+            for x in 0..grid.width().min(self.width) {
+                if let Some(cell) = source.get(x, y) {
+                    grid.set(x, y, cell);
+                }
+            }
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Debug for BrailleCanvas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrailleCanvas")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("x_bounds", &self.x_bounds)
+            .field("y_bounds", &self.y_bounds)
+            .finish()
+    }
+}
+
+/// Clips line into `-0.5..=max`, returns `None` was the line is outside.
+fn clip_line(
+    begin: (f64, f64),
+    end: (f64, f64),
+    max: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let (dx, dy) = (end.0 - begin.0, end.1 - begin.1);
+    let mut t0 = 0.0f64;
+    let mut t1 = 1.0f64;
+
+    if !(begin.0.is_finite() && begin.1.is_finite() && end.0.is_finite() && end.1.is_finite()) {
+        return None;
+    }
+
+    for (p, q) in [
+        (-dx, begin.0 + 0.5),
+        (dx, max.0 - begin.0),
+        (-dy, begin.1 + 0.5),
+        (dy, max.1 - begin.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+
+            continue;
+        }
+
+        let r = q / p;
+
+        if p < 0.0 {
+            t0 = t0.max(r);
+        } else {
+            t1 = t1.min(r);
+        }
+
+        if t0 > t1 {
+            return None;
+        }
+    }
+
+    Some((
+        (begin.0 + dx * t0, begin.1 + dy * t0),
+        (begin.0 + dx * t1, begin.1 + dy * t1),
+    ))
+}
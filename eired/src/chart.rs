@@ -0,0 +1,689 @@
+use std::fmt::Debug;
+
+use crossterm::style::Color;
+use eired_display::{Annot, Cell, Layer, Rect};
+
+use crate::widget::Grid;
+use crate::{BrailleCanvas, Style, Theme, Themed, Widget};
+
+const MAX_TICKS: usize = 256;
+const AXIS_STYLE: Style = Style::new().fg(Color::DarkGrey);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// A symbol of data points in [Chart].
+pub enum Marker {
+    /// The braille dots, 2x4 points per cell.
+    #[default]
+    Braille,
+
+    /// A `'•'` per cell.
+    Dot,
+
+    /// A `'█'` per cell.
+    Block,
+}
+
+impl Marker {
+    fn symbol(&self) -> char {
+        match self {
+            Marker::Braille => '⣿',
+            Marker::Dot => '•',
+            Marker::Block => '█',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// A drawing of data points in [Chart].
+pub enum GraphType {
+    /// Points are connected by lines.
+    #[default]
+    Line,
+
+    /// Only points are drawn.
+    Scatter,
+}
+
+#[derive(Clone, PartialEq)]
+/// A named series of `(x, y)` points.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Dataset;
+/// use crossterm::style::Color;
+/// use eired::{GraphType, Marker};
+///
+/// let dataset = Dataset::new("requests", vec![(0.0, 1.0), (1.0, 3.0)])
+///     .marker(Marker::Dot)
+///     .graph_type(GraphType::Scatter)
+///     .color(Color::Yellow);
+///
+/// assert_eq!(dataset.name(), "requests");
+/// ```
+pub struct Dataset {
+    name: String,
+    data: Vec<(f64, f64)>,
+    marker: Marker,
+    graph_type: GraphType,
+    color: Color,
+}
+
+impl Dataset {
+    /// Create new line dataset by braille marker.
+    pub fn new<S: Into<String>>(name: S, data: Vec<(f64, f64)>) -> Self {
+        Self {
+            name: name.into(),
+            data,
+            marker: Marker::default(),
+            graph_type: GraphType::default(),
+            color: Color::Reset,
+        }
+    }
+
+    /// Sets marker.
+    pub fn marker(self, marker: Marker) -> Self {
+        Self { marker, ..self }
+    }
+
+    /// Sets graph type.
+    pub fn graph_type(self, graph_type: GraphType) -> Self {
+        Self { graph_type, ..self }
+    }
+
+    /// Sets color.
+    pub fn color(self, color: Color) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Returns name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns points ref.
+    pub fn data(&self) -> &[(f64, f64)] {
+        &self.data
+    }
+}
+
+impl Debug for Dataset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dataset")
+            .field("name", &self.name)
+            .field("data", &self.data)
+            .field("marker", &self.marker)
+            .field("graph_type", &self.graph_type)
+            .field("color", &self.color)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq)]
+/// An axis of [Chart].
+///
+/// Bounds are computed from datasets when not set, and tick labels are placed at the "nice"
+/// numbers when labels are not set.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Axis;
+/// let axis = Axis::default().title("time").bounds(0.0, 60.0).ticks(4);
+///
+/// assert_eq!(axis.tick_labels((0.0, 60.0)), vec!["0", "20", "40", "60"]);
+///
+/// let axis = Axis::default().labels(vec!["low", "high"]);
+///
+/// assert_eq!(axis.tick_labels((0.0, 1.0)), vec!["low", "high"]);
+/// ```
+pub struct Axis {
+    title: Option<String>,
+    bounds: Option<(f64, f64)>,
+    labels: Option<Vec<String>>,
+    ticks: usize,
+}
+
+impl Axis {
+    /// Sets title.
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    /// Sets bounds.
+    pub fn bounds(self, min: f64, max: f64) -> Self {
+        Self {
+            bounds: Some((min, max)),
+            ..self
+        }
+    }
+
+    /// Sets labels that placed evenly from min to max.
+    pub fn labels<S: Into<String>>(self, labels: Vec<S>) -> Self {
+        Self {
+            labels: Some(labels.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Sets approximate count of automatic ticks.
+    ///
+    /// At most 256 ticks are made, the step is widened for more.
+    pub fn ticks(self, ticks: usize) -> Self {
+        Self { ticks, ..self }
+    }
+
+    /// Returns tick positions and labels in `bounds`.
+    fn tick_marks(&self, bounds: (f64, f64)) -> Vec<(f64, String)> {
+        let (min, max) = bounds;
+
+        if let Some(labels) = &self.labels {
+            let last = labels.len().saturating_sub(1).max(1) as f64;
+
+            return labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| (min + (max - min) * i as f64 / last, label.clone()))
+                .collect();
+        }
+
+        let mut step = nice_step(max - min, self.ticks.min(MAX_TICKS));
+
+        if step <= 0.0 || !step.is_finite() {
+            return vec![(min, format_tick(min, 1.0))];
+        }
+
+        let tick_range = |step: f64| {
+            let first = (min / step).ceil();
+
+            (first, ((max + step * 1e-9) / step).floor() - first)
+        };
+        let (mut first, mut count) = tick_range(step);
+
+        // The rounded step may give more ticks than MAX_TICKS, widen it to the next nice step
+        while count >= MAX_TICKS as f64 {
+            step = nice_step(step * 1.5, 2);
+            (first, count) = tick_range(step);
+        }
+
+        if count < 0.0 {
+            return vec![];
+        }
+
+        (0..=count as usize)
+            .map(|k| {
+                let value = (first + k as f64) * step;
+
+                (value, format_tick(value, step))
+            })
+            .collect()
+    }
+
+    /// Returns tick labels in `bounds`.
+    pub fn tick_labels(&self, bounds: (f64, f64)) -> Vec<String> {
+        self.tick_marks(bounds)
+            .into_iter()
+            .map(|(_, label)| label)
+            .collect()
+    }
+}
+
+impl Default for Axis {
+    fn default() -> Self {
+        Self {
+            title: None,
+            bounds: None,
+            labels: None,
+            ticks: 5,
+        }
+    }
+}
+
+impl Debug for Axis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Axis")
+            .field("title", &self.title)
+            .field("bounds", &self.bounds)
+            .field("labels", &self.labels)
+            .field("ticks", &self.ticks)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq)]
+/// A line or scatter chart with axes and legend.
+///
+/// The y axis with tick labels is drawn at left, the x axis at bottom, and the legend box at the
+/// right top of plot area.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Chart;
+/// use eired::{Axis, Dataset, Marker, Widget};
+/// use eired_display::{Annotate, Canvas, Layer, Rect};
+///
+/// let mut chart = Chart::new(vec![
+///     Dataset::new("up", vec![(0.0, 0.0), (10.0, 10.0)]).marker(Marker::Block),
+/// ]);
+///
+/// chart.set_x_axis(Axis::default().bounds(0.0, 10.0).ticks(2));
+/// chart.set_y_axis(Axis::default().bounds(0.0, 10.0).ticks(2));
+/// chart.set_legend_visible(false);
+///
+/// let mut layer = Layer::default();
+///
+/// chart.render(&mut layer, Rect(8, 5).annotate((0, 0)));
+///
+/// let mut canvas = Canvas::default();
+///
+/// canvas.overlap_layer(layer.annotate((0, 0)));
+///
+/// let view = canvas.create_view();
+/// let rows = (0..view.height())
+///     .map(|y| view.get_line(y).iter().map(|c| c.map_or(' ', |c| c.ch)).collect::<String>())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(rows, ["10┤    █", "  │  ██ ", " 0┤██   ", "  └────┬", "   0  10"]);
+/// ```
+pub struct Chart {
+    datasets: Vec<Dataset>,
+    x_axis: Axis,
+    y_axis: Axis,
+    legend_visible: bool,
+    axis_style: Style,
+    legend_style: Style,
+}
+
+impl Chart {
+    /// Create new chart.
+    pub fn new(datasets: Vec<Dataset>) -> Self {
+        Self {
+            datasets,
+            x_axis: Axis::default(),
+            y_axis: Axis::default(),
+            legend_visible: true,
+            axis_style: AXIS_STYLE,
+            legend_style: Style::new(),
+        }
+    }
+
+    /// Pushes dataset to top.
+    pub fn push_dataset(&mut self, dataset: Dataset) {
+        self.datasets.push(dataset);
+    }
+
+    /// Returns datasets ref mut for update points.
+    pub fn datasets_mut(&mut self) -> &mut Vec<Dataset> {
+        &mut self.datasets
+    }
+
+    /// Sets x axis.
+    pub fn set_x_axis(&mut self, axis: Axis) {
+        self.x_axis = axis;
+    }
+
+    /// Sets y axis.
+    pub fn set_y_axis(&mut self, axis: Axis) {
+        self.y_axis = axis;
+    }
+
+    /// Shows legend box.
+    pub fn set_legend_visible(&mut self, visible: bool) {
+        self.legend_visible = visible;
+    }
+
+    /// Sets style of axes and tick labels.
+    pub fn set_axis_style(&mut self, style: Style) {
+        self.axis_style = style;
+    }
+
+    /// Sets style of legend box.
+    pub fn set_legend_style(&mut self, style: Style) {
+        self.legend_style = style;
+    }
+
+    /// Returns bounds of x and y that used to render.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Chart;
+    /// use eired::{Axis, Dataset};
+    ///
+    /// let mut chart = Chart::new(vec![Dataset::new("", vec![(1.0, 5.0), (3.0, -5.0)])]);
+    ///
+    /// assert_eq!(chart.bounds(), ((1.0, 3.0), (-5.0, 5.0)));
+    ///
+    /// chart.set_y_axis(Axis::default().bounds(0.0, 1.0));
+    ///
+    /// assert_eq!(chart.bounds(), ((1.0, 3.0), (0.0, 1.0)));
+    /// ```
+    pub fn bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let points = || self.datasets.iter().flat_map(|d| d.data.iter());
+        let x = self
+            .x_axis
+            .bounds
+            .unwrap_or_else(|| data_bounds(points().map(|p| p.0)));
+        let y = self
+            .y_axis
+            .bounds
+            .unwrap_or_else(|| data_bounds(points().map(|p| p.1)));
+
+        (x, y)
+    }
+
+    fn draw_datasets(&self, grid: &mut Grid, plot: (u16, u16, u16, u16), bounds: Bounds) {
+        let (left, top, width, height) = plot;
+        let (x_bounds, y_bounds) = bounds;
+
+        for dataset in self.datasets.iter() {
+            let plot_grid = if dataset.marker == Marker::Braille {
+                let mut canvas = BrailleCanvas::new(width, height);
+
+                canvas.set_x_bounds(x_bounds.0, x_bounds.1);
+                canvas.set_y_bounds(y_bounds.0, y_bounds.1);
+
+                match dataset.graph_type {
+                    GraphType::Line => canvas.polyline(&dataset.data, dataset.color),
+                    GraphType::Scatter => dataset
+                        .data
+                        .iter()
+                        .for_each(|p| canvas.point(p.0, p.1, dataset.color)),
+                }
+
+                canvas.to_grid()
+            } else {
+                let mut plot_grid = Grid::new(width, height);
+                let cell = Style::new().fg(dataset.color).cell(dataset.marker.symbol());
+                let to_cell = |p: &(f64, f64)| {
+                    (
+                        map_value(p.0, x_bounds, width, false),
+                        map_value(p.1, y_bounds, height, true),
+                    )
+                };
+                let cells = dataset.data.iter().map(to_cell).collect::<Vec<_>>();
+
+                match dataset.graph_type {
+                    GraphType::Line if cells.len() > 1 => cells
+                        .windows(2)
+                        .for_each(|pair| draw_cell_line(&mut plot_grid, pair[0], pair[1], cell)),
+                    _ => cells
+                        .iter()
+                        .for_each(|p| draw_cell_line(&mut plot_grid, *p, *p, cell)),
+                }
+
+                plot_grid
+            };
+
+            for y in 0..height {
+                for x in 0..width {
+                    let Some(cell) = plot_grid.get(x, y) else {
+                        continue;
+                    };
+
+                    let target = grid.get(left + x, top + y);
+
+                    grid.set(left + x, top + y, merge_braille(target, cell));
+                }
+            }
+        }
+    }
+
+    fn draw_legend(&self, grid: &mut Grid, plot: (u16, u16, u16, u16)) {
+        let (left, top, width, height) = plot;
+        let entries = self
+            .datasets
+            .iter()
+            .filter(|d| !d.name.is_empty())
+            .collect::<Vec<_>>();
+        let name_width = entries
+            .iter()
+            .map(|d| d.name.chars().count() as u16)
+            .max()
+            .unwrap_or(0);
+        let (box_width, box_height) = (name_width + 4, entries.len() as u16 + 2);
+
+        if entries.is_empty() || box_width > width || box_height > height {
+            return;
+        }
+
+        let box_x = left + width - box_width;
+
+        grid.fill(
+            (box_x, top),
+            (box_width, box_height),
+            self.legend_style.cell(' '),
+        );
+        grid.draw_border((box_x, top), (box_width, box_height), self.legend_style);
+
+        for (i, dataset) in entries.iter().enumerate() {
+            let y = top + 1 + i as u16;
+
+            grid.set(
+                box_x + 1,
+                y,
+                self.legend_style
+                    .patch(Style::new().fg(dataset.color))
+                    .cell(dataset.marker.symbol()),
+            );
+            grid.set_str(box_x + 3, y, &dataset.name, self.legend_style);
+        }
+    }
+}
+
+type Bounds = ((f64, f64), (f64, f64));
+
+impl Widget for Chart {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let (width, height) = (area.width(), area.height());
+        let y_title_rows = self.y_axis.title.is_some() as u16;
+        let x_title_rows = self.x_axis.title.is_some() as u16;
+
+        if height < y_title_rows + x_title_rows + 3 {
+            return;
+        }
+
+        let (x_bounds, y_bounds) = self.bounds();
+        let x_ticks = self.x_axis.tick_marks(x_bounds);
+        let y_ticks = self.y_axis.tick_marks(y_bounds);
+        let y_label_width = y_ticks
+            .iter()
+            .map(|(_, label)| label.chars().count() as u16)
+            .max()
+            .unwrap_or(0);
+        let label_row = height - 1 - x_title_rows;
+        let axis_row = label_row - 1;
+        let plot_top = y_title_rows;
+        let plot_height = axis_row - plot_top;
+        let axis_col = y_label_width;
+        let plot_left = axis_col + 1;
+
+        if width <= plot_left {
+            return;
+        }
+
+        let plot_width = width - plot_left;
+        let plot = (plot_left, plot_top, plot_width, plot_height);
+        let mut grid = Grid::new(width, height);
+
+        for y in plot_top..axis_row {
+            grid.set(axis_col, y, self.axis_style.cell('│'));
+        }
+
+        for x in plot_left..width {
+            grid.set(x, axis_row, self.axis_style.cell('─'));
+        }
+
+        grid.set(axis_col, axis_row, self.axis_style.cell('└'));
+
+        for (value, label) in y_ticks.iter() {
+            let y = if plot_height == 0 {
+                axis_row
+            } else {
+                plot_top + map_value(*value, y_bounds, plot_height, true)
+            };
+            let label_x = y_label_width - label.chars().count() as u16;
+            grid.set_str(label_x, y, label, self.axis_style);
+            grid.set(axis_col, y, self.axis_style.cell('┤'));
+        }
+
+        let mut label_end = 0u16;
+
+        for (value, label) in x_ticks.iter() {
+            let x = plot_left + map_value(*value, x_bounds, plot_width, false);
+            let len = label.chars().count() as u16;
+            let label_x = x.saturating_sub(len / 2).min(width.saturating_sub(len));
+
+            if x > plot_left {
+                grid.set(x, axis_row, self.axis_style.cell('┬'));
+            }
+
+            if label_x < label_end {
+                continue;
+            }
+
+            grid.set_str(label_x, label_row, label, self.axis_style);
+            label_end = label_x + len + 1;
+        }
+
+        if let Some(title) = &self.y_axis.title {
+            grid.set_str(0, 0, title, self.axis_style);
+        }
+
+        if let Some(title) = &self.x_axis.title {
+            let len = title.chars().count() as u16;
+
+            grid.set_str(
+                width.saturating_sub(len),
+                height - 1,
+                title,
+                self.axis_style,
+            );
+        }
+
+        self.draw_datasets(&mut grid, plot, (x_bounds, y_bounds));
+
+        if self.legend_visible {
+            self.draw_legend(&mut grid, plot);
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Themed for Chart {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.axis_style, &["chart.axis", "axis"], AXIS_STYLE);
+        theme.assign(
+            &mut self.legend_style,
+            &["chart.legend", "text"],
+            Style::new(),
+        );
+    }
+}
+
+impl Debug for Chart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Chart")
+            .field("datasets", &self.datasets)
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("legend_visible", &self.legend_visible)
+            .finish()
+    }
+}
+
+fn data_bounds<I: Iterator<Item = f64>>(values: I) -> (f64, f64) {
+    let (min, max) = values
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+
+    if min > max {
+        (0.0, 1.0)
+    } else if min == max {
+        (min - 1.0, max + 1.0)
+    } else {
+        (min, max)
+    }
+}
+
+fn nice_step(range: f64, count: usize) -> f64 {
+    let raw = range / count.saturating_sub(1).max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let nice = if normalized < 1.5 {
+        1.0
+    } else if normalized < 3.0 {
+        2.0
+    } else if normalized < 7.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+fn format_tick(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    let label = format!("{:.*}", decimals, value);
+
+    if label
+        .trim_start_matches('-')
+        .chars()
+        .all(|c| c == '0' || c == '.')
+    {
+        format!("{:.*}", decimals, 0.0)
+    } else {
+        label
+    }
+}
+
+/// Maps `value` in `bounds` to a cell of `len`, `invert` is for the y axis that grows upward.
+fn map_value(value: f64, bounds: (f64, f64), len: u16, invert: bool) -> u16 {
+    let (min, max) = bounds;
+    let last = len.saturating_sub(1) as f64;
+    let ratio = if max == min {
+        0.0
+    } else {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    };
+    let pos = (ratio * last).round() as u16;
+
+    if invert {
+        len.saturating_sub(1) - pos
+    } else {
+        pos
+    }
+}
+
+fn draw_cell_line(grid: &mut Grid, begin: (u16, u16), end: (u16, u16), cell: Cell) {
+    let (x0, y0) = (begin.0 as i32, begin.1 as i32);
+    let (x1, y1) = (end.0 as i32, end.1 as i32);
+    let steps = (x1 - x0).abs().max((y1 - y0).abs()).max(1);
+
+    for i in 0..=steps {
+        let x = x0 + (x1 - x0) * i / steps;
+        let y = y0 + (y1 - y0) * i / steps;
+
+        grid.set(x as u16, y as u16, cell);
+    }
+}
+
+fn merge_braille(below: Option<Cell>, above: Cell) -> Cell {
+    let is_braille = |c: char| ('\u{2800}'..='\u{28ff}').contains(&c);
+
+    match below {
+        Some(below) if is_braille(below.ch) && is_braille(above.ch) => Cell {
+            ch: char::from_u32(below.ch as u32 | above.ch as u32).unwrap_or(above.ch),
+            ..above
+        },
+        _ => above,
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use eired_display::{Annot, Layer, Rect};
+
+use crate::Widget;
+
+/// A widget that receives key events while focused.
+pub trait Focusable: Widget {
+    /// Handles key event, returns `true` was the key consumed.
+    ///
+    /// Unconsumed `Tab` and `BackTab` move the focus by [FocusManager].
+    fn handle_key(&mut self, key: KeyEvent) -> bool;
+
+    /// Renders by focus state, the same as [`render`](Widget::render) by default.
+    fn render_focus(&mut self, layer: &mut Layer, area: Annot<Rect>, is_focused: bool) {
+        let _ = is_focused;
+
+        self.render(layer, area);
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct FocusEntry {
+    id: String,
+    order: i32,
+    is_enabled: bool,
+}
+
+#[derive(Clone, Default, PartialEq, Eq)]
+/// A focus ring of widget ids.
+///
+/// Ids are traversed by ascending order, the same order keeps registration order.
+/// Disabled ids are skipped by traversal and can't be focused.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::FocusManager;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+///
+/// let mut focus = FocusManager::default();
+///
+/// focus.register("search");
+/// focus.register("list");
+///
+/// assert_eq!(focus.focused(), Some("search"));
+///
+/// // The focused widget does not consume Tab
+/// focus.input(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE), |_, _| false);
+///
+/// assert_eq!(focus.focused(), Some("list"));
+/// ```
+pub struct FocusManager {
+    ring: Vec<FocusEntry>,
+    focused: Option<String>,
+    next_order: i32,
+}
+
+impl FocusManager {
+    /// Registers `id` to the end of ring, the first id is focused.
+    pub fn register<S: Into<String>>(&mut self, id: S) {
+        let order = self.next_order;
+
+        self.register_with_order(id, order);
+    }
+
+    /// Registers `id` with traversal `order`, a registered id is moved.
+    pub fn register_with_order<S: Into<String>>(&mut self, id: S, order: i32) {
+        let id = id.into();
+
+        self.ring.retain(|e| e.id != id);
+
+        let idx = self.ring.partition_point(|e| e.order <= order);
+
+        self.ring.insert(
+            idx,
+            FocusEntry {
+                id: id.clone(),
+                order,
+                is_enabled: true,
+            },
+        );
+        self.next_order = self.next_order.max(order.saturating_add(1));
+
+        if self.focused.is_none() {
+            self.focused = Some(id);
+        }
+    }
+
+    /// Unregisters `id`, the focus moves to next when `id` was focused.
+    pub fn unregister(&mut self, id: &str) -> bool {
+        if self.is_focused(id) {
+            self.focus_next();
+        }
+
+        let len = self.ring.len();
+
+        self.ring.retain(|e| e.id != id);
+
+        if self.is_focused(id) {
+            self.focused = None;
+        }
+
+        len != self.ring.len()
+    }
+
+    /// Returns ids by traversal order.
+    pub fn ids(&self) -> Vec<&str> {
+        self.ring.iter().map(|e| e.id.as_str()).collect()
+    }
+
+    /// Enables or disables `id`, the focus moves to next when focused `id` is disabled.
+    pub fn set_enabled(&mut self, id: &str, is_enabled: bool) {
+        if !is_enabled && self.is_focused(id) {
+            self.focus_next();
+
+            if self.is_focused(id) {
+                self.focused = None;
+            }
+        }
+
+        if let Some(entry) = self.ring.iter_mut().find(|e| e.id == id) {
+            entry.is_enabled = is_enabled;
+        }
+
+        if self.focused.is_none() && is_enabled {
+            self.focus(id);
+        }
+    }
+
+    /// Returns focused id.
+    pub fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    /// Returns `true` was `id` focused.
+    pub fn is_focused(&self, id: &str) -> bool {
+        self.focused.as_deref() == Some(id)
+    }
+
+    /// Focuses `id`, returns `false` was not registered or disabled.
+    pub fn focus(&mut self, id: &str) -> bool {
+        let is_focusable = self.ring.iter().any(|e| e.id == id && e.is_enabled);
+
+        if is_focusable {
+            self.focused = Some(id.to_string());
+        }
+
+        is_focusable
+    }
+
+    /// Removes focus from all.
+    pub fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Focuses next enabled id, the last wraps to the first.
+    pub fn focus_next(&mut self) -> bool {
+        self.step(true)
+    }
+
+    /// Focuses previous enabled id, the first wraps to the last.
+    pub fn focus_previous(&mut self) -> bool {
+        self.step(false)
+    }
+
+    fn step(&mut self, is_forward: bool) -> bool {
+        let len = self.ring.len();
+        let current = self
+            .focused
+            .as_ref()
+            .and_then(|id| self.ring.iter().position(|e| &e.id == id));
+        let next = (1..=len)
+            .map(|step| match (current, is_forward) {
+                (Some(idx), true) => (idx + step) % len,
+                (Some(idx), false) => (idx + len * 2 - step) % len,
+                (None, true) => step - 1,
+                (None, false) => len - step,
+            })
+            .find(|i| self.ring[*i].is_enabled);
+
+        match next {
+            Some(next) if Some(next) != current => {
+                self.focused = Some(self.ring[next].id.clone());
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Passes key event to `handler` with focused id, then moves focus by unconsumed
+    /// `Tab`/`BackTab`.
+    ///
+    /// Returns `true` was the key consumed.
+    pub fn input<F: FnOnce(&str, KeyEvent) -> bool>(&mut self, key: KeyEvent, handler: F) -> bool {
+        if let Some(id) = self.focused.clone()
+            && handler(&id, key)
+        {
+            return true;
+        }
+
+        match key.code {
+            KeyCode::BackTab => self.focus_previous(),
+            KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => self.focus_previous(),
+            KeyCode::Tab => self.focus_next(),
+            _ => false,
+        }
+    }
+
+    /// Passes key event to the focused one of `widgets`, then moves focus by unconsumed
+    /// `Tab`/`BackTab`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::FocusManager;
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use eired::{Focusable, TextArea};
+    ///
+    /// let mut focus = FocusManager::default();
+    /// let mut name = TextArea::default();
+    /// let mut note = TextArea::default();
+    ///
+    /// focus.register("name");
+    /// focus.register("note");
+    /// focus.focus("note");
+    ///
+    /// focus.dispatch(
+    ///     KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+    ///     &mut [("name", &mut name), ("note", &mut note)],
+    /// );
+    ///
+    /// assert_eq!(name.text(), "");
+    /// assert_eq!(note.text(), "a");
+    /// ```
+    pub fn dispatch(&mut self, key: KeyEvent, widgets: &mut [(&str, &mut dyn Focusable)]) -> bool {
+        self.input(key, |id, key| {
+            widgets
+                .iter_mut()
+                .find(|(widget_id, _)| *widget_id == id)
+                .is_some_and(|(_, widget)| widget.handle_key(key))
+        })
+    }
+
+    /// Renders `widget` of `id` by focus state.
+    pub fn render(
+        &self,
+        id: &str,
+        widget: &mut dyn Focusable,
+        layer: &mut Layer,
+        area: Annot<Rect>,
+    ) {
+        widget.render_focus(layer, area, self.is_focused(id));
+    }
+}
+
+impl Debug for FocusManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocusManager")
+            .field("ring", &self.ids())
+            .field("focused", &self.focused)
+            .finish()
+    }
+}
@@ -0,0 +1,400 @@
+use std::fmt::Debug;
+
+use crossterm::style::Color;
+use eired_display::{Annot, Cell, Layer, Rect, Span};
+
+use crate::symbols::{FULL, HORIZONTAL_EIGHTHS};
+use crate::widget::write_span;
+use crate::{Style, Theme, Themed, Widget};
+
+const FILLED_STYLE: Style = Style::new().fg(Color::Green);
+
+#[derive(Clone, PartialEq)]
+/// A gauge widget that fills `area` by ratio.
+///
+/// The filled part is drawn by `'█'` with foreground of `filled_style`, and the edge cell uses the
+/// eighth blocks (`▏▎▍▌▋▊▉`) for sub-cell precision.
+/// The label is overlaid on the center row.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Gauge;
+/// use eired::Widget;
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut gauge = Gauge::new(0.55);
+/// let mut layer = Layer::default();
+///
+/// gauge.set_label("");
+/// gauge.render(&mut layer, Rect(4, 1).annotate((0, 0)));
+///
+/// let chars = layer.inner()[0].inner().to_vec().iter().map(|c| c.ch).collect::<String>();
+///
+/// assert_eq!(chars, "██▎ ");
+/// ```
+pub struct Gauge {
+    ratio: f64,
+    label: Option<String>,
+    sub_cell: bool,
+    filled_style: Style,
+    unfilled_style: Style,
+    label_style: Style,
+}
+
+impl Gauge {
+    /// Create new gauge with `ratio` that clamped into `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Gauge;
+    /// let gauge = Gauge::new(1.5);
+    ///
+    /// assert_eq!(gauge.ratio(), 1.0);
+    /// ```
+    pub fn new(ratio: f64) -> Self {
+        Self {
+            ratio: clamp_ratio(ratio),
+            label: None,
+            sub_cell: true,
+            filled_style: FILLED_STYLE,
+            unfilled_style: Style::new(),
+            label_style: Style::new(),
+        }
+    }
+
+    /// Returns ratio.
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Sets ratio that clamped into `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Gauge;
+    /// let mut gauge = Gauge::new(0.0);
+    ///
+    /// gauge.set_ratio(0.25);
+    ///
+    /// assert_eq!(gauge.ratio(), 0.25);
+    /// ```
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = clamp_ratio(ratio);
+    }
+
+    /// Sets label, the percentage is used by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Gauge;
+    /// let mut gauge = Gauge::new(0.5);
+    ///
+    /// assert_eq!(gauge.label(), "50%");
+    ///
+    /// gauge.set_label("Loading");
+    ///
+    /// assert_eq!(gauge.label(), "Loading");
+    /// ```
+    pub fn set_label<S: Into<String>>(&mut self, label: S) {
+        self.label = Some(label.into());
+    }
+
+    /// Returns label text.
+    pub fn label(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => format!("{}%", (self.ratio * 100.0).round() as u16),
+        }
+    }
+
+    /// Enables sub-cell precision by the eighth blocks.
+    pub fn set_sub_cell(&mut self, sub_cell: bool) {
+        self.sub_cell = sub_cell;
+    }
+
+    /// Sets style of filled part, the bar color is the foreground.
+    pub fn set_filled_style(&mut self, style: Style) {
+        self.filled_style = style;
+    }
+
+    /// Sets style of unfilled part.
+    pub fn set_unfilled_style(&mut self, style: Style) {
+        self.unfilled_style = style;
+    }
+
+    /// Sets style of label.
+    pub fn set_label_style(&mut self, style: Style) {
+        self.label_style = style;
+    }
+
+    fn bar_cells(&self, width: u16) -> Vec<Cell> {
+        let eighths = (self.ratio * width as f64 * 8.0).round() as usize;
+        let (full, rem) = if self.sub_cell {
+            (eighths / 8, eighths % 8)
+        } else {
+            ((eighths + 4) / 8, 0)
+        };
+
+        (0..width as usize)
+            .map(|i| {
+                if i < full {
+                    self.filled_style.cell(FULL)
+                } else if i == full && rem > 0 {
+                    let mut cell = self.unfilled_style.cell(HORIZONTAL_EIGHTHS[rem]);
+
+                    if let Some(fg) = self.filled_style.fg {
+                        cell.fg = fg;
+                    }
+
+                    cell
+                } else {
+                    self.unfilled_style.cell(' ')
+                }
+            })
+            .collect()
+    }
+
+    fn overlay_label(&self, cells: &mut [Cell]) {
+        let label = self.label().chars().collect::<Vec<_>>();
+        let width = cells.len();
+
+        if label.is_empty() {
+            return;
+        }
+
+        let filled = cells.iter().take_while(|c| c.ch == FULL).count();
+        let begin = width.saturating_sub(label.len()) / 2;
+
+        for (i, ch) in label.into_iter().enumerate().take(width) {
+            let x = begin + i;
+            let mut cell = if x < filled {
+                let mut cell = Cell::new(ch);
+
+                if let Some(bg) = self.filled_style.fg {
+                    cell.bg = bg;
+                }
+
+                cell
+            } else {
+                self.unfilled_style.cell(ch)
+            };
+
+            self.label_style.apply(&mut cell);
+            cells[x] = cell;
+        }
+    }
+}
+
+impl Widget for Gauge {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let (area_x, area_y) = area.base_pos();
+        let height = area.height();
+        let cells = self.bar_cells(area.width());
+
+        for rel_y in 0..height {
+            let mut cells = cells.clone();
+
+            if rel_y == height / 2 {
+                self.overlay_label(&mut cells);
+            }
+
+            write_span(
+                layer,
+                &area,
+                (area_x, area_y + rel_y),
+                Span::from_iter(cells),
+            );
+        }
+    }
+}
+
+impl Themed for Gauge {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(
+            &mut self.filled_style,
+            &["gauge.filled", "progress"],
+            FILLED_STYLE,
+        );
+        theme.assign(
+            &mut self.unfilled_style,
+            &["gauge.unfilled", "text"],
+            Style::new(),
+        );
+        theme.assign(
+            &mut self.label_style,
+            &["gauge.label", "label"],
+            Style::new(),
+        );
+    }
+}
+
+impl Debug for Gauge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Gauge")
+            .field("ratio", &self.ratio)
+            .field("label", &self.label())
+            .field("sub_cell", &self.sub_cell)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq)]
+/// A single line progress bar of `position / total`.
+///
+/// Renders the first row of `area` as a [Gauge], the label is `"position/total"` by default.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::ProgressBar;
+/// use eired::Widget;
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut bar = ProgressBar::new(200);
+///
+/// bar.inc(50);
+///
+/// assert_eq!(bar.ratio(), 0.25);
+/// assert_eq!(bar.label(), "50/200");
+///
+/// let mut layer = Layer::default();
+///
+/// bar.render(&mut layer, Rect(20, 3).annotate((0, 0)));
+///
+/// assert_eq!(layer.inner().len(), 1);
+/// ```
+pub struct ProgressBar {
+    position: u64,
+    total: u64,
+    label: Option<String>,
+    gauge: Gauge,
+}
+
+impl ProgressBar {
+    /// Create new progress bar.
+    pub fn new(total: u64) -> Self {
+        Self {
+            position: 0,
+            total,
+            label: None,
+            gauge: Gauge::new(0.0),
+        }
+    }
+
+    /// Returns ratio of `position / total`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::ProgressBar;
+    /// let bar = ProgressBar::new(0);
+    ///
+    /// assert_eq!(bar.ratio(), 1.0);
+    /// ```
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+
+        clamp_ratio(self.position as f64 / self.total as f64)
+    }
+
+    /// Returns position.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns total.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns `true` was position reached to total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::ProgressBar;
+    /// let mut bar = ProgressBar::new(3);
+    ///
+    /// bar.inc(5);
+    ///
+    /// assert!(bar.is_finished());
+    /// assert_eq!(bar.position(), 3);
+    /// ```
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.total
+    }
+
+    /// Sets position that clamped by total.
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position.min(self.total);
+    }
+
+    /// Advances position by `delta`.
+    pub fn inc(&mut self, delta: u64) {
+        self.set_position(self.position.saturating_add(delta));
+    }
+
+    /// Sets total, the position is clamped.
+    pub fn set_total(&mut self, total: u64) {
+        self.total = total;
+        self.set_position(self.position);
+    }
+
+    /// Sets label, `"position/total"` is used by default.
+    pub fn set_label<S: Into<String>>(&mut self, label: S) {
+        self.label = Some(label.into());
+    }
+
+    /// Returns label text.
+    pub fn label(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => format!("{}/{}", self.position, self.total),
+        }
+    }
+
+    /// Returns inner gauge ref mut for modify styles.
+    pub fn gauge_mut(&mut self) -> &mut Gauge {
+        &mut self.gauge
+    }
+}
+
+impl Widget for ProgressBar {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let line = Annot::new(area.base_pos(), Rect(area.width(), area.height().min(1)));
+
+        self.gauge.set_ratio(self.ratio());
+        self.gauge.set_label(self.label());
+        self.gauge.render(layer, line);
+    }
+}
+
+impl Themed for ProgressBar {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.gauge.apply_theme(theme);
+    }
+}
+
+impl Debug for ProgressBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressBar")
+            .field("position", &self.position)
+            .field("total", &self.total)
+            .field("label", &self.label())
+            .finish()
+    }
+}
+
+fn clamp_ratio(ratio: f64) -> f64 {
+    if ratio.is_nan() {
+        return 0.0;
+    }
+
+    ratio.clamp(0.0, 1.0)
+}
@@ -0,0 +1,657 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect};
+
+use crate::widget::Grid;
+use crate::{ScrollState, Style, Theme, Themed, Widget};
+
+const DEFAULT_MODE: &str = "normal";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+const KEY_STYLE: Style = Style::new().fg(Color::Cyan);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// An error of [Keymap].
+pub enum KeymapError {
+    /// A key spec that can't be parsed.
+    InvalidSpec(String),
+
+    /// A sequence that equals to or is prefixed by a bound one in the mode.
+    Conflict {
+        /// The mode of bindings.
+        mode: String,
+
+        /// The sequence to bind.
+        keys: String,
+
+        /// The bound sequence.
+        existing: String,
+    },
+}
+
+impl Display for KeymapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSpec(spec) => write!(f, "invalid key spec: `{spec}`"),
+            Self::Conflict {
+                mode,
+                keys,
+                existing,
+            } => write!(f, "`{keys}` conflicts with `{existing}` in {mode} mode"),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A key with modifiers.
+///
+/// `SHIFT` of chars is dropped, because the char has its case, and `shift-tab` is `backtab`.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Key;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+///
+/// let key: Key = "ctrl-x".parse().unwrap();
+///
+/// assert_eq!(key, Key::from(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL)));
+/// assert_eq!(key.to_string(), "ctrl-x");
+/// assert_eq!("shift-a".parse::<Key>().unwrap().to_string(), "A");
+/// ```
+pub struct Key {
+    /// A code of key.
+    pub code: KeyCode,
+
+    /// Modifiers of key.
+    pub modifiers: KeyModifiers,
+}
+
+impl Key {
+    /// Create new key, `SHIFT` of chars is dropped.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        match code {
+            KeyCode::Char(ch) if modifiers.contains(KeyModifiers::SHIFT) => Self {
+                code: KeyCode::Char(ch.to_uppercase().next().unwrap_or(ch)),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => Self {
+                code: KeyCode::BackTab,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::BackTab => Self {
+                code,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => Self { code, modifiers },
+        }
+    }
+}
+
+impl From<KeyEvent> for Key {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl FromStr for Key {
+    type Err = KeymapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || KeymapError::InvalidSpec(s.to_string());
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = s;
+
+        while let Some((name, tail)) = rest.split_once('-').filter(|(_, tail)| !tail.is_empty()) {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "c" => KeyModifiers::CONTROL,
+                "alt" | "meta" | "m" => KeyModifiers::ALT,
+                "shift" | "s" => KeyModifiers::SHIFT,
+                "super" => KeyModifiers::SUPER,
+                _ => return Err(invalid()),
+            };
+            rest = tail;
+        }
+
+        let mut chars = rest.chars();
+
+        let code = match (chars.next(), chars.next()) {
+            (Some(ch), None) => KeyCode::Char(ch),
+            _ => match rest.to_ascii_lowercase().as_str() {
+                "enter" | "ret" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" | "bs" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "space" => KeyCode::Char(' '),
+                "minus" => KeyCode::Char('-'),
+                name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n @ 1..=24) => KeyCode::F(n),
+                    _ => return Err(invalid()),
+                },
+            },
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl-"),
+            (KeyModifiers::ALT, "alt-"),
+            (KeyModifiers::SHIFT, "shift-"),
+            (KeyModifiers::SUPER, "super-"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(ch) => write!(f, "{ch}"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("backtab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Delete => f.write_str("delete"),
+            KeyCode::Insert => f.write_str("insert"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+/// Parses whitespace separated key specs as a sequence, e.g. `"ctrl-x ctrl-s"`.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::parse_keys;
+/// use eired::Key;
+///
+/// let keys = parse_keys("ctrl-x ctrl-s").unwrap();
+///
+/// assert_eq!(keys, vec!["ctrl-x".parse::<Key>().unwrap(), "ctrl-s".parse().unwrap()]);
+/// assert!(parse_keys("").is_err());
+/// assert!(parse_keys("hyper-x").is_err());
+/// ```
+pub fn parse_keys(spec: &str) -> Result<Vec<Key>, KeymapError> {
+    let keys = spec
+        .split_whitespace()
+        .map(Key::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if keys.is_empty() {
+        return Err(KeymapError::InvalidSpec(spec.to_string()));
+    }
+
+    Ok(keys)
+}
+
+fn format_keys(keys: &[Key]) -> String {
+    keys.iter()
+        .map(Key::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A key sequence bound to an action.
+pub struct Binding<A> {
+    keys: Vec<Key>,
+    action: A,
+    help: Option<String>,
+}
+
+impl<A> Binding<A> {
+    /// Returns key sequence.
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    /// Returns bound action.
+    pub fn action(&self) -> &A {
+        &self.action
+    }
+
+    /// Returns description of the binding.
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+}
+
+impl<A: Debug> Debug for Binding<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Binding")
+            .field("keys", &format_keys(&self.keys))
+            .field("action", &self.action)
+            .field("help", &self.help)
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A result of [Keymap::input].
+pub enum KeyResult<A> {
+    /// A sequence is completed.
+    Action(A),
+
+    /// Keys are a prefix of some sequences, waits next key.
+    Pending,
+
+    /// Keys are not bound in the mode.
+    Unbound(Vec<Key>),
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A modal table of key sequences.
+///
+/// Each mode has its own bindings, `"normal"` is the current mode by default.
+/// A pending sequence is dropped after the timeout since the last key.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Keymap;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use eired::KeyResult;
+///
+/// let mut keymap = Keymap::default();
+///
+/// keymap.bind("normal", "ctrl-x ctrl-s", "save").unwrap();
+/// keymap.bind("normal", "i", "insert").unwrap();
+/// keymap.bind("insert", "esc", "normal").unwrap();
+///
+/// // `ctrl-x` is a prefix of `ctrl-x ctrl-s`
+/// assert!(keymap.bind("normal", "ctrl-x", "cut").is_err());
+///
+/// let ctrl = |ch| KeyEvent::new(KeyCode::Char(ch), KeyModifiers::CONTROL);
+///
+/// assert_eq!(keymap.input(ctrl('x')), KeyResult::Pending);
+/// assert_eq!(keymap.input(ctrl('s')), KeyResult::Action("save"));
+///
+/// keymap.set_mode("insert");
+///
+/// let a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+///
+/// assert_eq!(keymap.input(a), KeyResult::Unbound(vec![a.into()]));
+/// ```
+pub struct Keymap<A> {
+    modes: Vec<(String, Vec<Binding<A>>)>,
+    mode: String,
+    pending: Vec<Key>,
+    last_input: Option<Instant>,
+    timeout: Duration,
+}
+
+impl<A> Default for Keymap<A> {
+    fn default() -> Self {
+        Self {
+            modes: vec![],
+            mode: DEFAULT_MODE.to_string(),
+            pending: vec![],
+            last_input: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl<A> Keymap<A> {
+    fn bindings_mut(&mut self, mode: &str) -> &mut Vec<Binding<A>> {
+        let idx = match self.modes.iter().position(|(name, _)| name == mode) {
+            Some(idx) => idx,
+            None => {
+                self.modes.push((mode.to_string(), vec![]));
+
+                self.modes.len() - 1
+            }
+        };
+
+        &mut self.modes[idx].1
+    }
+
+    /// Binds `spec` to `action` in `mode`.
+    ///
+    /// Returns [KeymapError::Conflict] when a bound sequence equals to, prefixes or is prefixed by
+    /// the sequence.
+    pub fn bind(&mut self, mode: &str, spec: &str, action: A) -> Result<(), KeymapError> {
+        self.bind_inner(mode, spec, action, None)
+    }
+
+    /// Binds `spec` to `action` in `mode` with description for the help.
+    pub fn bind_with_help<S: Into<String>>(
+        &mut self,
+        mode: &str,
+        spec: &str,
+        action: A,
+        help: S,
+    ) -> Result<(), KeymapError> {
+        self.bind_inner(mode, spec, action, Some(help.into()))
+    }
+
+    fn bind_inner(
+        &mut self,
+        mode: &str,
+        spec: &str,
+        action: A,
+        help: Option<String>,
+    ) -> Result<(), KeymapError> {
+        let keys = parse_keys(spec)?;
+
+        if let Some(existing) = self.conflict(mode, &keys) {
+            return Err(KeymapError::Conflict {
+                mode: mode.to_string(),
+                keys: format_keys(&keys),
+                existing: format_keys(existing.keys()),
+            });
+        }
+
+        self.bindings_mut(mode).push(Binding { keys, action, help });
+
+        Ok(())
+    }
+
+    /// Returns a binding in `mode` that conflicts with `keys`.
+    pub fn conflict(&self, mode: &str, keys: &[Key]) -> Option<&Binding<A>> {
+        self.bindings(mode)
+            .iter()
+            .find(|b| b.keys.starts_with(keys) || keys.starts_with(&b.keys))
+    }
+
+    /// Unbinds `spec` in `mode`, returns the bound action.
+    ///
+    /// The mode is removed with its last binding.
+    pub fn unbind(&mut self, mode: &str, spec: &str) -> Option<A> {
+        let keys = parse_keys(spec).ok()?;
+        let mode_idx = self.modes.iter().position(|(name, _)| name == mode)?;
+        let bindings = &mut self.modes[mode_idx].1;
+        let idx = bindings.iter().position(|b| b.keys == keys)?;
+        let action = bindings.remove(idx).action;
+
+        if bindings.is_empty() {
+            self.modes.remove(mode_idx);
+        }
+
+        Some(action)
+    }
+
+    /// Returns bindings of `mode` by bound order.
+    pub fn bindings(&self, mode: &str) -> &[Binding<A>] {
+        self.modes
+            .iter()
+            .find(|(name, _)| name == mode)
+            .map(|(_, bindings)| bindings.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns names of modes that have bindings.
+    pub fn modes(&self) -> Vec<&str> {
+        self.modes.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Returns current mode.
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Switches current mode, the pending sequence is dropped.
+    pub fn set_mode<S: Into<String>>(&mut self, mode: S) {
+        self.mode = mode.into();
+        self.reset();
+    }
+
+    /// Returns timeout between keys of a sequence.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sets timeout between keys of a sequence.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Returns pending keys.
+    pub fn pending(&self) -> &[Key] {
+        &self.pending
+    }
+
+    /// Drops the pending sequence.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.last_input = None;
+    }
+
+    /// Drops the pending sequence if timed out at `now`, returns `true` was dropped.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let is_expired = self
+            .last_input
+            .is_some_and(|last| now.saturating_duration_since(last) > self.timeout);
+
+        if is_expired && !self.pending.is_empty() {
+            self.reset();
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Creates help view of bindings in `mode`.
+    pub fn help(&self, mode: &str) -> KeymapHelp {
+        KeymapHelp::new(
+            self.bindings(mode)
+                .iter()
+                .map(|b| (format_keys(&b.keys), b.help.clone().unwrap_or_default()))
+                .collect(),
+        )
+    }
+}
+
+impl<A: Clone> Keymap<A> {
+    /// Handles key event at the current time.
+    pub fn input(&mut self, key: KeyEvent) -> KeyResult<A> {
+        self.input_at(key, Instant::now())
+    }
+
+    /// Handles key event at `now`.
+    ///
+    /// A key that breaks the pending sequence starts a new sequence, the broken keys are returned
+    /// as [KeyResult::Unbound] when the key is unbound too.
+    pub fn input_at(&mut self, key: KeyEvent, now: Instant) -> KeyResult<A> {
+        self.expire(now);
+
+        let key = Key::from(key);
+        let mut keys = std::mem::take(&mut self.pending);
+
+        keys.push(key);
+
+        match self.lookup(&keys) {
+            KeyResult::Unbound(_) if keys.len() > 1 => match self.lookup(&[key]) {
+                KeyResult::Unbound(_) => {
+                    self.reset();
+
+                    KeyResult::Unbound(keys)
+                }
+                result => self.settle(vec![key], result, now),
+            },
+            result => self.settle(keys, result, now),
+        }
+    }
+
+    fn lookup(&self, keys: &[Key]) -> KeyResult<A> {
+        let bindings = self.bindings(&self.mode);
+
+        if let Some(binding) = bindings.iter().find(|b| b.keys == keys) {
+            return KeyResult::Action(binding.action.clone());
+        }
+
+        if bindings.iter().any(|b| b.keys.starts_with(keys)) {
+            return KeyResult::Pending;
+        }
+
+        KeyResult::Unbound(keys.to_vec())
+    }
+
+    fn settle(&mut self, keys: Vec<Key>, result: KeyResult<A>, now: Instant) -> KeyResult<A> {
+        if matches!(result, KeyResult::Pending) {
+            self.pending = keys;
+            self.last_input = Some(now);
+        } else {
+            self.reset();
+        }
+
+        result
+    }
+}
+
+impl<A: Debug> Debug for Keymap<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keymap")
+            .field("modes", &self.modes)
+            .field("mode", &self.mode)
+            .field("pending", &format_keys(&self.pending))
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A widget that lists key sequences and descriptions.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Keymap;
+/// use eired::Widget;
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut keymap = Keymap::default();
+///
+/// keymap.bind_with_help("normal", "ctrl-x ctrl-s", (), "Save").unwrap();
+/// keymap.bind_with_help("normal", "q", (), "Quit").unwrap();
+///
+/// let mut layer = Layer::default();
+///
+/// keymap.help("normal").render(&mut layer, Rect(20, 2).annotate((0, 0)));
+///
+/// let row: String = layer.inner()[0].inner().to_vec().iter().map(|cell| cell.ch).collect();
+///
+/// assert_eq!(row, "ctrl-x ctrl-s  Save ");
+/// ```
+pub struct KeymapHelp {
+    rows: Vec<(String, String)>,
+    scroll: ScrollState,
+    style: Style,
+    key_style: Style,
+}
+
+impl KeymapHelp {
+    /// Create new help of `(keys, description)` rows.
+    pub fn new(rows: Vec<(String, String)>) -> Self {
+        Self {
+            scroll: ScrollState::new(rows.len(), 0),
+            rows,
+            style: Style::new(),
+            key_style: KEY_STYLE,
+        }
+    }
+
+    /// Returns rows of help.
+    pub fn rows(&self) -> &[(String, String)] {
+        &self.rows
+    }
+
+    /// Returns scroll state.
+    pub fn scroll(&self) -> &ScrollState {
+        &self.scroll
+    }
+
+    /// Returns mutable scroll state.
+    pub fn scroll_mut(&mut self) -> &mut ScrollState {
+        &mut self.scroll
+    }
+
+    /// Sets base style.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of key sequences.
+    pub fn set_key_style(&mut self, style: Style) {
+        self.key_style = style;
+    }
+}
+
+impl Widget for KeymapHelp {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let (width, height) = (area.width(), area.height());
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let mut grid = Grid::new(width, height);
+        let keys_width = self
+            .rows
+            .iter()
+            .map(|(keys, _)| keys.chars().count() as u16)
+            .max()
+            .unwrap_or(0);
+
+        self.scroll.set_content_len(self.rows.len());
+        self.scroll.set_viewport_len(height as usize);
+        grid.fill((0, 0), (width, height), self.style.cell(' '));
+
+        for (y, i) in self.scroll.visible_range().enumerate() {
+            let (keys, help) = &self.rows[i];
+
+            grid.set_str(0, y as u16, keys, self.style.patch(self.key_style));
+            grid.set_str(keys_width.saturating_add(2), y as u16, help, self.style);
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Themed for KeymapHelp {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["keymap", "text"], Style::new());
+        theme.assign(&mut self.key_style, &["keymap.key", "key"], KEY_STYLE);
+    }
+}
+
+impl Debug for KeymapHelp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeymapHelp")
+            .field("rows", &self.rows)
+            .field("scroll", &self.scroll)
+            .finish()
+    }
+}
@@ -0,0 +1,38 @@
+mod barchart;
+mod braille;
+mod chart;
+mod focus;
+mod gauge;
+mod keymap;
+mod menu;
+mod mouse;
+mod palette;
+mod popup;
+mod scrollbar;
+mod sparkline;
+mod style;
+mod symbols;
+mod tabs;
+mod textarea;
+mod theme;
+mod tree;
+mod widget;
+
+pub use barchart::{Bar, BarChart, BarGroup};
+pub use braille::BrailleCanvas;
+pub use chart::{Axis, Chart, Dataset, GraphType, Marker};
+pub use focus::{FocusManager, Focusable};
+pub use gauge::{Gauge, ProgressBar};
+pub use keymap::{Binding, Key, KeyResult, Keymap, KeymapError, KeymapHelp, parse_keys};
+pub use menu::{Menu, MenuItem};
+pub use mouse::{Hit, HitMap, MouseAction, MouseRouter};
+pub use palette::{Command, CommandPalette, FuzzyMatch, fuzzy_match};
+pub use popup::{Dialog, DialogResult, Placement, Popup};
+pub use scrollbar::{ScrollState, Scrollbar};
+pub use sparkline::Sparkline;
+pub use style::Style;
+pub use tabs::{TabContainer, Tabs};
+pub use textarea::{CursorMove, TextArea};
+pub use theme::{Theme, ThemeError, ThemeRegistry, Themed};
+pub use tree::{TreeLoader, TreeNode, TreeView};
+pub use widget::{Orientation, Widget};
@@ -0,0 +1,541 @@
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::style::Color;
+use eired_display::{Annot, Annotate, Canvas, Layer, LayerId, Rect};
+
+use crate::widget::Grid;
+use crate::{Style, Theme, Themed};
+
+const HIGHLIGHT_STYLE: Style = Style::new().fg(Color::Black).bg(Color::White);
+const DISABLED_STYLE: Style = Style::new().fg(Color::DarkGrey);
+
+#[derive(Clone, PartialEq, Eq)]
+enum ItemKind {
+    Action(String),
+    Submenu(Vec<MenuItem>),
+    Separator,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// An item of [Menu].
+///
+/// # Examples
+///
+/// ```
+/// # use eired::MenuItem;
+/// let item = MenuItem::new("save", "Save").accelerator('s');
+///
+/// assert_eq!(item.id(), Some("save"));
+/// assert!(MenuItem::separator().is_separator());
+/// assert!(!MenuItem::new("quit", "Quit").disabled(true).is_enabled());
+/// ```
+pub struct MenuItem {
+    kind: ItemKind,
+    label: String,
+    accelerator: Option<char>,
+    is_disabled: bool,
+}
+
+impl MenuItem {
+    /// Create new item that reported by `id` when chosen.
+    pub fn new<I: Into<String>, L: Into<String>>(id: I, label: L) -> Self {
+        Self {
+            kind: ItemKind::Action(id.into()),
+            label: label.into(),
+            accelerator: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Create new item that opens nested `items`.
+    pub fn submenu<L: Into<String>>(label: L, items: Vec<MenuItem>) -> Self {
+        Self {
+            kind: ItemKind::Submenu(items),
+            label: label.into(),
+            accelerator: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Create new separator line.
+    pub fn separator() -> Self {
+        Self {
+            kind: ItemKind::Separator,
+            label: String::new(),
+            accelerator: None,
+            is_disabled: true,
+        }
+    }
+
+    /// Sets key that activates the item while the menu is open.
+    pub fn accelerator(self, key: char) -> Self {
+        Self {
+            accelerator: Some(key),
+            ..self
+        }
+    }
+
+    /// Sets disabled state, disabled items can't be selected.
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        Self {
+            is_disabled: is_disabled || self.is_separator(),
+            ..self
+        }
+    }
+
+    /// Returns id, `None` was not an action.
+    pub fn id(&self) -> Option<&str> {
+        match &self.kind {
+            ItemKind::Action(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Returns label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns `true` was selectable.
+    pub fn is_enabled(&self) -> bool {
+        !self.is_disabled
+    }
+
+    /// Returns `true` was a separator.
+    pub fn is_separator(&self) -> bool {
+        self.kind == ItemKind::Separator
+    }
+
+    fn children(&self) -> Option<&[MenuItem]> {
+        match &self.kind {
+            ItemKind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns width of `label  hint`.
+    fn width(&self) -> u16 {
+        let hint = self.hint().map_or(0, |h| h.chars().count() + 2);
+
+        (self.label.chars().count() + hint) as u16
+    }
+
+    fn hint(&self) -> Option<String> {
+        match (&self.kind, self.accelerator) {
+            (ItemKind::Submenu(_), _) => Some("▶".to_string()),
+            (_, Some(key)) => Some(key.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl Debug for MenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MenuItem")
+            .field("label", &self.label)
+            .field("id", &self.id())
+            .field("accelerator", &self.accelerator)
+            .field("is_disabled", &self.is_disabled)
+            .field("children", &self.children())
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A dropdown or context menu that opens at an anchor.
+///
+/// The menu flips to the left or upward of anchor when it would overflow the screen, submenus
+/// open beside the parent in the same way.
+/// While open, [`input`](Menu::input) captures all keys.
+///
+/// | Key | Action |
+/// | --- | --- |
+/// | Up, Down | Selects enabled item |
+/// | Right, Enter | Opens submenu, or chooses item |
+/// | Left, Esc | Closes submenu, or the menu |
+/// | Accelerator | Activates item of current menu |
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Menu;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use eired::MenuItem;
+///
+/// let mut menu = Menu::new(vec![
+///     MenuItem::new("open", "Open"),
+///     MenuItem::separator(),
+///     MenuItem::new("save", "Save").accelerator('s'),
+/// ]);
+///
+/// menu.open_at((0, 0));
+/// menu.input(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+///
+/// assert_eq!(menu.take_chosen(), Some("save".to_string()));
+/// assert!(!menu.is_open());
+/// ```
+pub struct Menu {
+    items: Vec<MenuItem>,
+    anchor: (u16, u16),
+    path: Vec<usize>,
+    chosen: Option<String>,
+    style: Style,
+    highlight_style: Style,
+    disabled_style: Style,
+}
+
+impl Menu {
+    /// Create new closed menu.
+    pub fn new(items: Vec<MenuItem>) -> Self {
+        Self {
+            items,
+            anchor: (0, 0),
+            path: vec![],
+            chosen: None,
+            style: Style::new(),
+            highlight_style: HIGHLIGHT_STYLE,
+            disabled_style: DISABLED_STYLE,
+        }
+    }
+
+    /// Returns items ref.
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    /// Opens the menu at `anchor`, the previous chosen item is cleared.
+    ///
+    /// A menu without items is not opened.
+    pub fn open_at(&mut self, anchor: (u16, u16)) {
+        self.anchor = anchor;
+        self.chosen = None;
+
+        if self.items.is_empty() {
+            self.path.clear();
+        } else {
+            self.path = vec![first_enabled(&self.items).unwrap_or(0)];
+        }
+    }
+
+    /// Closes the menu and submenus.
+    pub fn close(&mut self) {
+        self.path.clear();
+    }
+
+    /// Returns `true` was opened.
+    pub fn is_open(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Returns indices of selected item in each opened menu.
+    pub fn selected_path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Takes id of chosen item.
+    pub fn take_chosen(&mut self) -> Option<String> {
+        self.chosen.take()
+    }
+
+    /// Sets style of items and border.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of selected item.
+    pub fn set_highlight_style(&mut self, style: Style) {
+        self.highlight_style = style;
+    }
+
+    /// Sets style of disabled items and separators.
+    pub fn set_disabled_style(&mut self, style: Style) {
+        self.disabled_style = style;
+    }
+
+    /// Returns items of each opened menu.
+    fn levels(&self) -> Vec<&[MenuItem]> {
+        let mut levels = vec![];
+        let mut items = &self.items[..];
+
+        for (depth, idx) in self.path.iter().enumerate() {
+            levels.push(items);
+
+            if depth + 1 == self.path.len() {
+                break;
+            }
+
+            match items.get(*idx).and_then(|i| i.children()) {
+                Some(children) => items = children,
+                None => break,
+            }
+        }
+
+        levels
+    }
+
+    fn current(&self) -> Option<(&[MenuItem], usize)> {
+        let items = *self.levels().last()?;
+
+        Some((items, *self.path.last()?))
+    }
+
+    fn move_selection(&mut self, is_forward: bool) {
+        let Some((items, idx)) = self.current() else {
+            return;
+        };
+
+        let len = items.len();
+        let next = (1..=len)
+            .map(|step| {
+                if is_forward {
+                    (idx + step) % len
+                } else {
+                    (idx + len * 2 - step) % len
+                }
+            })
+            .find(|i| items[*i].is_enabled());
+
+        if let (Some(next), Some(last)) = (next, self.path.last_mut()) {
+            *last = next;
+        }
+    }
+
+    fn activate(&mut self, idx: usize) {
+        let Some((items, _)) = self.current() else {
+            return;
+        };
+        let Some(item) = items.get(idx).filter(|i| i.is_enabled()) else {
+            return;
+        };
+
+        match &item.kind {
+            ItemKind::Action(id) => {
+                self.chosen = Some(id.clone());
+                self.close();
+            }
+            ItemKind::Submenu(children) => {
+                let first = first_enabled(children);
+
+                if let Some(last) = self.path.last_mut() {
+                    *last = idx;
+                }
+
+                if let Some(first) = first {
+                    self.path.push(first);
+                }
+            }
+            ItemKind::Separator => {}
+        }
+    }
+
+    /// Handles key event, returns `true` while open.
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        if !self.is_open() {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Up => self.move_selection(false),
+            KeyCode::Down => self.move_selection(true),
+            KeyCode::Right => {
+                if let Some((items, idx)) = self.current()
+                    && items.get(idx).is_some_and(|i| i.children().is_some())
+                {
+                    self.activate(idx);
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(idx) = self.path.last().copied() {
+                    self.activate(idx);
+                }
+            }
+            KeyCode::Left if self.path.len() > 1 => {
+                self.path.pop();
+            }
+            KeyCode::Esc => {
+                self.path.pop();
+            }
+            KeyCode::Char(c) => {
+                let found = self.current().and_then(|(items, _)| {
+                    items
+                        .iter()
+                        .position(|i| i.accelerator.is_some_and(|a| a.eq_ignore_ascii_case(&c)))
+                });
+
+                if let Some(idx) = found {
+                    self.activate(idx);
+                }
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    /// Returns areas of each opened menu on `screen`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Menu;
+    /// use eired::MenuItem;
+    /// use eired_display::Rect;
+    ///
+    /// let mut menu = Menu::new(vec![MenuItem::new("a", "A")]);
+    ///
+    /// menu.open_at((9, 9));
+    ///
+    /// // Flipped to the left and upward of anchor
+    /// assert_eq!(menu.areas(Rect(10, 10))[0].base_pos(), (5, 7));
+    /// ```
+    pub fn areas(&self, screen: Rect) -> Vec<Annot<Rect>> {
+        let mut areas: Vec<Annot<Rect>> = vec![];
+
+        for (depth, items) in self.levels().into_iter().enumerate() {
+            let width = items.iter().map(|i| i.width()).max().unwrap_or(0) + 4;
+            let height = items.len() as u16 + 2;
+            let (width, height) = (width.min(screen.0), height.min(screen.1));
+
+            let (x, y) = match areas.last() {
+                None => {
+                    let (ax, ay) = self.anchor;
+
+                    (
+                        flip(ax, width, screen.0, ax.saturating_add(1)),
+                        flip(ay, height, screen.1, ay.saturating_add(1)),
+                    )
+                }
+                Some(parent) => {
+                    let (px, py) = parent.base_pos();
+                    let row = py + self.path[depth - 1] as u16;
+
+                    (
+                        flip(px + parent.width(), width, screen.0, px),
+                        row.min(screen.1 - height),
+                    )
+                }
+            };
+
+            areas.push(Rect(width, height).annotate((x, y)));
+        }
+
+        areas
+    }
+
+    /// Overlaps opened menus on the top of `canvas`, returns ids of the added layers from the
+    /// root menu to the deepest.
+    ///
+    /// Remove them by [`Canvas::take_layer`] on close or before showing again.
+    /// Returns empty was closed.
+    pub fn show(&self, canvas: &mut Canvas, screen: Rect) -> Vec<LayerId> {
+        let mut ids = vec![];
+
+        if !self.is_open() {
+            return ids;
+        }
+
+        for ((items, area), selected) in self
+            .levels()
+            .into_iter()
+            .zip(self.areas(screen))
+            .zip(self.path.iter())
+        {
+            let mut layer = Layer::default();
+
+            self.render_level(items, *selected, &area)
+                .write_to(&mut layer, (0, 0));
+            ids.push(canvas.overlap_layer(layer.annotate(area.base_pos())));
+        }
+
+        ids
+    }
+
+    fn render_level(&self, items: &[MenuItem], selected: usize, area: &Annot<Rect>) -> Grid {
+        let (width, height) = (area.width(), area.height());
+        let mut grid = Grid::new(width, height);
+
+        grid.fill((0, 0), (width, height), self.style.cell(' '));
+        grid.draw_border((0, 0), (width, height), self.style);
+
+        for (i, item) in items
+            .iter()
+            .enumerate()
+            .take(height.saturating_sub(2) as usize)
+        {
+            let y = i as u16 + 1;
+
+            if item.is_separator() {
+                grid.set(0, y, self.style.cell('├'));
+                grid.set(width.saturating_sub(1), y, self.style.cell('┤'));
+
+                for x in 1..width.saturating_sub(1) {
+                    grid.set(x, y, self.style.cell('─'));
+                }
+
+                continue;
+            }
+
+            let style = if !item.is_enabled() {
+                self.style.patch(self.disabled_style)
+            } else if i == selected {
+                self.style.patch(self.highlight_style)
+            } else {
+                self.style
+            };
+
+            grid.fill((1, y), (width.saturating_sub(2), 1), style.cell(' '));
+            grid.set_str(2, y, &item.label, style);
+
+            if let Some(hint) = item.hint() {
+                let hint_x = width.saturating_sub(2 + hint.chars().count() as u16);
+
+                grid.set_str(hint_x, y, &hint, style);
+            }
+        }
+
+        grid
+    }
+}
+
+impl Themed for Menu {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["menu", "popup"], Style::new());
+        theme.assign(
+            &mut self.highlight_style,
+            &["menu.selected", "selection"],
+            HIGHLIGHT_STYLE,
+        );
+        theme.assign(
+            &mut self.disabled_style,
+            &["menu.disabled", "disabled"],
+            DISABLED_STYLE,
+        );
+    }
+}
+
+impl Debug for Menu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Menu")
+            .field("items", &self.items)
+            .field("anchor", &self.anchor)
+            .field("path", &self.path)
+            .field("chosen", &self.chosen)
+            .finish()
+    }
+}
+
+fn first_enabled(items: &[MenuItem]) -> Option<usize> {
+    items.iter().position(|i| i.is_enabled())
+}
+
+/// Returns `begin` when `len` fits in `screen`, or the position that ends at `flipped_end`.
+fn flip(begin: u16, len: u16, screen: u16, flipped_end: u16) -> u16 {
+    if begin.saturating_add(len) <= screen {
+        begin
+    } else {
+        flipped_end
+            .saturating_sub(len)
+            .min(screen.saturating_sub(len))
+    }
+}
@@ -0,0 +1,397 @@
+use std::fmt::Debug;
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use eired_display::{Canvas, Window};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A result of [HitMap::hit_test].
+pub struct Hit<Id> {
+    /// A registered id.
+    pub id: Id,
+
+    /// Coords relative to the hit view.
+    pub pos: (u16, u16),
+}
+
+/// Registered ids of a window view.
+struct ViewEntry<'a, Id> {
+    view: usize,
+    id: Option<Id>,
+    canvas: Option<CanvasEntry<'a, Id>>,
+}
+
+/// A registered canvas of a window view.
+struct CanvasEntry<'a, Id> {
+    canvas: &'a Canvas,
+    origin: (u16, u16),
+    layers: Vec<(usize, Id)>,
+}
+
+/// A mapping of [Window] views and [Canvas] layers to ids.
+///
+/// On hit testing, the top view that covers coords is found, then layers of its canvas are walked
+/// from the top `z_index`.
+/// Unregistered layers are passed through, the view id is used when no layer is hit.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::HitMap;
+/// use eired_display::{Annotate, Canvas, Layer, Span, Window};
+///
+/// let mut canvas = Canvas::default();
+/// let mut layer = Layer::default();
+///
+/// layer.push_span_write(Span::from("[OK]").annotate((0, 0)));
+/// canvas.overlap_layer(layer.annotate((2, 1)));
+///
+/// let mut window = Window::new(10, 3);
+///
+/// window.overlap(canvas.create_view().annotate((0, 0)));
+///
+/// let mut hits = HitMap::default();
+///
+/// hits.register_view(0, "dialog");
+/// hits.register_canvas(0, &canvas, (0, 0), vec![(0, "ok")]);
+///
+/// assert_eq!(hits.hit_test(&window, 3, 1).unwrap().id, "ok");
+/// assert_eq!(hits.hit_test(&window, 0, 0).unwrap().id, "dialog");
+/// assert!(hits.hit_test(&window, 9, 2).is_none());
+/// ```
+pub struct HitMap<'a, Id> {
+    views: Vec<ViewEntry<'a, Id>>,
+}
+
+impl<Id> Default for HitMap<'_, Id> {
+    fn default() -> Self {
+        Self { views: vec![] }
+    }
+}
+
+impl<'a, Id: Clone> HitMap<'a, Id> {
+    fn entry_mut(&mut self, view: usize) -> &mut ViewEntry<'a, Id> {
+        let idx = match self.views.iter().position(|e| e.view == view) {
+            Some(idx) => idx,
+            None => {
+                self.views.push(ViewEntry {
+                    view,
+                    id: None,
+                    canvas: None,
+                });
+
+                self.views.len() - 1
+            }
+        };
+
+        &mut self.views[idx]
+    }
+
+    /// Registers `id` to the view of index in [Window].
+    pub fn register_view(&mut self, view: usize, id: Id) {
+        self.entry_mut(view).id = Some(id);
+    }
+
+    /// Registers the canvas of the view, and ids of its layers by `z_index`.
+    ///
+    /// `origin` is canvas coords of the view's left top, e.g. [`Viewport::offset`] of a scrolled
+    /// view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::HitMap;
+    /// use eired_display::{Annotate, Canvas, Layer, Span, Viewport, Window};
+    ///
+    /// let mut canvas = Canvas::default();
+    ///
+    /// for y in 0..100 {
+    ///     let mut layer = Layer::default();
+    ///
+    ///     layer.push_span_write(Span::from("item").annotate((0, 0)));
+    ///     canvas.insert(y as usize, layer.annotate((0, y)));
+    /// }
+    ///
+    /// let mut viewport = Viewport::new(4, 2);
+    ///
+    /// viewport.set_content_size(canvas.size());
+    /// viewport.scroll_by((0, 50));
+    ///
+    /// let mut window = Window::new(4, 2);
+    ///
+    /// window.overlap(viewport.create_view(&canvas).annotate((0, 0)));
+    ///
+    /// let mut hits = HitMap::default();
+    ///
+    /// hits.register_canvas(0, &canvas, viewport.offset(), vec![(50, "item 50"), (51, "item 51")]);
+    ///
+    /// assert_eq!(hits.hit_test(&window, 0, 1).unwrap().id, "item 51");
+    /// ```
+    ///
+    /// [`Viewport::offset`]: eired_display::Viewport::offset
+    pub fn register_canvas(
+        &mut self,
+        view: usize,
+        canvas: &'a Canvas,
+        origin: (u16, u16),
+        layers: Vec<(usize, Id)>,
+    ) {
+        self.entry_mut(view).canvas = Some(CanvasEntry {
+            canvas,
+            origin,
+            layers,
+        });
+    }
+
+    /// Removes all registrations.
+    pub fn clear(&mut self) {
+        self.views.clear();
+    }
+
+    /// Returns id of the top registered region at coords of `window`.
+    pub fn hit_test(&self, window: &Window, x: u16, y: u16) -> Option<Hit<Id>> {
+        let (view, pos) = window.hit_test(x, y)?;
+        let entry = self.views.iter().find(|e| e.view == view)?;
+
+        let layer_id = entry.canvas.as_ref().and_then(|c| {
+            let (x, y) = (
+                c.origin.0.saturating_add(pos.0),
+                c.origin.1.saturating_add(pos.1),
+            );
+
+            c.canvas.hits(x, y).into_iter().find_map(|z_index| {
+                c.layers
+                    .iter()
+                    .find(|(z, _)| *z == z_index)
+                    .map(|(_, id)| id.clone())
+            })
+        });
+
+        layer_id
+            .or_else(|| entry.id.clone())
+            .map(|id| Hit { id, pos })
+    }
+}
+
+impl<Id: Debug> Debug for HitMap<'_, Id> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.views.iter().map(|e| {
+                let layers = e.canvas.as_ref().map(|c| &c.layers);
+
+                (e.view, &e.id, layers)
+            }))
+            .finish()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An action of mouse that routed to an id by [MouseRouter].
+///
+/// Coords are relative to the hit view, drags are relative to the view of pressed.
+pub enum MouseAction<Id> {
+    /// A button is pressed on id.
+    Press(Id, MouseButton, (u16, u16)),
+
+    /// A button is released, after pressed on id.
+    Release(Id, MouseButton, (u16, u16)),
+
+    /// A button is pressed and released on the same id.
+    Click(Id, MouseButton, (u16, u16)),
+
+    /// The pointer is dragged from the pressed position, id is kept while dragging.
+    Drag(Id, MouseButton, (u16, u16), (u16, u16)),
+
+    /// The pointer enters id.
+    Enter(Id),
+
+    /// The pointer leaves id.
+    Leave(Id),
+
+    /// The pointer moves on id.
+    Hover(Id, (u16, u16)),
+
+    /// The wheel scrolls on id by `(x, y)` steps.
+    Scroll(Id, (i8, i8)),
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A router that converts mouse events to [MouseAction] by [HitMap].
+///
+/// # Examples
+///
+/// ```
+/// # use eired::MouseRouter;
+/// use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+/// use eired::{HitMap, MouseAction};
+/// use eired_display::{Annotate, View, Window};
+///
+/// let window = Window::from_views(4, 1, vec![View::new(4, 1, vec![None; 4]).annotate((0, 0))]);
+/// let mut hits = HitMap::default();
+/// let mut router = MouseRouter::default();
+///
+/// hits.register_view(0, "button");
+///
+/// let event = |kind| MouseEvent { kind, column: 1, row: 0, modifiers: KeyModifiers::NONE };
+///
+/// router.route(event(MouseEventKind::Down(MouseButton::Left)), &hits, &window);
+///
+/// let actions = router.route(event(MouseEventKind::Up(MouseButton::Left)), &hits, &window);
+///
+/// assert!(actions.contains(&MouseAction::Click("button", MouseButton::Left, (1, 0))));
+/// ```
+pub struct MouseRouter<Id> {
+    hovered: Option<Id>,
+    pressed: Option<Pressed<Id>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A captured press, `pos` is relative to the view and `origin` is the signed position of the
+/// view.
+struct Pressed<Id> {
+    id: Id,
+    button: MouseButton,
+    pos: (u16, u16),
+    origin: (i32, i32),
+}
+
+impl<Id> Default for MouseRouter<Id> {
+    fn default() -> Self {
+        Self {
+            hovered: None,
+            pressed: None,
+        }
+    }
+}
+
+impl<Id: Clone + PartialEq> MouseRouter<Id> {
+    /// Returns hovered id.
+    pub fn hovered(&self) -> Option<&Id> {
+        self.hovered.as_ref()
+    }
+
+    /// Returns `true` was dragging.
+    pub fn is_dragging(&self) -> bool {
+        self.pressed.is_some()
+    }
+
+    /// Converts `event` to actions by the hit id on `window`.
+    pub fn route(
+        &mut self,
+        event: MouseEvent,
+        hits: &HitMap<Id>,
+        window: &Window,
+    ) -> Vec<MouseAction<Id>> {
+        let (x, y) = (event.column, event.row);
+        let hit = hits.hit_test(window, x, y);
+        let mut actions = self.update_hover(hit.as_ref());
+
+        match event.kind {
+            MouseEventKind::Down(button) => {
+                if let Some(Hit { id, pos }) = hit {
+                    let origin = window
+                        .hit_test(x, y)
+                        .and_then(|(view, _)| window.views().get(view))
+                        .map_or((0, 0), |view| view.signed_pos());
+
+                    self.pressed = Some(Pressed {
+                        id: id.clone(),
+                        button,
+                        pos,
+                        origin,
+                    });
+                    actions.push(MouseAction::Press(id, button, pos));
+                }
+            }
+            MouseEventKind::Up(button) => {
+                if let Some(Pressed {
+                    id,
+                    button: pressed_button,
+                    origin,
+                    ..
+                }) = self.pressed.take()
+                {
+                    let rel = relative(origin, (x, y));
+
+                    actions.push(MouseAction::Release(id.clone(), pressed_button, rel));
+
+                    if hit.is_some_and(|h| h.id == id) && pressed_button == button {
+                        actions.push(MouseAction::Click(id, button, rel));
+                    }
+                }
+            }
+            MouseEventKind::Drag(_) => {
+                if let Some(Pressed {
+                    id,
+                    button,
+                    pos,
+                    origin,
+                }) = &self.pressed
+                {
+                    let rel = relative(*origin, (x, y));
+
+                    actions.push(MouseAction::Drag(id.clone(), *button, *pos, rel));
+                }
+            }
+            MouseEventKind::Moved => {
+                if let Some(Hit { id, pos }) = hit {
+                    actions.push(MouseAction::Hover(id, pos));
+                }
+            }
+            MouseEventKind::ScrollDown => {
+                actions.extend(hit.map(|h| MouseAction::Scroll(h.id, (0, 1))))
+            }
+            MouseEventKind::ScrollUp => {
+                actions.extend(hit.map(|h| MouseAction::Scroll(h.id, (0, -1))))
+            }
+            MouseEventKind::ScrollRight => {
+                actions.extend(hit.map(|h| MouseAction::Scroll(h.id, (1, 0))))
+            }
+            MouseEventKind::ScrollLeft => {
+                actions.extend(hit.map(|h| MouseAction::Scroll(h.id, (-1, 0))))
+            }
+        }
+
+        actions
+    }
+
+    fn update_hover(&mut self, hit: Option<&Hit<Id>>) -> Vec<MouseAction<Id>> {
+        let id = hit.map(|h| h.id.clone());
+
+        if self.hovered == id {
+            return vec![];
+        }
+
+        let mut actions = vec![];
+
+        if let Some(old) = self.hovered.take() {
+            actions.push(MouseAction::Leave(old));
+        }
+
+        if let Some(new) = id {
+            self.hovered = Some(new.clone());
+            actions.push(MouseAction::Enter(new));
+        }
+
+        actions
+    }
+}
+
+impl<Id: Debug> Debug for MouseRouter<Id> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MouseRouter")
+            .field("hovered", &self.hovered)
+            .field("pressed", &self.pressed)
+            .finish()
+    }
+}
+
+/// Returns `screen` relative to the view at `origin`, saturates at the left top.
+fn relative(origin: (i32, i32), screen: (u16, u16)) -> (u16, u16) {
+    let rel = |screen: u16, origin: i32| {
+        (screen as i32)
+            .saturating_sub(origin)
+            .clamp(0, u16::MAX as i32) as u16
+    };
+
+    (rel(screen.0, origin.0), rel(screen.1, origin.1))
+}
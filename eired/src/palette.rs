@@ -0,0 +1,624 @@
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect, Span};
+
+use crate::widget::Grid;
+use crate::{Focusable, ScrollState, Style, Theme, Themed, Widget};
+
+const SCORE_MATCH: i64 = 16;
+const SCORE_CONSECUTIVE: i64 = 8;
+const SCORE_GAP_START: i64 = 3;
+const BONUS_BOUNDARY: i64 = 8;
+const BONUS_CAMEL: i64 = 6;
+const MAX_LEADING_PENALTY: usize = 10;
+const CURSOR_STYLE: Style = Style::new().fg(Color::Black).bg(Color::White);
+const MATCH_STYLE: Style = Style::new().fg(Color::Yellow);
+const HIGHLIGHT_STYLE: Style = Style::new().bg(Color::DarkGrey);
+const HINT_STYLE: Style = Style::new().fg(Color::DarkGrey);
+
+#[derive(Clone, PartialEq, Eq)]
+/// A result of [fuzzy_match].
+pub struct FuzzyMatch {
+    score: i64,
+    indices: Vec<usize>,
+}
+
+impl FuzzyMatch {
+    /// Returns score, the higher is the better.
+    pub fn score(&self) -> i64 {
+        self.score
+    }
+
+    /// Returns char indices of matched chars in text.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Create new span of `text` that matched chars are styled by `match_style`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::fuzzy_match;
+    /// use crossterm::style::Color;
+    /// use eired::Style;
+    ///
+    /// let matched = fuzzy_match("fb", "foo bar").unwrap();
+    /// let span = matched.highlight("foo bar", Style::new(), Style::new().fg(Color::Yellow));
+    ///
+    /// assert_eq!(span.get(0).unwrap().fg, Color::Yellow);
+    /// assert_eq!(span.get(1).unwrap().fg, Color::Reset);
+    /// assert_eq!(span.get(4).unwrap().fg, Color::Yellow);
+    /// ```
+    pub fn highlight(&self, text: &str, style: Style, match_style: Style) -> Span {
+        text.chars()
+            .enumerate()
+            .map(|(i, ch)| {
+                if self.indices.contains(&i) {
+                    style.patch(match_style).cell(ch)
+                } else {
+                    style.cell(ch)
+                }
+            })
+            .collect()
+    }
+}
+
+impl Debug for FuzzyMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FuzzyMatch")
+            .field("score", &self.score)
+            .field("indices", &self.indices)
+            .finish()
+    }
+}
+
+/// Matches chars of `pattern` in order against `text` ignoring case.
+///
+/// Whitespaces in `pattern` are ignored.
+/// Matches at word boundaries and consecutive matches score higher, gaps and leading chars
+/// score lower.
+/// Returns `None` was not matched.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::fuzzy_match;
+/// let file = fuzzy_match("sf", "Save File").unwrap();
+/// let misfit = fuzzy_match("sf", "misfit").unwrap();
+///
+/// assert_eq!(file.indices(), &[0, 5]);
+/// assert!(file.score() > misfit.score());
+/// assert!(fuzzy_match("fs", "Save File").is_none());
+/// ```
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let pattern = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (m, n) = (pattern.len(), text.len());
+
+    if m == 0 {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: vec![],
+        });
+    }
+
+    if m > n {
+        return None;
+    }
+
+    let none = i64::MIN / 2;
+    let mut scores = vec![vec![none; n]; m];
+    let mut from = vec![vec![0usize; n]; m];
+
+    for j in 0..n {
+        if eq_ignore_case(pattern[0], text[j]) {
+            scores[0][j] = SCORE_MATCH + bonus(&text, j) - j.min(MAX_LEADING_PENALTY) as i64;
+        }
+    }
+
+    for i in 1..m {
+        // Max of `score + k` in the previous row, for gap penalty of `j - k - 1`.
+        let mut best = none;
+        let mut best_k = 0;
+
+        for j in i..n {
+            if j >= 2 && scores[i - 1][j - 2] > none {
+                let value = scores[i - 1][j - 2] + (j - 2) as i64;
+
+                if value > best {
+                    best = value;
+                    best_k = j - 2;
+                }
+            }
+
+            if !eq_ignore_case(pattern[i], text[j]) {
+                continue;
+            }
+
+            let mut score = none;
+
+            if best > none {
+                score = best - j as i64 + 1 - SCORE_GAP_START;
+                from[i][j] = best_k;
+            }
+
+            let prev = scores[i - 1][j - 1];
+
+            if prev > none && prev + SCORE_CONSECUTIVE >= score {
+                score = prev + SCORE_CONSECUTIVE;
+                from[i][j] = j - 1;
+            }
+
+            if score > none {
+                scores[i][j] = score + SCORE_MATCH + bonus(&text, j);
+            }
+        }
+    }
+
+    let (mut j, score) = scores[m - 1]
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, s)| *s > none)
+        .max_by_key(|(j, s)| (*s, std::cmp::Reverse(*j)))?;
+    let mut indices = vec![0; m];
+
+    for i in (0..m).rev() {
+        indices[i] = j;
+        j = from[i][j];
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn bonus(text: &[char], idx: usize) -> i64 {
+    let Some(prev) = idx.checked_sub(1).map(|i| text[i]) else {
+        return BONUS_BOUNDARY;
+    };
+    let ch = text[idx];
+
+    if prev.is_whitespace() || matches!(prev, '-' | '_' | '.' | '/' | ':') {
+        BONUS_BOUNDARY
+    } else if prev.is_lowercase() && ch.is_uppercase() {
+        BONUS_CAMEL
+    } else {
+        0
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A named command of [CommandPalette].
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Command;
+/// let command = Command::new("file.save", "Save File").key_hint("ctrl-s");
+///
+/// assert_eq!(command.id(), "file.save");
+/// assert_eq!(command.hint(), Some("ctrl-s"));
+/// ```
+pub struct Command {
+    id: String,
+    name: String,
+    key_hint: Option<String>,
+}
+
+impl Command {
+    /// Create new command that reported by `id` when chosen.
+    pub fn new<I: Into<String>, N: Into<String>>(id: I, name: N) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            key_hint: None,
+        }
+    }
+
+    /// Sets key hint that drawn right of name.
+    pub fn key_hint<S: Into<String>>(self, hint: S) -> Self {
+        Self {
+            key_hint: Some(hint.into()),
+            ..self
+        }
+    }
+
+    /// Returns id.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns key hint.
+    pub fn hint(&self) -> Option<&str> {
+        self.key_hint.as_deref()
+    }
+}
+
+impl Debug for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Command")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("key_hint", &self.key_hint)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A Ctrl-P style palette that filters commands by [fuzzy_match].
+///
+/// The first row is the input line, the second is a divider, and the rest lists ranked results.
+/// While open, [`input`](CommandPalette::input) captures all keys.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::CommandPalette;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use eired::Command;
+///
+/// let mut palette = CommandPalette::default();
+///
+/// palette.register(Command::new("open", "Open File"));
+/// palette.register(Command::new("quit", "Quit"));
+/// palette.open();
+/// palette.set_query("qt");
+///
+/// assert_eq!(palette.results()[0].id(), "quit");
+///
+/// palette.input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+///
+/// assert_eq!(palette.take_chosen(), Some("quit".to_string()));
+/// ```
+pub struct CommandPalette {
+    commands: Vec<Command>,
+    query: Vec<char>,
+    cursor: usize,
+    results: Vec<(usize, FuzzyMatch)>,
+    selected: usize,
+    scroll: ScrollState,
+    is_open: bool,
+    chosen: Option<String>,
+    prompt: String,
+    style: Style,
+    cursor_style: Style,
+    match_style: Style,
+    highlight_style: Style,
+    hint_style: Style,
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self {
+            commands: vec![],
+            query: vec![],
+            cursor: 0,
+            results: vec![],
+            selected: 0,
+            scroll: ScrollState::default(),
+            is_open: false,
+            chosen: None,
+            prompt: "> ".to_string(),
+            style: Style::new(),
+            cursor_style: CURSOR_STYLE,
+            match_style: MATCH_STYLE,
+            highlight_style: HIGHLIGHT_STYLE,
+            hint_style: HINT_STYLE,
+        }
+    }
+}
+
+impl CommandPalette {
+    /// Registers command, a command of the same id is replaced.
+    pub fn register(&mut self, command: Command) {
+        match self.commands.iter_mut().find(|c| c.id == command.id) {
+            Some(registered) => *registered = command,
+            None => self.commands.push(command),
+        }
+
+        self.refresh();
+    }
+
+    /// Unregisters command of `id`.
+    pub fn unregister(&mut self, id: &str) -> Option<Command> {
+        let idx = self.commands.iter().position(|c| c.id == id)?;
+        let command = self.commands.remove(idx);
+
+        self.refresh();
+
+        Some(command)
+    }
+
+    /// Returns registered commands.
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Opens the palette with empty query.
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.chosen = None;
+        self.query.clear();
+        self.cursor = 0;
+        self.refresh();
+    }
+
+    /// Closes the palette.
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Returns `true` was opened.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Returns query.
+    pub fn query(&self) -> String {
+        self.query.iter().collect()
+    }
+
+    /// Replaces query, the cursor moves to end.
+    pub fn set_query(&mut self, query: &str) {
+        self.query = query.chars().collect();
+        self.cursor = self.query.len();
+        self.refresh();
+    }
+
+    /// Returns matched commands by rank.
+    pub fn results(&self) -> Vec<&Command> {
+        self.results
+            .iter()
+            .map(|(idx, _)| &self.commands[*idx])
+            .collect()
+    }
+
+    /// Returns selected command.
+    pub fn selected(&self) -> Option<&Command> {
+        self.results
+            .get(self.selected)
+            .map(|(idx, _)| &self.commands[*idx])
+    }
+
+    /// Takes id of chosen command.
+    pub fn take_chosen(&mut self) -> Option<String> {
+        self.chosen.take()
+    }
+
+    /// Sets prompt of the input line.
+    pub fn set_prompt<S: Into<String>>(&mut self, prompt: S) {
+        self.prompt = prompt.into();
+    }
+
+    /// Sets style of palette.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of cursor in query.
+    pub fn set_cursor_style(&mut self, style: Style) {
+        self.cursor_style = style;
+    }
+
+    /// Sets style of matched chars.
+    pub fn set_match_style(&mut self, style: Style) {
+        self.match_style = style;
+    }
+
+    /// Sets style of selected row.
+    pub fn set_highlight_style(&mut self, style: Style) {
+        self.highlight_style = style;
+    }
+
+    /// Sets style of key hints.
+    pub fn set_hint_style(&mut self, style: Style) {
+        self.hint_style = style;
+    }
+
+    /// Ranks commands by score, ties keep registration order.
+    fn refresh(&mut self) {
+        let query = self.query();
+        let mut results = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| fuzzy_match(&query, &c.name).map(|m| (i, m)))
+            .collect::<Vec<_>>();
+
+        results.sort_by_key(|(_, m)| std::cmp::Reverse(m.score));
+
+        self.results = results;
+        self.selected = 0;
+        self.scroll.set_content_len(self.results.len());
+        self.scroll.scroll_to_top();
+    }
+
+    fn select_by(&mut self, delta: isize) {
+        if self.results.is_empty() {
+            return;
+        }
+
+        let last = self.results.len() - 1;
+
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+        self.scroll.ensure_visible(self.selected);
+    }
+
+    /// Handles key event, returns `true` while open.
+    ///
+    /// | Key | Action |
+    /// | --- | --- |
+    /// | Chars, Backspace, Delete, Left, Right, Home, End | Edits query |
+    /// | Up, Down, Ctrl-p, Ctrl-n, PageUp, PageDown | Selects result |
+    /// | Enter | Chooses selected command |
+    /// | Esc | Closes |
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        if !self.is_open {
+            return false;
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let page = self.scroll.viewport_len().max(1) as isize;
+
+        match key.code {
+            KeyCode::Up => self.select_by(-1),
+            KeyCode::Down => self.select_by(1),
+            KeyCode::Char('p') if ctrl => self.select_by(-1),
+            KeyCode::Char('n') if ctrl => self.select_by(1),
+            KeyCode::PageUp => self.select_by(-page),
+            KeyCode::PageDown => self.select_by(page),
+            KeyCode::Enter => {
+                if let Some(command) = self.selected() {
+                    self.chosen = Some(command.id.clone());
+                    self.close();
+                }
+            }
+            KeyCode::Esc => self.close(),
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.query.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.query.len(),
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.query.remove(self.cursor);
+                self.refresh();
+            }
+            KeyCode::Delete if self.cursor < self.query.len() => {
+                self.query.remove(self.cursor);
+                self.refresh();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.insert(self.cursor, c);
+                self.cursor += 1;
+                self.refresh();
+            }
+            _ => {}
+        }
+
+        true
+    }
+}
+
+impl Widget for CommandPalette {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let (width, height) = (area.width(), area.height());
+        let mut grid = Grid::new(width, height);
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        grid.fill((0, 0), (width, height), self.style.cell(' '));
+
+        let prompt_len = grid.set_str(0, 0, &self.prompt, self.style);
+
+        grid.set_str(prompt_len, 0, &self.query(), self.style);
+
+        let cursor_x = prompt_len.saturating_add(self.cursor as u16);
+
+        if let Some(Some(cell)) = grid.get_mut(cursor_x, 0) {
+            self.cursor_style.apply(cell);
+        }
+
+        for x in 0..width {
+            grid.set(x, 1, self.style.cell('─'));
+        }
+
+        self.scroll
+            .set_viewport_len(height.saturating_sub(2) as usize);
+        self.scroll.ensure_visible(self.selected);
+
+        for (row, i) in self.scroll.visible_range().enumerate() {
+            let (idx, matched) = &self.results[i];
+            let command = &self.commands[*idx];
+            let y = row as u16 + 2;
+            let style = if i == self.selected {
+                self.style.patch(self.highlight_style)
+            } else {
+                self.style
+            };
+
+            grid.fill((0, y), (width, 1), style.cell(' '));
+
+            let name = matched.highlight(&command.name, style, self.match_style);
+
+            for (x, cell) in name.to_vec().into_iter().enumerate() {
+                grid.set(x as u16 + 1, y, cell);
+            }
+
+            if let Some(hint) = &command.key_hint {
+                let hint_x = width.saturating_sub(hint.chars().count() as u16 + 1);
+
+                grid.set_str(hint_x, y, hint, style.patch(self.hint_style));
+            }
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Focusable for CommandPalette {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input(key)
+    }
+
+    /// Renders without the cursor while unfocused.
+    fn render_focus(&mut self, layer: &mut Layer, area: Annot<Rect>, is_focused: bool) {
+        if is_focused {
+            return self.render(layer, area);
+        }
+
+        let cursor_style = std::mem::take(&mut self.cursor_style);
+
+        self.render(layer, area);
+        self.cursor_style = cursor_style;
+    }
+}
+
+impl Themed for CommandPalette {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["palette", "popup"], Style::new());
+        theme.assign(
+            &mut self.cursor_style,
+            &["palette.cursor", "cursor"],
+            CURSOR_STYLE,
+        );
+        theme.assign(
+            &mut self.match_style,
+            &["palette.match", "match"],
+            MATCH_STYLE,
+        );
+        theme.assign(
+            &mut self.highlight_style,
+            &["palette.selected", "selection"],
+            HIGHLIGHT_STYLE,
+        );
+        theme.assign(&mut self.hint_style, &["palette.hint", "hint"], HINT_STYLE);
+    }
+}
+
+impl Debug for CommandPalette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandPalette")
+            .field("commands", &self.commands)
+            .field("query", &self.query)
+            .field("cursor", &self.cursor)
+            .field("selected", &self.selected)
+            .field("is_open", &self.is_open)
+            .finish()
+    }
+}
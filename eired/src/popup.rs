@@ -0,0 +1,581 @@
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::style::Color;
+use eired_display::{Annot, Annotate, Blend, Canvas, Cell, Layer, LayerId, Rect};
+
+use crate::widget::Grid;
+use crate::{Style, Theme, Themed};
+
+const DIM_STYLE: Style = Style::new().fg(Color::DarkGrey);
+const HIGHLIGHT_STYLE: Style = Style::new().fg(Color::Black).bg(Color::White);
+const SHADOW_STYLE: Style = Style::new().fg(Color::DarkGrey).bg(Color::Black);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// A position of [Popup] to center on.
+pub enum Placement {
+    /// Centers on the screen.
+    #[default]
+    Center,
+
+    /// Centers on the area, the popup is kept inside the screen.
+    Anchor(Annot<Rect>),
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A bordered box that drawn on the top of [Canvas].
+///
+/// The area beneath the box is cleared, the rest of screen can be dimmed and the box can drop a
+/// shadow.
+/// While open, [`input`](Popup::input) captures all keys until dismissed by `Esc`.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Popup;
+/// use eired_display::{Annotate, Canvas, Rect, Span};
+///
+/// let popup = Popup::new(4, 3);
+/// let mut canvas = Canvas::default();
+///
+/// let ids = popup.show(&mut canvas, Rect(8, 5), |layer, area| {
+///     let (x, y) = area.base_pos();
+///
+///     layer.push_span_write(Span::from("ok").annotate((x, y)));
+/// });
+///
+/// assert_eq!(ids.len(), 1);
+///
+/// let view = canvas.create_view();
+/// let row = view.get_line(2).iter().map(|c| c.map_or(' ', |c| c.ch)).collect::<String>();
+///
+/// assert_eq!(row, "  │ok│");
+/// ```
+pub struct Popup {
+    size: (u16, u16),
+    placement: Placement,
+    title: Option<String>,
+    is_open: bool,
+    is_dim: bool,
+    has_shadow: bool,
+    style: Style,
+    border_style: Style,
+    dim_style: Style,
+    shadow_style: Style,
+}
+
+impl Popup {
+    /// Create new opened popup of `width * height` that includes the border.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            size: (width, height),
+            placement: Placement::Center,
+            title: None,
+            is_open: true,
+            is_dim: false,
+            has_shadow: false,
+            style: Style::new(),
+            border_style: Style::new(),
+            dim_style: DIM_STYLE,
+            shadow_style: SHADOW_STYLE,
+        }
+    }
+
+    /// Returns size that includes the border.
+    pub fn size(&self) -> (u16, u16) {
+        self.size
+    }
+
+    /// Sets size that includes the border.
+    pub fn set_size(&mut self, width: u16, height: u16) {
+        self.size = (width, height);
+    }
+
+    /// Sets position to center on.
+    pub fn set_placement(&mut self, placement: Placement) {
+        self.placement = placement;
+    }
+
+    /// Sets title on the top border.
+    pub fn set_title<S: Into<String>>(&mut self, title: Option<S>) {
+        self.title = title.map(Into::into);
+    }
+
+    /// Dims the screen beneath the popup.
+    pub fn set_dim(&mut self, is_dim: bool) {
+        self.is_dim = is_dim;
+    }
+
+    /// Drops a shadow to the right and bottom of the box.
+    pub fn set_shadow(&mut self, has_shadow: bool) {
+        self.has_shadow = has_shadow;
+    }
+
+    /// Sets style of the box inside.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of border and title.
+    pub fn set_border_style(&mut self, style: Style) {
+        self.border_style = style;
+    }
+
+    /// Sets style that applied to the dimmed screen.
+    pub fn set_dim_style(&mut self, style: Style) {
+        self.dim_style = style;
+    }
+
+    /// Sets style that applied to the shadow.
+    pub fn set_shadow_style(&mut self, style: Style) {
+        self.shadow_style = style;
+    }
+
+    /// Opens the popup.
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    /// Closes the popup.
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Returns `true` was opened.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Handles key event, `Esc` closes the popup.
+    ///
+    /// Returns `true` while open, so keys are captured from the widgets beneath.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Popup;
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    ///
+    /// let mut popup = Popup::new(10, 3);
+    ///
+    /// assert!(popup.input(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)));
+    /// assert!(popup.input(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+    /// assert!(!popup.is_open());
+    /// assert!(!popup.input(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)));
+    /// ```
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        if !self.is_open {
+            return false;
+        }
+
+        if key.code == KeyCode::Esc {
+            self.close();
+        }
+
+        true
+    }
+
+    /// Returns area of the box on `screen`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Popup;
+    /// use eired::Placement;
+    /// use eired_display::{Annotate, Rect};
+    ///
+    /// let mut popup = Popup::new(4, 2);
+    ///
+    /// assert_eq!(popup.area(Rect(10, 6)).base_pos(), (3, 2));
+    ///
+    /// popup.set_placement(Placement::Anchor(Rect(2, 1).annotate((9, 0))));
+    ///
+    /// assert_eq!(popup.area(Rect(10, 6)).base_pos(), (6, 0));
+    /// ```
+    pub fn area(&self, screen: Rect) -> Annot<Rect> {
+        let (width, height) = (self.size.0.min(screen.0), self.size.1.min(screen.1));
+        let (x, y) = match self.placement {
+            Placement::Center => ((screen.0 - width) / 2, (screen.1 - height) / 2),
+            Placement::Anchor(anchor) => {
+                let (ax, ay) = anchor.base_pos();
+                let center_x = (ax as i32 + anchor.width() as i32 / 2 - width as i32 / 2)
+                    .clamp(0, (screen.0 - width) as i32);
+                let center_y = (ay as i32 + anchor.height() as i32 / 2 - height as i32 / 2)
+                    .clamp(0, (screen.1 - height) as i32);
+
+                (center_x as u16, center_y as u16)
+            }
+        };
+
+        Rect(width, height).annotate((x, y))
+    }
+
+    /// Returns area inside the border on `screen`.
+    pub fn inner_area(&self, screen: Rect) -> Annot<Rect> {
+        let area = self.area(screen);
+        let (x, y) = area.base_pos();
+
+        Rect(
+            area.width().saturating_sub(2),
+            area.height().saturating_sub(2),
+        )
+        .annotate((x + 1, y + 1))
+    }
+
+    /// Overlaps the popup on the top of `canvas`, returns ids of the added layers.
+    ///
+    /// The ids are the backdrop layer of dim and shadow if needed, then the box layer.
+    /// Remove them by [`Canvas::take_layer`] on dismiss or before showing again.
+    /// `content` renders into the box layer, the area is relative to the box.
+    /// Returns empty was closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Popup;
+    /// use eired_display::{Canvas, Rect};
+    ///
+    /// let mut popup = Popup::new(4, 3);
+    /// let mut canvas = Canvas::default();
+    ///
+    /// popup.set_dim(true);
+    ///
+    /// let ids = popup.show(&mut canvas, Rect(8, 5), |_, _| {});
+    ///
+    /// assert_eq!(ids.len(), 2);
+    ///
+    /// for id in ids {
+    ///     canvas.take_layer(id);
+    /// }
+    ///
+    /// assert!(canvas.inner_vec().is_empty());
+    /// ```
+    pub fn show<F: FnOnce(&mut Layer, Annot<Rect>)>(
+        &self,
+        canvas: &mut Canvas,
+        screen: Rect,
+        content: F,
+    ) -> Vec<LayerId> {
+        if !self.is_open {
+            return vec![];
+        }
+
+        let area = self.area(screen);
+        let mut ids = vec![];
+
+        if self.is_dim || self.has_shadow {
+            let mut backdrop = Layer::default();
+
+            self.backdrop(screen, area).write_to(&mut backdrop, (0, 0));
+            ids.push(canvas.overlap_layer(backdrop.annotate((0, 0))));
+        }
+
+        let (width, height) = (area.width(), area.height());
+        let mut grid = Grid::new(width, height);
+        let mut layer = Layer::default();
+
+        grid.fill((0, 0), (width, height), self.style.cell(' '));
+        grid.draw_border((0, 0), (width, height), self.style.patch(self.border_style));
+
+        if let Some(title) = &self.title {
+            let title = format!(" {} ", title)
+                .chars()
+                .take(width.saturating_sub(2) as usize)
+                .collect::<String>();
+
+            grid.set_str(1, 0, &title, self.style.patch(self.border_style));
+        }
+
+        grid.write_to(&mut layer, (0, 0));
+        content(
+            &mut layer,
+            Rect(width.saturating_sub(2), height.saturating_sub(2)).annotate((1, 1)),
+        );
+        ids.push(canvas.overlap_layer(layer.annotate(area.base_pos())));
+
+        ids
+    }
+
+    /// Creates overlay cells of dimmed screen and shadow.
+    ///
+    /// The chars beneath are kept, and the colors of style are blended over them.
+    fn backdrop(&self, screen: Rect, area: Annot<Rect>) -> Grid {
+        let mut grid = Grid::new(screen.0, screen.1);
+        let shadow_style = if self.is_dim {
+            self.dim_style.patch(self.shadow_style)
+        } else {
+            self.shadow_style
+        };
+
+        if self.is_dim {
+            grid.fill((0, 0), (screen.0, screen.1), overlay(self.dim_style));
+        }
+
+        if self.has_shadow {
+            let (x, y) = area.base_pos();
+            let (right, bottom) = area.outer_apex_pos();
+            let shadow = (y + 1..=bottom)
+                .map(|sy| (right, sy))
+                .chain((x + 1..right).map(|sx| (sx, bottom)));
+
+            for (sx, sy) in shadow {
+                grid.set(sx, sy, overlay(shadow_style));
+            }
+        }
+
+        grid
+    }
+}
+
+impl Themed for Popup {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["popup"], Style::new());
+        theme.assign(
+            &mut self.border_style,
+            &["popup.border", "border.focused", "border"],
+            Style::new(),
+        );
+        theme.assign(&mut self.dim_style, &["popup.dim", "dim"], DIM_STYLE);
+        theme.assign(
+            &mut self.shadow_style,
+            &["popup.shadow", "shadow"],
+            SHADOW_STYLE,
+        );
+    }
+}
+
+impl Debug for Popup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Popup")
+            .field("size", &self.size)
+            .field("placement", &self.placement)
+            .field("title", &self.title)
+            .field("is_open", &self.is_open)
+            .field("is_dim", &self.is_dim)
+            .field("has_shadow", &self.has_shadow)
+            .finish()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A result of [Dialog].
+pub enum DialogResult {
+    /// The button of index was chosen.
+    Button(usize),
+
+    /// Dismissed by `Esc`.
+    Dismissed,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A modal [Popup] of message and buttons.
+///
+/// The popup is sized to fit the message and buttons.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Dialog;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use eired::DialogResult;
+///
+/// let mut dialog = Dialog::new("Save changes?", vec!["Yes", "No"]);
+///
+/// dialog.input(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE));
+/// dialog.input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+///
+/// assert_eq!(dialog.take_result(), Some(DialogResult::Button(1)));
+/// assert!(!dialog.is_open());
+/// ```
+pub struct Dialog {
+    popup: Popup,
+    message: Vec<String>,
+    buttons: Vec<String>,
+    selected: usize,
+    result: Option<DialogResult>,
+    button_style: Style,
+    highlight_style: Style,
+}
+
+impl Dialog {
+    /// Create new opened dialog.
+    pub fn new<M: Into<String>, S: Into<String>>(message: M, buttons: Vec<S>) -> Self {
+        let message = message.into().lines().map(String::from).collect::<Vec<_>>();
+        let buttons = buttons.into_iter().map(Into::into).collect::<Vec<String>>();
+        let message_width = message.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let width = u16::try_from(message_width.max(buttons_width(&buttons)))
+            .unwrap_or(u16::MAX)
+            .saturating_add(4);
+        let height = u16::try_from(message.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(4);
+        let mut popup = Popup::new(width, height);
+
+        popup.set_shadow(true);
+
+        Self {
+            popup,
+            message,
+            buttons,
+            selected: 0,
+            result: None,
+            button_style: Style::new(),
+            highlight_style: HIGHLIGHT_STYLE,
+        }
+    }
+
+    /// Returns popup ref.
+    pub fn popup(&self) -> &Popup {
+        &self.popup
+    }
+
+    /// Returns popup ref mut for change placement and styles.
+    pub fn popup_mut(&mut self) -> &mut Popup {
+        &mut self.popup
+    }
+
+    /// Returns `true` was opened.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    /// Opens the dialog again, the previous result is cleared.
+    pub fn open(&mut self) {
+        self.result = None;
+        self.popup.open();
+    }
+
+    /// Returns selected button index.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Takes the result.
+    pub fn take_result(&mut self) -> Option<DialogResult> {
+        self.result.take()
+    }
+
+    /// Sets style of buttons.
+    pub fn set_button_style(&mut self, style: Style) {
+        self.button_style = style;
+    }
+
+    /// Sets style of selected button.
+    pub fn set_highlight_style(&mut self, style: Style) {
+        self.highlight_style = style;
+    }
+
+    /// Handles key event, returns `true` while open.
+    ///
+    /// `Left`/`Right`/`Tab`/`BackTab` select button, `Enter` chooses it and `Esc` dismisses.
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        if !self.is_open() {
+            return false;
+        }
+
+        let len = self.buttons.len().max(1);
+
+        match key.code {
+            KeyCode::Left | KeyCode::BackTab => self.selected = (self.selected + len - 1) % len,
+            KeyCode::Right | KeyCode::Tab => self.selected = (self.selected + 1) % len,
+            KeyCode::Enter => {
+                self.result = Some(DialogResult::Button(self.selected));
+                self.popup.close();
+            }
+            KeyCode::Esc => {
+                self.result = Some(DialogResult::Dismissed);
+                self.popup.close();
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    /// Overlaps the dialog on the top of `canvas`, returns ids of the added layers.
+    ///
+    /// See [`Popup::show`].
+    pub fn show(&self, canvas: &mut Canvas, screen: Rect) -> Vec<LayerId> {
+        self.popup
+            .show(canvas, screen, |layer, area| self.render_body(layer, area))
+    }
+
+    fn render_body(&self, layer: &mut Layer, area: Annot<Rect>) {
+        let (width, height) = (area.width(), area.height());
+        let mut grid = Grid::new(width, height);
+        let style = self.popup.style;
+
+        for (y, line) in self.message.iter().enumerate().take(height as usize) {
+            grid.set_str(1, y as u16, line, style);
+        }
+
+        let buttons_y = height.saturating_sub(1);
+        let mut x = width.saturating_sub(buttons_width(&self.buttons) as u16) / 2;
+
+        for (i, button) in self.buttons.iter().enumerate() {
+            let button_style = if i == self.selected {
+                style.patch(self.highlight_style)
+            } else {
+                style.patch(self.button_style)
+            };
+
+            x = x.saturating_add(grid.set_str(
+                x,
+                buttons_y,
+                &format!("[{}]", button),
+                button_style,
+            ));
+            x = x.saturating_add(1);
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Themed for Dialog {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.popup.apply_theme(theme);
+        theme.assign(
+            &mut self.button_style,
+            &["dialog.button", "button"],
+            Style::new(),
+        );
+        theme.assign(
+            &mut self.highlight_style,
+            &["dialog.selected", "selection"],
+            HIGHLIGHT_STYLE,
+        );
+    }
+}
+
+impl Debug for Dialog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dialog")
+            .field("popup", &self.popup)
+            .field("message", &self.message)
+            .field("buttons", &self.buttons)
+            .field("selected", &self.selected)
+            .field("result", &self.result)
+            .finish()
+    }
+}
+
+/// Returns width of `[button] [button]`.
+fn buttons_width(buttons: &[String]) -> usize {
+    let len = buttons.iter().map(|b| b.chars().count() + 2).sum::<usize>();
+
+    len + buttons.len().saturating_sub(1)
+}
+
+/// Returns a cell that blends colors of `style` over the cell beneath.
+fn overlay(style: Style) -> Cell {
+    let mut cell = style.cell(' ');
+
+    cell.blend = Blend {
+        ch: true,
+        fg: style.fg.is_none(),
+        bg: style.bg.is_none(),
+    };
+
+    cell
+}
@@ -0,0 +1,361 @@
+use std::fmt::Debug;
+
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect};
+
+use crate::widget::Grid;
+use crate::{Orientation, Style, Theme, Themed, Widget};
+
+const TRACK_STYLE: Style = Style::new().fg(Color::DarkGrey);
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+/// A scroll position of content in viewport.
+///
+/// The offset is kept in `0..=content_len - viewport_len`.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::ScrollState;
+/// let mut state = ScrollState::new(100, 10);
+///
+/// state.scroll_down(95);
+/// assert_eq!(state.offset(), 90);
+///
+/// state.ensure_visible(3);
+/// assert_eq!(state.offset(), 3);
+/// ```
+pub struct ScrollState {
+    content_len: usize,
+    viewport_len: usize,
+    offset: usize,
+}
+
+impl ScrollState {
+    /// Create new state at the top.
+    pub fn new(content_len: usize, viewport_len: usize) -> Self {
+        Self {
+            content_len,
+            viewport_len,
+            offset: 0,
+        }
+    }
+
+    /// Returns length of content.
+    pub fn content_len(&self) -> usize {
+        self.content_len
+    }
+
+    /// Returns length of viewport.
+    pub fn viewport_len(&self) -> usize {
+        self.viewport_len
+    }
+
+    /// Returns offset of the first visible item.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns upper bound of offset.
+    pub fn max_offset(&self) -> usize {
+        self.content_len.saturating_sub(self.viewport_len)
+    }
+
+    /// Sets length of content, the offset is clamped.
+    pub fn set_content_len(&mut self, len: usize) {
+        self.content_len = len;
+        self.clamp();
+    }
+
+    /// Sets length of viewport, the offset is clamped.
+    pub fn set_viewport_len(&mut self, len: usize) {
+        self.viewport_len = len;
+        self.clamp();
+    }
+
+    /// Sets offset that clamped into `0..=max_offset`.
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset.min(self.max_offset());
+    }
+
+    /// Clamps offset into `0..=max_offset`.
+    pub fn clamp(&mut self) {
+        self.set_offset(self.offset);
+    }
+
+    /// Scrolls toward the top by `n`.
+    pub fn scroll_up(&mut self, n: usize) {
+        self.set_offset(self.offset.saturating_sub(n));
+    }
+
+    /// Scrolls toward the bottom by `n`.
+    pub fn scroll_down(&mut self, n: usize) {
+        self.set_offset(self.offset.saturating_add(n));
+    }
+
+    /// Scrolls toward the top by a viewport.
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.viewport_len.max(1));
+    }
+
+    /// Scrolls toward the bottom by a viewport.
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.viewport_len.max(1));
+    }
+
+    /// Scrolls to the top.
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scrolls to the bottom.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = self.max_offset();
+    }
+
+    /// Scrolls minimally to make item at `idx` visible.
+    pub fn ensure_visible(&mut self, idx: usize) {
+        if idx < self.offset {
+            self.set_offset(idx);
+        } else if self.viewport_len > 0 && idx >= self.offset + self.viewport_len {
+            self.set_offset(idx + 1 - self.viewport_len);
+        }
+    }
+
+    /// Returns `true` was the first item visible.
+    pub fn is_at_top(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Returns `true` was the last item visible.
+    pub fn is_at_bottom(&self) -> bool {
+        self.offset >= self.max_offset()
+    }
+
+    /// Returns range of visible items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::ScrollState;
+    /// let mut state = ScrollState::new(5, 3);
+    ///
+    /// state.scroll_down(1);
+    /// assert_eq!(state.visible_range(), 1..4);
+    ///
+    /// assert_eq!(ScrollState::new(2, 3).visible_range(), 0..2);
+    /// ```
+    pub fn visible_range(&self) -> std::ops::Range<usize> {
+        self.offset
+            ..self
+                .offset
+                .saturating_add(self.viewport_len)
+                .min(self.content_len)
+    }
+}
+
+impl Debug for ScrollState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScrollState")
+            .field("content_len", &self.content_len)
+            .field("viewport_len", &self.viewport_len)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A scrollbar of [ScrollState].
+///
+/// The bar is drawn on the first column (or row on horizontal) of `area`, the thumb length is
+/// proportional to the visible ratio of content.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Scrollbar;
+/// use eired::{Orientation, ScrollState, Widget};
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut scrollbar = Scrollbar::new(Orientation::Horizontal);
+/// let mut state = ScrollState::new(20, 10);
+/// let mut layer = Layer::default();
+///
+/// state.scroll_to_bottom();
+/// scrollbar.set_state(state);
+/// scrollbar.render(&mut layer, Rect(6, 1).annotate((0, 0)));
+///
+/// let chars = layer.inner()[0].inner().to_vec().iter().map(|c| c.ch).collect::<String>();
+///
+/// assert_eq!(chars, "◀──██▶");
+/// ```
+pub struct Scrollbar {
+    orientation: Orientation,
+    state: ScrollState,
+    track: char,
+    thumb: char,
+    arrows: Option<(char, char)>,
+    track_style: Style,
+    thumb_style: Style,
+    arrow_style: Style,
+}
+
+impl Scrollbar {
+    /// Create new scrollbar with default symbols of `orientation`.
+    pub fn new(orientation: Orientation) -> Self {
+        let (track, arrows) = match orientation {
+            Orientation::Vertical => ('│', ('▲', '▼')),
+            Orientation::Horizontal => ('─', ('◀', '▶')),
+        };
+
+        Self {
+            orientation,
+            state: ScrollState::default(),
+            track,
+            thumb: '█',
+            arrows: Some(arrows),
+            track_style: TRACK_STYLE,
+            thumb_style: Style::new(),
+            arrow_style: Style::new(),
+        }
+    }
+
+    /// Returns state ref.
+    pub fn state(&self) -> &ScrollState {
+        &self.state
+    }
+
+    /// Returns state ref mut.
+    pub fn state_mut(&mut self) -> &mut ScrollState {
+        &mut self.state
+    }
+
+    /// Replaces state.
+    pub fn set_state(&mut self, state: ScrollState) {
+        self.state = state;
+    }
+
+    /// Sets symbol of track.
+    pub fn set_track_symbol(&mut self, symbol: char) {
+        self.track = symbol;
+    }
+
+    /// Sets symbol of thumb.
+    pub fn set_thumb_symbol(&mut self, symbol: char) {
+        self.thumb = symbol;
+    }
+
+    /// Sets symbols of begin and end arrows, `None` hides arrows.
+    pub fn set_arrows(&mut self, arrows: Option<(char, char)>) {
+        self.arrows = arrows;
+    }
+
+    /// Sets style of track.
+    pub fn set_track_style(&mut self, style: Style) {
+        self.track_style = style;
+    }
+
+    /// Sets style of thumb.
+    pub fn set_thumb_style(&mut self, style: Style) {
+        self.thumb_style = style;
+    }
+
+    /// Sets style of arrows.
+    pub fn set_arrow_style(&mut self, style: Style) {
+        self.arrow_style = style;
+    }
+
+    /// Returns `(begin, len)` of thumb in track of `track_len`.
+    fn thumb(&self, track_len: u16) -> (u16, u16) {
+        let state = &self.state;
+        let max_offset = state.max_offset();
+
+        if max_offset == 0 || track_len == 0 {
+            return (0, track_len);
+        }
+
+        let track = track_len as usize;
+        let thumb_len = (track * state.viewport_len)
+            .div_ceil(state.content_len)
+            .clamp(1, track);
+        let thumb_begin = (state.offset.min(max_offset) * (track - thumb_len) * 2 + max_offset)
+            / (max_offset * 2);
+
+        (thumb_begin as u16, thumb_len as u16)
+    }
+}
+
+impl Widget for Scrollbar {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let (len, size) = match self.orientation {
+            Orientation::Vertical => (area.height(), (1.min(area.width()), area.height())),
+            Orientation::Horizontal => (area.width(), (area.width(), 1.min(area.height()))),
+        };
+
+        if size.0 == 0 || size.1 == 0 {
+            return;
+        }
+
+        let mut grid = Grid::new(size.0, size.1);
+        let mut set = |i: u16, cell| match self.orientation {
+            Orientation::Vertical => grid.set(0, i, cell),
+            Orientation::Horizontal => grid.set(i, 0, cell),
+        };
+
+        let track_begin = match self.arrows {
+            Some((begin, end)) if len >= 2 => {
+                set(0, self.arrow_style.cell(begin));
+                set(len - 1, self.arrow_style.cell(end));
+
+                1
+            }
+            _ => 0,
+        };
+        let track_len = len - track_begin * 2;
+        let (thumb_begin, thumb_len) = self.thumb(track_len);
+
+        for i in 0..track_len {
+            let cell = if (thumb_begin..thumb_begin + thumb_len).contains(&i) {
+                self.thumb_style.cell(self.thumb)
+            } else {
+                self.track_style.cell(self.track)
+            };
+
+            set(track_begin + i, cell);
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Themed for Scrollbar {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(
+            &mut self.track_style,
+            &["scrollbar.track", "scrollbar"],
+            TRACK_STYLE,
+        );
+        theme.assign(
+            &mut self.thumb_style,
+            &["scrollbar.thumb", "scrollbar"],
+            Style::new(),
+        );
+        theme.assign(
+            &mut self.arrow_style,
+            &["scrollbar.arrow", "scrollbar"],
+            Style::new(),
+        );
+    }
+}
+
+impl Debug for Scrollbar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scrollbar")
+            .field("orientation", &self.orientation)
+            .field("state", &self.state)
+            .field("track", &self.track)
+            .field("thumb", &self.thumb)
+            .field("arrows", &self.arrows)
+            .finish()
+    }
+}
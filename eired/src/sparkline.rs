@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+
+use eired_display::{Annot, Layer, Rect};
+
+use crate::symbols::VERTICAL_EIGHTHS;
+use crate::widget::Grid;
+use crate::{Style, Theme, Themed, Widget};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+/// A compact graph of values by the vertical eighth blocks (`▁▂▃▄▅▆▇█`).
+///
+/// Each value takes one column, the latest values are drawn when data is wider than `area`.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Sparkline;
+/// use eired::Widget;
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut sparkline = Sparkline::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+/// let mut layer = Layer::default();
+///
+/// sparkline.render(&mut layer, Rect(9, 1).annotate((0, 0)));
+///
+/// let chars = layer.inner()[0].inner().to_vec().iter().map(|c| c.ch).collect::<String>();
+///
+/// assert_eq!(chars, "▁▂▃▄▅▆▇█");
+/// ```
+pub struct Sparkline {
+    data: Vec<u64>,
+    max: Option<u64>,
+    style: Style,
+}
+
+impl Sparkline {
+    /// Create new sparkline.
+    pub fn new(data: Vec<u64>) -> Self {
+        Self {
+            data,
+            ..Self::default()
+        }
+    }
+
+    /// Returns data ref.
+    pub fn data(&self) -> &[u64] {
+        &self.data
+    }
+
+    /// Replaces data.
+    pub fn set_data(&mut self, data: Vec<u64>) {
+        self.data = data;
+    }
+
+    /// Pushes value to back of data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Sparkline;
+    /// let mut sparkline = Sparkline::default();
+    ///
+    /// sparkline.push(3);
+    /// sparkline.push(5);
+    ///
+    /// assert_eq!(sparkline.data(), &[3, 5]);
+    /// ```
+    pub fn push(&mut self, value: u64) {
+        self.data.push(value);
+    }
+
+    /// Sets upper bound of scale, the max of data is used by default.
+    pub fn set_max(&mut self, max: Option<u64>) {
+        self.max = max;
+    }
+
+    /// Sets style of blocks.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+}
+
+impl Widget for Sparkline {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let mut grid = Grid::new(area.width(), area.height());
+        let visible = &self.data[self.data.len().saturating_sub(grid.width() as usize)..];
+        let max = self
+            .max
+            .unwrap_or_else(|| visible.iter().copied().max().unwrap_or(0));
+
+        if max == 0 {
+            return;
+        }
+
+        let height = grid.height();
+
+        for (x, value) in visible.iter().enumerate() {
+            let eighths = scale(*value, max, height as u64 * 8);
+
+            draw_vertical(&mut grid, (x as u16, height), eighths, self.style);
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Themed for Sparkline {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["sparkline", "chart"], Style::new());
+    }
+}
+
+impl Debug for Sparkline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sparkline")
+            .field("data", &self.data)
+            .field("max", &self.max)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+/// Scales `value` of `0..=max` into `0..=len`.
+pub(crate) fn scale(value: u64, max: u64, len: u64) -> u64 {
+    if max == 0 {
+        return 0;
+    }
+
+    (value.min(max) as u128 * len as u128 / max as u128) as u64
+}
+
+/// Draws a column of `eighths` height upward from above of `bottom`.
+pub(crate) fn draw_vertical(grid: &mut Grid, bottom: (u16, u16), eighths: u64, style: Style) {
+    let (x, bottom_y) = bottom;
+
+    for i in 0..bottom_y {
+        let rest = eighths.saturating_sub(i as u64 * 8).min(8);
+
+        if rest == 0 {
+            break;
+        }
+
+        grid.set(
+            x,
+            bottom_y - 1 - i,
+            style.cell(VERTICAL_EIGHTHS[rest as usize]),
+        );
+    }
+}
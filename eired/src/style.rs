@@ -0,0 +1,235 @@
+use std::fmt::Debug;
+
+use crossterm::style::{Attribute, Attributes, Color};
+use eired_display::{Cell, Span};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+/// A set of colors and attributes that applies to cells.
+///
+/// `None` colors keep the cell color as is, so styles can be layered by [`patch`](Style::patch).
+/// Attributes are added to the cell attributes.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Style;
+/// use crossterm::style::Color;
+/// use eired_display::Cell;
+///
+/// let style = Style::new().fg(Color::Red);
+///
+/// assert_eq!(style.cell('A'), Cell::new_fg('A', Color::Red));
+/// ```
+pub struct Style {
+    /// A foreground color of style.
+    pub fg: Option<Color>,
+
+    /// A background color of style.
+    pub bg: Option<Color>,
+
+    /// Attributes of style.
+    pub attrs: Attributes,
+}
+
+impl Style {
+    /// Create new empty style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Attributes;
+    ///
+    /// let style = Style::new();
+    ///
+    /// assert_eq!(style, Style { fg: None, bg: None, attrs: Attributes::none() });
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            attrs: Attributes::none(),
+        }
+    }
+
+    /// Sets foreground color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    ///
+    /// let style = Style::new().fg(Color::Red);
+    ///
+    /// assert_eq!(style.fg, Some(Color::Red));
+    /// ```
+    pub const fn fg(self, color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    /// Sets background color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    ///
+    /// let style = Style::new().bg(Color::Blue);
+    ///
+    /// assert_eq!(style.bg, Some(Color::Blue));
+    /// ```
+    pub const fn bg(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    /// Adds an attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Attribute;
+    ///
+    /// let style = Style::new().attr(Attribute::Bold);
+    ///
+    /// assert!(style.attrs.has(Attribute::Bold));
+    /// ```
+    pub fn attr(self, attr: Attribute) -> Self {
+        Self {
+            attrs: self.attrs.with(attr),
+            ..self
+        }
+    }
+
+    /// Returns style that `other` overrides above `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    ///
+    /// let base = Style::new().fg(Color::Red).bg(Color::Blue);
+    /// let style = base.patch(Style::new().bg(Color::Green));
+    ///
+    /// assert_eq!(style, Style::new().fg(Color::Red).bg(Color::Green));
+    /// ```
+    pub fn patch(self, other: Style) -> Self {
+        Self {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            attrs: self.attrs | other.attrs,
+        }
+    }
+
+    /// Applies colors and attributes to `cell`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    /// use eired_display::Cell;
+    ///
+    /// let mut cell = Cell::new_fg('A', Color::Red);
+    ///
+    /// Style::new().bg(Color::Blue).apply(&mut cell);
+    ///
+    /// assert_eq!(cell.fg, Color::Red);
+    /// assert_eq!(cell.bg, Color::Blue);
+    /// ```
+    pub fn apply(&self, cell: &mut Cell) {
+        if let Some(fg) = self.fg {
+            cell.fg = fg;
+        }
+
+        if let Some(bg) = self.bg {
+            cell.bg = bg;
+        }
+
+        cell.attrs.extend(self.attrs);
+    }
+
+    /// Applies colors and attributes to all cells of `span`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    /// use eired_display::Span;
+    ///
+    /// let mut span = Span::from("Red");
+    ///
+    /// Style::new().fg(Color::Red).apply_span(&mut span);
+    ///
+    /// assert_eq!(span, Span::new_with_fg("Red", Color::Red));
+    /// ```
+    pub fn apply_span(&self, span: &mut Span) {
+        for i in 0..span.len() as usize {
+            if let Some(cell) = span.get_mut(i) {
+                self.apply(cell);
+            }
+        }
+    }
+
+    /// Create new styled cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    /// use eired_display::Cell;
+    ///
+    /// let style = Style::new().bg(Color::Blue);
+    ///
+    /// assert_eq!(style.cell('B'), Cell::new_bg('B', Color::Blue));
+    /// ```
+    pub fn cell(&self, ch: char) -> Cell {
+        let mut cell = Cell::new(ch);
+
+        self.apply(&mut cell);
+
+        cell
+    }
+
+    /// Create new styled span.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Style;
+    /// use crossterm::style::Color;
+    /// use eired_display::Span;
+    ///
+    /// let style = Style::new().fg(Color::Red);
+    ///
+    /// assert_eq!(style.span("Red text"), Span::new_with_fg("Red text", Color::Red));
+    /// ```
+    pub fn span<S: AsRef<str>>(&self, s: S) -> Span {
+        let mut span = Span::from(s.as_ref());
+
+        self.apply_span(&mut span);
+
+        span
+    }
+}
+
+impl Debug for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Style")
+            .field("fg", &self.fg)
+            .field("bg", &self.bg)
+            .field("attrs", &self.attrs)
+            .finish()
+    }
+}
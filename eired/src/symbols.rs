@@ -0,0 +1,8 @@
+/// Left aligned blocks by eighth width, index `0` is blank and `8` is full.
+pub(crate) const HORIZONTAL_EIGHTHS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// Bottom aligned blocks by eighth height, index `0` is blank and `8` is full.
+pub(crate) const VERTICAL_EIGHTHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A full block.
+pub(crate) const FULL: char = '█';
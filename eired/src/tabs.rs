@@ -0,0 +1,407 @@
+use std::fmt::Debug;
+
+use crossterm::style::Color;
+use eired_display::{Annot, Annotate, Canvas, Layer, Rect, Window};
+
+use crate::widget::Grid;
+use crate::{Style, Theme, Themed, Widget};
+
+const HIGHLIGHT_STYLE: Style = Style::new().fg(Color::Black).bg(Color::White);
+
+#[derive(Clone, PartialEq, Eq)]
+/// A header row of tab titles.
+///
+/// Titles are padded by a space and separated by the divider.
+/// The header scrolls to keep the selected tab visible when tabs exceed the width, and `'◀'` or
+/// `'▶'` marks the hidden side.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Tabs;
+/// use eired::Widget;
+/// use eired_display::{Annotate, Layer, Rect, Span};
+///
+/// let mut tabs = Tabs::new(vec!["One", "Two"]);
+/// let mut layer = Layer::default();
+///
+/// tabs.select(1);
+/// tabs.render(&mut layer, Rect(20, 1).annotate((0, 0)));
+///
+/// let chars = layer.inner()[0].inner().to_vec().iter().map(|c| c.ch).collect::<String>();
+///
+/// assert_eq!(chars, " One │ Two ");
+/// ```
+pub struct Tabs {
+    titles: Vec<String>,
+    selected: usize,
+    divider: String,
+    offset: u16,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl Tabs {
+    /// Create new tabs that selected the first.
+    pub fn new<S: Into<String>>(titles: Vec<S>) -> Self {
+        Self {
+            titles: titles.into_iter().map(Into::into).collect(),
+            selected: 0,
+            divider: "│".to_string(),
+            offset: 0,
+            style: Style::new(),
+            highlight_style: HIGHLIGHT_STYLE,
+        }
+    }
+
+    /// Returns titles ref.
+    pub fn titles(&self) -> &[String] {
+        &self.titles
+    }
+
+    /// Pushes title to back.
+    pub fn push<S: Into<String>>(&mut self, title: S) {
+        self.titles.push(title.into());
+    }
+
+    /// Removes title at `idx`, the selection is kept in range.
+    pub fn remove(&mut self, idx: usize) -> Option<String> {
+        if idx >= self.titles.len() {
+            return None;
+        }
+
+        let title = self.titles.remove(idx);
+
+        if self.selected > idx || self.selected >= self.titles.len() {
+            self.selected = self.selected.saturating_sub(1);
+        }
+
+        Some(title)
+    }
+
+    /// Returns `true` was no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    /// Returns count of tabs.
+    pub fn len(&self) -> usize {
+        self.titles.len()
+    }
+
+    /// Returns selected index.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects tab at `idx` that clamped into tabs.
+    pub fn select(&mut self, idx: usize) {
+        self.selected = idx.min(self.titles.len().saturating_sub(1));
+    }
+
+    /// Selects next tab, the last wraps to the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Tabs;
+    /// let mut tabs = Tabs::new(vec!["A", "B"]);
+    ///
+    /// tabs.next();
+    /// assert_eq!(tabs.selected(), 1);
+    ///
+    /// tabs.next();
+    /// assert_eq!(tabs.selected(), 0);
+    /// ```
+    pub fn next(&mut self) {
+        if !self.titles.is_empty() {
+            self.selected = (self.selected + 1) % self.titles.len();
+        }
+    }
+
+    /// Selects previous tab, the first wraps to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Tabs;
+    /// let mut tabs = Tabs::new(vec!["A", "B"]);
+    ///
+    /// tabs.previous();
+    /// assert_eq!(tabs.selected(), 1);
+    /// ```
+    pub fn previous(&mut self) {
+        if !self.titles.is_empty() {
+            self.selected = (self.selected + self.titles.len() - 1) % self.titles.len();
+        }
+    }
+
+    /// Sets divider between titles.
+    pub fn set_divider<S: Into<String>>(&mut self, divider: S) {
+        self.divider = divider.into();
+    }
+
+    /// Sets style of titles and divider.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of selected title.
+    pub fn set_highlight_style(&mut self, style: Style) {
+        self.highlight_style = style;
+    }
+
+    /// Returns `(begin, end)` of each padded title on the unscrolled header.
+    fn title_ranges(&self) -> Vec<(u16, u16)> {
+        let divider_len = self.divider.chars().count() as u16;
+        let mut x = 0u16;
+
+        self.titles
+            .iter()
+            .map(|title| {
+                let begin = x;
+                let len = u16::try_from(title.chars().count()).unwrap_or(u16::MAX);
+                let end = begin.saturating_add(len).saturating_add(2);
+
+                x = end.saturating_add(divider_len);
+
+                (begin, end)
+            })
+            .collect()
+    }
+}
+
+impl Widget for Tabs {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let width = area.width();
+        let ranges = self.title_ranges();
+        let Some(total) = ranges.last().map(|r| r.1) else {
+            return;
+        };
+
+        if width == 0 || area.height() == 0 {
+            return;
+        }
+
+        let is_overflow = total > width;
+        let (view_begin, view_width) = if is_overflow {
+            (1, width.saturating_sub(2))
+        } else {
+            (0, width)
+        };
+
+        if !is_overflow {
+            self.offset = 0;
+        } else if let Some(&(begin, end)) = ranges.get(self.selected) {
+            if begin < self.offset {
+                self.offset = begin;
+            } else if end > self.offset.saturating_add(view_width) {
+                self.offset = end - view_width.min(end);
+            }
+
+            self.offset = self.offset.min(total.saturating_sub(view_width));
+        }
+
+        let mut line = Grid::new(total, 1);
+
+        for (i, (title, (begin, end))) in self.titles.iter().zip(ranges.iter()).enumerate() {
+            let style = if i == self.selected {
+                self.style.patch(self.highlight_style)
+            } else {
+                self.style
+            };
+
+            line.set_str(*begin, 0, &format!(" {} ", title), style);
+
+            if i + 1 < self.titles.len() {
+                line.set_str(*end, 0, &self.divider, self.style);
+            }
+        }
+
+        let mut grid = Grid::new(width, 1);
+
+        for x in 0..view_width {
+            if let Some(cell) = line.get(self.offset.saturating_add(x), 0) {
+                grid.set(view_begin + x, 0, cell);
+            }
+        }
+
+        if is_overflow {
+            if self.offset > 0 {
+                grid.set(0, 0, self.style.cell('◀'));
+            }
+
+            if self.offset.saturating_add(view_width) < total {
+                grid.set(width - 1, 0, self.style.cell('▶'));
+            }
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+impl Themed for Tabs {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["tabs", "text"], Style::new());
+        theme.assign(
+            &mut self.highlight_style,
+            &["tabs.selected", "selection"],
+            HIGHLIGHT_STYLE,
+        );
+    }
+}
+
+impl Debug for Tabs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tabs")
+            .field("titles", &self.titles)
+            .field("selected", &self.selected)
+            .field("divider", &self.divider)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+#[derive(Default)]
+/// A container that holds a [Canvas] per tab.
+///
+/// Only the header and the canvas of active tab are composited into [Window].
+///
+/// # Examples
+///
+/// ```
+/// # use eired::TabContainer;
+/// use eired_display::{Annotate, Canvas, Layer, Rect, Span, Window};
+///
+/// let mut container = TabContainer::default();
+///
+/// container.push("Logs", Canvas::default());
+/// container.push("Stats", Canvas::default());
+///
+/// let mut layer = Layer::default();
+///
+/// layer.push_span_write(Span::from("42 req/s").annotate((0, 0)));
+/// container.page_mut(1).unwrap().overlap_layer(layer.annotate((0, 0)));
+/// container.select(1);
+///
+/// let mut window = Window::new(20, 5);
+///
+/// container.composite(&mut window, Rect(20, 5).annotate((0, 0)));
+///
+/// let vterm = eired_display::create_virtual_terminal(window.annotate((0, 0)));
+///
+/// assert_eq!(vterm.inner().to_vec()[20].unwrap().ch, '4');
+/// ```
+pub struct TabContainer {
+    tabs: Tabs,
+    pages: Vec<Canvas>,
+}
+
+impl TabContainer {
+    /// Pushes a tab with content.
+    pub fn push<S: Into<String>>(&mut self, title: S, page: Canvas) {
+        self.tabs.push(title);
+        self.pages.push(page);
+    }
+
+    /// Removes a tab at `idx`.
+    pub fn remove(&mut self, idx: usize) -> Option<(String, Canvas)> {
+        let title = self.tabs.remove(idx)?;
+
+        Some((title, self.pages.remove(idx)))
+    }
+
+    /// Returns header ref.
+    pub fn tabs(&self) -> &Tabs {
+        &self.tabs
+    }
+
+    /// Returns header ref mut for change styles.
+    pub fn tabs_mut(&mut self) -> &mut Tabs {
+        &mut self.tabs
+    }
+
+    /// Returns active index.
+    pub fn selected(&self) -> usize {
+        self.tabs.selected()
+    }
+
+    /// Selects tab at `idx`.
+    pub fn select(&mut self, idx: usize) {
+        self.tabs.select(idx);
+    }
+
+    /// Selects next tab.
+    pub fn next(&mut self) {
+        self.tabs.next();
+    }
+
+    /// Selects previous tab.
+    pub fn previous(&mut self) {
+        self.tabs.previous();
+    }
+
+    /// Returns content ref of tab at `idx`.
+    pub fn page(&self, idx: usize) -> Option<&Canvas> {
+        self.pages.get(idx)
+    }
+
+    /// Returns content ref mut of tab at `idx`.
+    pub fn page_mut(&mut self, idx: usize) -> Option<&mut Canvas> {
+        self.pages.get_mut(idx)
+    }
+
+    /// Returns content ref mut of active tab.
+    pub fn active_page_mut(&mut self) -> Option<&mut Canvas> {
+        self.pages.get_mut(self.tabs.selected())
+    }
+
+    /// Overlaps header at the first row of `area` and active content below it to `window`.
+    ///
+    /// The content is cut to fit in `area`.
+    pub fn composite(&mut self, window: &mut Window, area: Annot<Rect>) {
+        let (x, y) = area.base_pos();
+
+        if self.pages.is_empty() {
+            return;
+        }
+
+        let mut header = Layer::default();
+
+        self.tabs
+            .render(&mut header, Rect(area.width(), 1).annotate((0, 0)));
+
+        let mut header_canvas = Canvas::default();
+
+        header_canvas.overlap_layer(header.annotate((0, 0)));
+        window.overlap(header_canvas.create_view().annotate((x, y)));
+
+        if let Some(page) = self.pages.get(self.tabs.selected()) {
+            let (page_width, page_height) = page.size();
+            let visible = Rect(
+                page_width.min(area.width()),
+                page_height.min(area.height().saturating_sub(1)),
+            );
+
+            window.overlap(
+                page.create_viewport(visible.annotate((0, 0)))
+                    .annotate((x, y.saturating_add(1))),
+            );
+        }
+    }
+}
+
+impl Debug for TabContainer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TabContainer")
+            .field("tabs", &self.tabs)
+            .field("pages", &self.pages)
+            .finish()
+    }
+}
@@ -0,0 +1,1166 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect, Span};
+
+use crate::widget::write_span;
+use crate::{Focusable, Style, Theme, Themed, Widget};
+
+const HISTORY_LIMIT: usize = 256;
+const CURSOR_STYLE: Style = Style::new().fg(Color::Black).bg(Color::White);
+const SELECTION_STYLE: Style = Style::new().bg(Color::DarkBlue);
+const LINE_NUMBER_STYLE: Style = Style::new().fg(Color::DarkGrey);
+
+type Highlight = ((usize, usize), (usize, usize), Style);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A direction of cursor moving in [TextArea].
+pub enum CursorMove {
+    /// Moves to next char, or head of next line.
+    Forward,
+
+    /// Moves to previous char, or end of previous line.
+    Back,
+
+    /// Moves to previous line.
+    Up,
+
+    /// Moves to next line.
+    Down,
+
+    /// Moves to head of line.
+    Head,
+
+    /// Moves to end of line.
+    End,
+
+    /// Moves to head of first line.
+    Top,
+
+    /// Moves to end of last line.
+    Bottom,
+
+    /// Moves to head of next word.
+    WordForward,
+
+    /// Moves to head of previous word.
+    WordBack,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct Snapshot {
+    lines: Vec<Vec<char>>,
+    cursor: (usize, usize),
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A multi-line text editor widget.
+///
+/// Holds lines of text, cursor, selection, internal register and undo/redo history.
+/// Positions are `(row, col)` pairs that counted by chars.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::TextArea;
+/// use eired::CursorMove;
+///
+/// let mut textarea = TextArea::from("Hello");
+///
+/// textarea.move_cursor(CursorMove::End);
+/// textarea.insert_str(",\nWorld!");
+///
+/// assert_eq!(textarea.lines(), vec!["Hello,", "World!"]);
+///
+/// textarea.undo();
+///
+/// assert_eq!(textarea.text(), "Hello");
+/// ```
+pub struct TextArea {
+    lines: Vec<Vec<char>>,
+    cursor: (usize, usize),
+    anchor: Option<(usize, usize)>,
+    register: String,
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    typing: Option<(usize, usize)>,
+    highlights: Vec<Highlight>,
+    wrap: bool,
+    line_numbers: bool,
+    style: Style,
+    cursor_style: Style,
+    selection_style: Style,
+    line_number_style: Style,
+    scroll: (usize, usize),
+}
+
+impl TextArea {
+    /// Create new empty textarea.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let textarea = TextArea::new();
+    ///
+    /// assert_eq!(textarea.lines(), vec![""]);
+    /// assert_eq!(textarea.cursor(), (0, 0));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns copied lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let textarea = TextArea::from("A\nB");
+    ///
+    /// assert_eq!(textarea.lines(), vec!["A", "B"]);
+    /// ```
+    pub fn lines(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .map(|line| line.iter().collect())
+            .collect()
+    }
+
+    /// Returns whole text that joined by `'\n'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let textarea = TextArea::from("A\nB");
+    ///
+    /// assert_eq!(textarea.text(), "A\nB");
+    /// ```
+    pub fn text(&self) -> String {
+        self.lines().join("\n")
+    }
+
+    /// Returns cursor position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.insert_str("AB\nC");
+    ///
+    /// assert_eq!(textarea.cursor(), (1, 1));
+    /// ```
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Sets cursor position that clamped into text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("ABC\nD");
+    ///
+    /// textarea.set_cursor(5, 5);
+    ///
+    /// assert_eq!(textarea.cursor(), (1, 1));
+    /// ```
+    pub fn set_cursor(&mut self, row: usize, col: usize) {
+        let row = row.min(self.lines.len() - 1);
+        let col = col.min(self.lines[row].len());
+
+        self.cursor = (row, col);
+    }
+
+    /// Moves cursor.
+    ///
+    /// The selection is extended when selecting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use eired::CursorMove;
+    ///
+    /// let mut textarea = TextArea::from("Hello, World!\nBye");
+    ///
+    /// textarea.move_cursor(CursorMove::WordForward);
+    /// assert_eq!(textarea.cursor(), (0, 7));
+    ///
+    /// textarea.move_cursor(CursorMove::Down);
+    /// assert_eq!(textarea.cursor(), (1, 3));
+    ///
+    /// textarea.move_cursor(CursorMove::Top);
+    /// assert_eq!(textarea.cursor(), (0, 0));
+    /// ```
+    pub fn move_cursor(&mut self, cursor_move: CursorMove) {
+        let (row, col) = self.cursor;
+        let last_row = self.lines.len() - 1;
+
+        self.cursor = match cursor_move {
+            CursorMove::Forward if col < self.lines[row].len() => (row, col + 1),
+            CursorMove::Forward if row < last_row => (row + 1, 0),
+            CursorMove::Back if col > 0 => (row, col - 1),
+            CursorMove::Back if row > 0 => (row - 1, self.lines[row - 1].len()),
+            CursorMove::Up if row > 0 => (row - 1, col.min(self.lines[row - 1].len())),
+            CursorMove::Down if row < last_row => (row + 1, col.min(self.lines[row + 1].len())),
+            CursorMove::Head => (row, 0),
+            CursorMove::End => (row, self.lines[row].len()),
+            CursorMove::Top => (0, 0),
+            CursorMove::Bottom => (last_row, self.lines[last_row].len()),
+            CursorMove::WordForward => self.next_word_pos(),
+            CursorMove::WordBack => self.prev_word_pos(),
+            _ => (row, col),
+        };
+    }
+
+    fn next_word_pos(&self) -> (usize, usize) {
+        let (row, col) = self.cursor;
+        let line = &self.lines[row];
+
+        if col >= line.len() {
+            return if row + 1 < self.lines.len() {
+                (row + 1, 0)
+            } else {
+                (row, col)
+            };
+        }
+
+        let is_word = !line[col].is_whitespace();
+        let mut i = col;
+
+        while i < line.len() && line[i].is_whitespace() != is_word {
+            i += 1;
+        }
+
+        while i < line.len() && line[i].is_whitespace() {
+            i += 1;
+        }
+
+        (row, i)
+    }
+
+    fn prev_word_pos(&self) -> (usize, usize) {
+        let (row, col) = self.cursor;
+
+        if col == 0 {
+            return if row > 0 {
+                (row - 1, self.lines[row - 1].len())
+            } else {
+                (row, col)
+            };
+        }
+
+        let line = &self.lines[row];
+        let mut i = col;
+
+        while i > 0 && line[i - 1].is_whitespace() {
+            i -= 1;
+        }
+
+        while i > 0 && !line[i - 1].is_whitespace() {
+            i -= 1;
+        }
+
+        (row, i)
+    }
+
+    /// Starts selection from cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use eired::CursorMove;
+    ///
+    /// let mut textarea = TextArea::from("Hello, World!");
+    ///
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::WordForward);
+    ///
+    /// assert_eq!(textarea.selection(), Some(((0, 0), (0, 7))));
+    /// ```
+    pub fn start_selection(&mut self) {
+        self.anchor = Some(self.cursor);
+    }
+
+    /// Cancels selection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello");
+    ///
+    /// textarea.select_all();
+    /// textarea.cancel_selection();
+    ///
+    /// assert_eq!(textarea.selection(), None);
+    /// ```
+    pub fn cancel_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Selects whole text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello\nWorld");
+    ///
+    /// textarea.select_all();
+    ///
+    /// assert_eq!(textarea.selection(), Some(((0, 0), (1, 5))));
+    /// ```
+    pub fn select_all(&mut self) {
+        self.anchor = Some((0, 0));
+        self.move_cursor(CursorMove::Bottom);
+    }
+
+    /// Returns `true` was selecting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.start_selection();
+    ///
+    /// assert!(textarea.is_selecting());
+    /// ```
+    pub fn is_selecting(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    /// Returns sorted selection range.
+    ///
+    /// The end position is exclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use eired::CursorMove;
+    ///
+    /// let mut textarea = TextArea::from("Hello");
+    ///
+    /// textarea.move_cursor(CursorMove::End);
+    /// textarea.start_selection();
+    /// textarea.move_cursor(CursorMove::Head);
+    ///
+    /// assert_eq!(textarea.selection(), Some(((0, 0), (0, 5))));
+    /// ```
+    pub fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.anchor
+            .map(|anchor| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    /// Returns selected text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello\nWorld");
+    ///
+    /// textarea.select_all();
+    ///
+    /// assert_eq!(textarea.selected_text(), Some("Hello\nWorld".to_string()));
+    /// ```
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection()
+            .map(|(begin, end)| self.text_range(begin, end))
+    }
+
+    fn text_range(&self, begin: (usize, usize), end: (usize, usize)) -> String {
+        if begin.0 == end.0 {
+            return self.lines[begin.0][begin.1..end.1].iter().collect();
+        }
+
+        let mut text: String = self.lines[begin.0][begin.1..].iter().collect();
+
+        for line in &self.lines[begin.0 + 1..end.0] {
+            text.push('\n');
+            text.extend(line.iter());
+        }
+
+        text.push('\n');
+        text.extend(self.lines[end.0][..end.1].iter());
+
+        text
+    }
+
+    fn remove_range(&mut self, begin: (usize, usize), end: (usize, usize)) {
+        let tail = self.lines[end.0].split_off(end.1);
+
+        self.lines[begin.0].truncate(begin.1);
+        self.lines[begin.0].extend(tail);
+        self.lines.drain(begin.0 + 1..end.0 + 1);
+        self.cursor = begin;
+    }
+
+    fn remove_selection(&mut self) -> bool {
+        let Some((begin, end)) = self.selection() else {
+            return false;
+        };
+
+        self.anchor = None;
+        self.remove_range(begin, end);
+
+        true
+    }
+
+    fn insert_raw(&mut self, s: &str) {
+        let (row, col) = self.cursor;
+        let tail = self.lines[row].split_off(col);
+        let mut parts = s.split('\n').map(|part| part.trim_end_matches('\r'));
+
+        if let Some(first) = parts.next() {
+            self.lines[row].extend(first.chars());
+        }
+
+        let mut cur_row = row;
+
+        for part in parts {
+            cur_row += 1;
+            self.lines.insert(cur_row, part.chars().collect());
+        }
+
+        let cur_col = self.lines[cur_row].len();
+
+        self.lines[cur_row].extend(tail);
+        self.cursor = (cur_row, cur_col);
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = self.snapshot();
+
+        self.lines = snapshot.lines;
+        self.cursor = snapshot.cursor;
+        self.anchor = None;
+        self.typing = None;
+
+        current
+    }
+
+    fn push_history(&mut self) {
+        if self.undo.len() >= HISTORY_LIMIT {
+            self.undo.pop_front();
+        }
+
+        self.undo.push_back(self.snapshot());
+        self.redo.clear();
+        self.typing = None;
+    }
+
+    /// Inserts `c` at cursor.
+    ///
+    /// The selection is replaced if selecting.
+    /// Consecutive chars are undone at once until newline, other edit or cursor move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.insert_char('H');
+    /// textarea.insert_char('i');
+    ///
+    /// assert_eq!(textarea.text(), "Hi");
+    /// ```
+    pub fn insert_char(&mut self, c: char) {
+        let is_typing = c != '\n' && self.anchor.is_none() && self.typing == Some(self.cursor);
+
+        if !is_typing {
+            self.push_history();
+            self.remove_selection();
+        }
+
+        self.insert_raw(c.encode_utf8(&mut [0; 4]));
+        self.typing = (c != '\n').then_some(self.cursor);
+    }
+
+    /// Inserts `s` at cursor.
+    ///
+    /// The selection is replaced if selecting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello!");
+    ///
+    /// textarea.set_cursor(0, 5);
+    /// textarea.insert_str(",\nWorld");
+    ///
+    /// assert_eq!(textarea.lines(), vec!["Hello,", "World!"]);
+    /// ```
+    pub fn insert_str<S: AsRef<str>>(&mut self, s: S) {
+        if s.as_ref().is_empty() && self.anchor.is_none() {
+            return;
+        }
+
+        self.push_history();
+        self.remove_selection();
+        self.insert_raw(s.as_ref());
+    }
+
+    /// Splits line at cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("HelloWorld");
+    ///
+    /// textarea.set_cursor(0, 5);
+    /// textarea.insert_newline();
+    ///
+    /// assert_eq!(textarea.lines(), vec!["Hello", "World"]);
+    /// ```
+    pub fn insert_newline(&mut self) {
+        self.insert_str("\n");
+    }
+
+    /// Deletes char before cursor, or selection if selecting.
+    ///
+    /// Returns `true` was text changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello\nWorld");
+    ///
+    /// textarea.set_cursor(1, 0);
+    ///
+    /// assert!(textarea.delete_char());
+    /// assert_eq!(textarea.text(), "HelloWorld");
+    /// ```
+    pub fn delete_char(&mut self) -> bool {
+        let (row, col) = self.cursor;
+
+        if self.anchor.is_none() && row == 0 && col == 0 {
+            return false;
+        }
+
+        self.push_history();
+
+        if !self.remove_selection() {
+            let begin = if col > 0 {
+                (row, col - 1)
+            } else {
+                (row - 1, self.lines[row - 1].len())
+            };
+
+            self.remove_range(begin, (row, col));
+        }
+
+        true
+    }
+
+    /// Deletes char after cursor, or selection if selecting.
+    ///
+    /// Returns `true` was text changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello\nWorld");
+    ///
+    /// textarea.set_cursor(0, 5);
+    ///
+    /// assert!(textarea.delete_next_char());
+    /// assert_eq!(textarea.text(), "HelloWorld");
+    /// ```
+    pub fn delete_next_char(&mut self) -> bool {
+        let (row, col) = self.cursor;
+        let is_end = col >= self.lines[row].len();
+
+        if self.anchor.is_none() && is_end && row + 1 >= self.lines.len() {
+            return false;
+        }
+
+        self.push_history();
+
+        if !self.remove_selection() {
+            let end = if is_end { (row + 1, 0) } else { (row, col + 1) };
+
+            self.remove_range((row, col), end);
+        }
+
+        true
+    }
+
+    /// Copies selection to register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello");
+    ///
+    /// textarea.select_all();
+    /// textarea.copy();
+    ///
+    /// assert_eq!(textarea.register(), "Hello");
+    /// assert_eq!(textarea.text(), "Hello");
+    /// ```
+    pub fn copy(&mut self) {
+        if let Some(text) = self.selected_text() {
+            self.register = text;
+        }
+    }
+
+    /// Moves selection to register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::from("Hello");
+    ///
+    /// textarea.select_all();
+    /// textarea.cut();
+    ///
+    /// assert_eq!(textarea.register(), "Hello");
+    /// assert_eq!(textarea.text(), "");
+    /// ```
+    pub fn cut(&mut self) {
+        let Some(text) = self.selected_text() else {
+            return;
+        };
+
+        self.register = text;
+        self.push_history();
+        self.remove_selection();
+    }
+
+    /// Inserts register text at cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use eired::CursorMove;
+    ///
+    /// let mut textarea = TextArea::from("Hello");
+    ///
+    /// textarea.select_all();
+    /// textarea.copy();
+    /// textarea.cancel_selection();
+    /// textarea.paste();
+    ///
+    /// assert_eq!(textarea.text(), "HelloHello");
+    /// ```
+    pub fn paste(&mut self) {
+        if self.register.is_empty() {
+            return;
+        }
+
+        let text = self.register.clone();
+
+        self.insert_str(text);
+    }
+
+    /// Returns register text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let textarea = TextArea::new();
+    ///
+    /// assert_eq!(textarea.register(), "");
+    /// ```
+    pub fn register(&self) -> &str {
+        &self.register
+    }
+
+    /// Sets register text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.set_register("Hi");
+    /// textarea.paste();
+    ///
+    /// assert_eq!(textarea.text(), "Hi");
+    /// ```
+    pub fn set_register<S: Into<String>>(&mut self, text: S) {
+        self.register = text.into();
+    }
+
+    /// Undoes last edit.
+    ///
+    /// Returns `true` was undone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.insert_str("Hello");
+    ///
+    /// assert!(textarea.undo());
+    /// assert_eq!(textarea.text(), "");
+    /// assert!(!textarea.undo());
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo.pop_back() else {
+            return false;
+        };
+
+        let current = self.restore(snapshot);
+
+        self.redo.push(current);
+
+        true
+    }
+
+    /// Redoes last undone edit.
+    ///
+    /// Returns `true` was redone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.insert_str("Hello");
+    /// textarea.undo();
+    ///
+    /// assert!(textarea.redo());
+    /// assert_eq!(textarea.text(), "Hello");
+    /// ```
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+
+        let current = self.restore(snapshot);
+
+        self.undo.push_back(current);
+
+        true
+    }
+
+    /// Enables soft wrapping, otherwise scrolls horizontally.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+        self.scroll = (0, 0);
+    }
+
+    /// Enables line numbers.
+    pub fn set_line_numbers(&mut self, line_numbers: bool) {
+        self.line_numbers = line_numbers;
+    }
+
+    /// Sets base style of text.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of cursor cell.
+    pub fn set_cursor_style(&mut self, style: Style) {
+        self.cursor_style = style;
+    }
+
+    /// Sets style of selected cells.
+    pub fn set_selection_style(&mut self, style: Style) {
+        self.selection_style = style;
+    }
+
+    /// Sets style of line numbers.
+    pub fn set_line_number_style(&mut self, style: Style) {
+        self.line_number_style = style;
+    }
+
+    /// Adds highlight style to range of `begin..end`.
+    ///
+    /// Highlights are applied in order of added, cursor and selection styles take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use crossterm::style::Color;
+    /// use eired::{Style, Widget};
+    /// use eired_display::{Annotate, Layer, Rect};
+    ///
+    /// let mut textarea = TextArea::from("let x = 1;");
+    ///
+    /// textarea.set_cursor(0, 10);
+    /// textarea.add_highlight((0, 0), (0, 3), Style::new().fg(Color::Blue));
+    ///
+    /// let mut layer = Layer::default();
+    ///
+    /// textarea.render(&mut layer, Rect(12, 1).annotate((0, 0)));
+    ///
+    /// let line = layer.inner()[0].inner();
+    ///
+    /// assert_eq!(line.get(0).unwrap().fg, Color::Blue);
+    /// assert_eq!(line.get(3).unwrap().fg, Color::Reset);
+    /// ```
+    pub fn add_highlight(&mut self, begin: (usize, usize), end: (usize, usize), style: Style) {
+        self.highlights
+            .push((begin.min(end), begin.max(end), style));
+    }
+
+    /// Removes all highlights.
+    pub fn clear_highlights(&mut self) {
+        self.highlights.clear();
+    }
+
+    /// Handles a key event.
+    ///
+    /// Returns `true` was the key consumed.
+    ///
+    /// | Key | Action |
+    /// | --- | --- |
+    /// | Char | Insert char |
+    /// | Enter | Insert newline |
+    /// | Backspace / Delete | Delete char |
+    /// | Arrows / Home / End | Move cursor, extend selection with Shift, by word with Ctrl |
+    /// | Ctrl-a | Select all |
+    /// | Ctrl-c / Ctrl-x / Ctrl-v | Copy / Cut / Paste |
+    /// | Ctrl-z / Ctrl-y | Undo / Redo |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    ///
+    /// let mut textarea = TextArea::new();
+    ///
+    /// textarea.input(KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT));
+    /// textarea.input(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    /// textarea.input(KeyEvent::new(KeyCode::Char('B'), KeyModifiers::SHIFT));
+    ///
+    /// assert_eq!(textarea.text(), "A\nB");
+    ///
+    /// textarea.input(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL));
+    ///
+    /// assert_eq!(textarea.text(), "A\n");
+    /// ```
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+
+        let cursor_move = match key.code {
+            KeyCode::Left if ctrl => CursorMove::WordBack,
+            KeyCode::Right if ctrl => CursorMove::WordForward,
+            KeyCode::Left => CursorMove::Back,
+            KeyCode::Right => CursorMove::Forward,
+            KeyCode::Up => CursorMove::Up,
+            KeyCode::Down => CursorMove::Down,
+            KeyCode::Home if ctrl => CursorMove::Top,
+            KeyCode::End if ctrl => CursorMove::Bottom,
+            KeyCode::Home => CursorMove::Head,
+            KeyCode::End => CursorMove::End,
+            KeyCode::Char('a') if ctrl => {
+                self.select_all();
+
+                return true;
+            }
+            KeyCode::Char('c') if ctrl => {
+                self.copy();
+
+                return true;
+            }
+            KeyCode::Char('x') if ctrl => {
+                self.cut();
+
+                return true;
+            }
+            KeyCode::Char('v') if ctrl => {
+                self.paste();
+
+                return true;
+            }
+            KeyCode::Char('z') if ctrl => return self.undo(),
+            KeyCode::Char('y') if ctrl => return self.redo(),
+            KeyCode::Char(c) if !ctrl && !alt => {
+                self.insert_char(c);
+
+                return true;
+            }
+            KeyCode::Enter => {
+                self.insert_newline();
+
+                return true;
+            }
+            KeyCode::Backspace => return self.delete_char(),
+            KeyCode::Delete => return self.delete_next_char(),
+            _ => return false,
+        };
+
+        if shift && !self.is_selecting() {
+            self.start_selection();
+        } else if !shift {
+            self.cancel_selection();
+        }
+
+        self.move_cursor(cursor_move);
+
+        true
+    }
+
+    fn style_at(&self, pos: (usize, usize)) -> Style {
+        let mut style = self.style;
+
+        for (begin, end, highlight) in self.highlights.iter() {
+            if *begin <= pos && pos < *end {
+                style = style.patch(*highlight);
+            }
+        }
+
+        if let Some((begin, end)) = self.selection()
+            && begin <= pos
+            && pos < end
+        {
+            style = style.patch(self.selection_style);
+        }
+
+        if pos == self.cursor {
+            style = style.patch(self.cursor_style);
+        }
+
+        style
+    }
+
+    fn visual_rows(&self, text_width: usize) -> Vec<(usize, usize)> {
+        if !self.wrap {
+            return (0..self.lines.len()).map(|row| (row, 0)).collect();
+        }
+
+        self.lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                (0..line.len() / text_width + 1).map(move |i| (row, i * text_width))
+            })
+            .collect()
+    }
+
+    fn scroll_into_view(&mut self, rows: &[(usize, usize)], text_width: usize, height: usize) {
+        let (row, col) = self.cursor;
+        let cursor_row = rows
+            .iter()
+            .rposition(|(r, begin)| *r == row && *begin <= col)
+            .unwrap_or(0);
+
+        if cursor_row < self.scroll.0 {
+            self.scroll.0 = cursor_row;
+        } else if cursor_row >= self.scroll.0 + height {
+            self.scroll.0 = cursor_row + 1 - height;
+        }
+
+        if self.wrap {
+            self.scroll.1 = 0;
+        } else if col < self.scroll.1 {
+            self.scroll.1 = col;
+        } else if col >= self.scroll.1 + text_width {
+            self.scroll.1 = col + 1 - text_width;
+        }
+    }
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self {
+            lines: vec![vec![]],
+            cursor: (0, 0),
+            anchor: None,
+            register: String::new(),
+            undo: VecDeque::new(),
+            redo: vec![],
+            typing: None,
+            highlights: vec![],
+            wrap: false,
+            line_numbers: false,
+            style: Style::new(),
+            cursor_style: CURSOR_STYLE,
+            selection_style: SELECTION_STYLE,
+            line_number_style: LINE_NUMBER_STYLE,
+            scroll: (0, 0),
+        }
+    }
+}
+
+impl From<&str> for TextArea {
+    fn from(value: &str) -> Self {
+        let mut textarea = Self::default();
+
+        textarea.insert_raw(value);
+        textarea.cursor = (0, 0);
+
+        textarea
+    }
+}
+
+impl From<String> for TextArea {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl Widget for TextArea {
+    /// Renders visible lines, the cursor is scrolled into `area`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TextArea;
+    /// use eired::Widget;
+    /// use eired_display::{Annotate, Layer, Rect, Span};
+    ///
+    /// let mut textarea = TextArea::from("A\nB\nC");
+    /// let mut layer = Layer::default();
+    ///
+    /// textarea.set_line_numbers(true);
+    /// textarea.set_cursor(2, 1);
+    /// textarea.render(&mut layer, Rect(4, 2).annotate((0, 0)));
+    ///
+    /// let texts = layer
+    ///     .inner()
+    ///     .iter()
+    ///     .map(|s| s.inner().to_vec().iter().map(|c| c.ch).collect::<String>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(texts, vec!["2 B ", "3 C "]);
+    /// ```
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let (area_x, area_y) = area.base_pos();
+        let (width, height) = (area.width() as usize, area.height() as usize);
+        let gutter = if self.line_numbers {
+            self.lines.len().to_string().len() + 1
+        } else {
+            0
+        };
+        let text_width = width.saturating_sub(gutter);
+
+        if text_width == 0 || height == 0 {
+            return;
+        }
+
+        let rows = self.visual_rows(text_width);
+
+        self.scroll_into_view(&rows, text_width, height);
+
+        for rel_y in 0..height {
+            let pos = (area_x, area_y + rel_y as u16);
+            let Some(&(row, begin)) = rows.get(self.scroll.0 + rel_y) else {
+                write_span(layer, &area, pos, self.style.span(" ".repeat(width)));
+
+                continue;
+            };
+
+            let mut span = Span::default();
+
+            if gutter > 0 {
+                let label = if begin == 0 {
+                    format!("{:>w$} ", row + 1, w = gutter - 1)
+                } else {
+                    " ".repeat(gutter)
+                };
+
+                span.push_all(self.line_number_style.span(label));
+            }
+
+            let begin = begin + self.scroll.1;
+            let end = if self.wrap {
+                (begin + text_width).min(self.lines[row].len() + 1)
+            } else {
+                begin + text_width
+            };
+
+            for col in begin..end {
+                let ch = self.lines[row].get(col).copied().unwrap_or(' ');
+
+                span.push_back(self.style_at((row, col)).cell(ch));
+            }
+
+            let mut padding = self.style.span(" ".repeat(width - span.len() as usize));
+
+            span.append(&mut padding);
+            write_span(layer, &area, pos, span);
+        }
+    }
+}
+
+impl Focusable for TextArea {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input(key)
+    }
+
+    /// Renders without the cursor while unfocused.
+    fn render_focus(&mut self, layer: &mut Layer, area: Annot<Rect>, is_focused: bool) {
+        if is_focused {
+            return self.render(layer, area);
+        }
+
+        let cursor_style = std::mem::take(&mut self.cursor_style);
+
+        self.render(layer, area);
+        self.cursor_style = cursor_style;
+    }
+}
+
+impl Themed for TextArea {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["textarea", "text"], Style::new());
+        theme.assign(
+            &mut self.cursor_style,
+            &["textarea.cursor", "cursor"],
+            CURSOR_STYLE,
+        );
+        theme.assign(
+            &mut self.selection_style,
+            &["textarea.selection", "selection"],
+            SELECTION_STYLE,
+        );
+        theme.assign(
+            &mut self.line_number_style,
+            &["textarea.line_number", "line_number"],
+            LINE_NUMBER_STYLE,
+        );
+    }
+}
+
+impl Debug for TextArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextArea")
+            .field("lines", &self.lines())
+            .field("cursor", &self.cursor)
+            .field("selection", &self.selection())
+            .field("register", &self.register)
+            .field("wrap", &self.wrap)
+            .field("line_numbers", &self.line_numbers)
+            .finish()
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::io;
+use std::path::Path;
+
+use crossterm::style::{Attribute, Color};
+use eired_display::{Cell, Span};
+
+use crate::Style;
+
+#[derive(Debug)]
+/// An error of loading [Theme].
+pub enum ThemeError {
+    /// Failed to read a theme file.
+    Io(io::Error),
+
+    /// A line that can't be parsed, `line` starts at 1.
+    Parse {
+        /// A line number.
+        line: usize,
+
+        /// A reason of error.
+        message: String,
+    },
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read theme: {e}"),
+            Self::Parse { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ThemeError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A named set of styles by semantic names, e.g. `"error"`, `"selection"` and `"border.focused"`.
+///
+/// Theme files have a style by line, lines start with `#` are comments:
+///
+/// ```text
+/// # name = fg:<color> bg:<color> <attribute>...
+/// error          = fg:red bold
+/// selection      = fg:black bg:#ffcc00
+/// border.focused = fg:cyan
+/// ```
+///
+/// Colors are names of [`Color`] (`dark_grey`, `red`, ...), `#rrggbb` or ANSI values `0`~`255`.
+/// Attributes are `bold`, `dim`, `italic`, `underlined`, `reverse`, `hidden`, `crossed_out`,
+/// `slow_blink` and `rapid_blink`.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Theme;
+/// use crossterm::style::{Attribute, Color};
+/// use eired::Style;
+///
+/// let theme = Theme::parse("dark", "
+///     border         = fg:dark_grey
+///     border.focused = fg:cyan bold
+/// ").unwrap();
+///
+/// assert_eq!(theme.style("border.focused"), Style::new().fg(Color::Cyan).attr(Attribute::Bold));
+///
+/// // Falls back to the parent name
+/// assert_eq!(theme.style("border.disabled"), Style::new().fg(Color::DarkGrey));
+/// assert_eq!(theme.style("error"), Style::new());
+/// ```
+pub struct Theme {
+    name: String,
+    styles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    /// Create new empty theme.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// Parses theme of `name` from `text`.
+    pub fn parse<S: Into<String>>(name: S, text: &str) -> Result<Self, ThemeError> {
+        let mut theme = Self::new(name);
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: String| ThemeError::Parse {
+                line: i + 1,
+                message,
+            };
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected `name = style`: `{line}`")))?;
+            let key = key.trim();
+
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return Err(error(format!("invalid name: `{key}`")));
+            }
+
+            theme.set(key, parse_style(value).map_err(error)?);
+        }
+
+        Ok(theme)
+    }
+
+    /// Loads theme from a file, the file stem is the name.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self::parse(name, &text)
+    }
+
+    /// Returns name of theme.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets style of `name`.
+    pub fn set<S: Into<String>>(&mut self, name: S, style: Style) {
+        self.styles.insert(name.into(), style);
+    }
+
+    /// Returns style of `name` as is.
+    pub fn get(&self, name: &str) -> Option<Style> {
+        self.styles.get(name).copied()
+    }
+
+    /// Returns style of the first defined one of `names`.
+    pub fn first(&self, names: &[&str]) -> Option<Style> {
+        names.iter().find_map(|name| self.get(name))
+    }
+
+    /// Sets the first defined style of `names` to `style`, resets it to `default` when no one is
+    /// defined.
+    pub(crate) fn assign(&self, style: &mut Style, names: &[&str], default: Style) {
+        *style = self.first(names).unwrap_or(default);
+    }
+
+    /// Returns style of `name`, the parent names separated by `.` are looked up in order.
+    ///
+    /// Returns empty style when no one is defined.
+    pub fn style(&self, name: &str) -> Style {
+        let mut name = name;
+
+        loop {
+            if let Some(style) = self.get(name) {
+                return style;
+            }
+
+            match name.rsplit_once('.') {
+                Some((parent, _)) => name = parent,
+                None => return Style::new(),
+            }
+        }
+    }
+
+    /// Returns names of styles by ascending order.
+    pub fn names(&self) -> Vec<&str> {
+        self.styles.keys().map(String::as_str).collect()
+    }
+
+    /// Create new cell that styled by `name`.
+    pub fn cell(&self, name: &str, ch: char) -> Cell {
+        self.style(name).cell(ch)
+    }
+
+    /// Create new span that styled by `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::Theme;
+    /// use crossterm::style::Color;
+    /// use eired_display::Span;
+    ///
+    /// let theme = Theme::parse("dark", "error = fg:red").unwrap();
+    ///
+    /// assert_eq!(theme.span("error", "Failed"), Span::new_with_fg("Failed", Color::Red));
+    /// ```
+    pub fn span<S: AsRef<str>>(&self, name: &str, s: S) -> Span {
+        self.style(name).span(s)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new("default")
+    }
+}
+
+impl Debug for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Theme")
+            .field("name", &self.name)
+            .field("styles", &self.styles)
+            .finish()
+    }
+}
+
+fn parse_style(value: &str) -> Result<Style, String> {
+    value
+        .split_whitespace()
+        .try_fold(Style::new(), |style, token| {
+            if let Some(color) = token.strip_prefix("fg:") {
+                return Ok(style.fg(parse_color(color)?));
+            }
+
+            if let Some(color) = token.strip_prefix("bg:") {
+                return Ok(style.bg(parse_color(color)?));
+            }
+
+            Ok(style.attr(parse_attr(token)?))
+        })
+}
+
+fn parse_color(s: &str) -> Result<Color, String> {
+    let invalid = || format!("invalid color: `{s}`");
+
+    if let Some(hex) = s.strip_prefix('#') {
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+        };
+
+        return match (hex.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Color::Rgb { r, g, b }),
+            _ => Err(invalid()),
+        };
+    }
+
+    if let Ok(value) = s.parse::<u8>() {
+        return Ok(Color::AnsiValue(value));
+    }
+
+    Color::try_from(s.replace('-', "_").as_str()).map_err(|_| invalid())
+}
+
+fn parse_attr(s: &str) -> Result<Attribute, String> {
+    match s.to_ascii_lowercase().replace('-', "_").as_str() {
+        "bold" => Ok(Attribute::Bold),
+        "dim" => Ok(Attribute::Dim),
+        "italic" => Ok(Attribute::Italic),
+        "underlined" | "underline" => Ok(Attribute::Underlined),
+        "reverse" | "reversed" => Ok(Attribute::Reverse),
+        "hidden" => Ok(Attribute::Hidden),
+        "crossed_out" | "strikethrough" => Ok(Attribute::CrossedOut),
+        "slow_blink" | "blink" => Ok(Attribute::SlowBlink),
+        "rapid_blink" => Ok(Attribute::RapidBlink),
+        _ => Err(format!("invalid attribute: `{s}`")),
+    }
+}
+
+/// A widget that takes styles from [Theme].
+pub trait Themed {
+    /// Applies styles of `theme`, styles of undefined names are reset to the widget defaults.
+    fn apply_theme(&mut self, theme: &Theme);
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A registry of themes that switches the active one at runtime.
+///
+/// Switching marks the registry changed, then the app applies the theme to widgets and re-renders
+/// all of them.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::ThemeRegistry;
+/// use crossterm::style::Color;
+/// use eired::{Tabs, Theme, Themed};
+///
+/// let mut themes = ThemeRegistry::default();
+/// let mut tabs = Tabs::new(vec!["A", "B"]);
+///
+/// themes.register(Theme::parse("light", "selection = fg:white bg:blue").unwrap());
+///
+/// assert!(themes.select("light"));
+///
+/// if themes.take_changed() {
+///     themes.apply(&mut [&mut tabs]);
+///     // Re-render all widgets here
+/// }
+///
+/// assert_eq!(themes.active().name(), "light");
+/// assert!(!themes.take_changed());
+/// ```
+pub struct ThemeRegistry {
+    themes: Vec<Theme>,
+    active: usize,
+    is_changed: bool,
+}
+
+impl ThemeRegistry {
+    /// Create new registry with the active `theme`.
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            themes: vec![theme],
+            active: 0,
+            is_changed: true,
+        }
+    }
+
+    /// Registers `theme`, a theme of the same name is replaced.
+    pub fn register(&mut self, theme: Theme) {
+        match self.themes.iter().position(|t| t.name == theme.name) {
+            Some(idx) => {
+                self.themes[idx] = theme;
+                self.is_changed |= idx == self.active;
+            }
+            None => self.themes.push(theme),
+        }
+    }
+
+    /// Returns names of themes by registration order.
+    pub fn names(&self) -> Vec<&str> {
+        self.themes.iter().map(Theme::name).collect()
+    }
+
+    /// Returns a theme of `name`.
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.iter().find(|t| t.name == name)
+    }
+
+    /// Returns active theme.
+    pub fn active(&self) -> &Theme {
+        &self.themes[self.active]
+    }
+
+    /// Activates a theme of `name`, returns `false` was not registered.
+    pub fn select(&mut self, name: &str) -> bool {
+        let Some(idx) = self.themes.iter().position(|t| t.name == name) else {
+            return false;
+        };
+
+        self.is_changed |= idx != self.active;
+        self.active = idx;
+
+        true
+    }
+
+    /// Returns `true` was the active theme changed since last call, and resets the flag.
+    ///
+    /// A new registry is changed, so the first theme is applied.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.is_changed)
+    }
+
+    /// Applies the active theme to `widgets`.
+    pub fn apply(&self, widgets: &mut [&mut dyn Themed]) {
+        for widget in widgets.iter_mut() {
+            widget.apply_theme(self.active());
+        }
+    }
+}
+
+impl Default for ThemeRegistry {
+    fn default() -> Self {
+        Self::new(Theme::default())
+    }
+}
+
+impl Debug for ThemeRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThemeRegistry")
+            .field("themes", &self.names())
+            .field("active", &self.active().name())
+            .finish()
+    }
+}
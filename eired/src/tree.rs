@@ -0,0 +1,573 @@
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::style::Color;
+use eired_display::{Annot, Layer, Rect};
+
+use crate::widget::Grid;
+use crate::{Focusable, Style, Theme, Themed, Widget};
+
+const HIGHLIGHT_STYLE: Style = Style::new().fg(Color::Black).bg(Color::White);
+const GUIDE_STYLE: Style = Style::new().fg(Color::DarkGrey);
+
+/// A provider of children that called when a lazy node is expanded at first.
+///
+/// Closures of `FnMut(&TreeNode) -> Vec<TreeNode>` are also a loader.
+pub trait TreeLoader {
+    /// Returns children of `node`.
+    fn load(&mut self, node: &TreeNode) -> Vec<TreeNode>;
+}
+
+impl<F: FnMut(&TreeNode) -> Vec<TreeNode>> TreeLoader for F {
+    fn load(&mut self, node: &TreeNode) -> Vec<TreeNode> {
+        self(node)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+/// A node of [TreeView].
+///
+/// The `id` identifies a node in siblings, a node is addressed by the path of ids from root.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::TreeNode;
+/// let node = TreeNode::new("src", "src/").children(vec![TreeNode::leaf("lib", "lib.rs")]);
+///
+/// assert_eq!(node.loaded_children().unwrap().len(), 1);
+/// assert!(!node.is_leaf());
+/// assert!(TreeNode::new("lazy", "lazy/").loaded_children().is_none());
+/// ```
+pub struct TreeNode {
+    id: String,
+    label: String,
+    is_leaf: bool,
+    children: Option<Vec<TreeNode>>,
+    is_expanded: bool,
+}
+
+impl TreeNode {
+    /// Create new branch, children are loaded by [TreeLoader] when expanded at first.
+    pub fn new<I: Into<String>, L: Into<String>>(id: I, label: L) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            is_leaf: false,
+            children: None,
+            is_expanded: false,
+        }
+    }
+
+    /// Create new node that has no children.
+    pub fn leaf<I: Into<String>, L: Into<String>>(id: I, label: L) -> Self {
+        Self {
+            is_leaf: true,
+            children: Some(vec![]),
+            ..Self::new(id, label)
+        }
+    }
+
+    /// Sets loaded children.
+    pub fn children(self, children: Vec<TreeNode>) -> Self {
+        Self {
+            is_leaf: false,
+            children: Some(children),
+            ..self
+        }
+    }
+
+    /// Sets expanded state.
+    pub fn expanded(self, is_expanded: bool) -> Self {
+        Self {
+            is_expanded: is_expanded && !self.is_leaf,
+            ..self
+        }
+    }
+
+    /// Returns id.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns `true` was the node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.is_leaf
+    }
+
+    /// Returns `true` was the node is expanded.
+    pub fn is_expanded(&self) -> bool {
+        self.is_expanded
+    }
+
+    /// Returns children, `None` was not loaded yet.
+    pub fn loaded_children(&self) -> Option<&[TreeNode]> {
+        self.children.as_deref()
+    }
+}
+
+impl Debug for TreeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeNode")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("is_leaf", &self.is_leaf)
+            .field("is_expanded", &self.is_expanded)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+/// A visible row of tree.
+struct Row {
+    path: Vec<String>,
+    /// `true` was the ancestor of each depth has a following sibling.
+    guides: Vec<bool>,
+    is_last: bool,
+    is_leaf: bool,
+    is_expanded: bool,
+    label: String,
+}
+
+/// A tree of [TreeNode] with expand/collapse markers and indentation guides.
+///
+/// The selection is kept by the path of ids, so it survives re-renders and changes of rows.
+/// When the selected node is hidden, the nearest visible ancestor is selected.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::TreeView;
+/// use eired::{TreeNode, Widget};
+/// use eired_display::{Annotate, Layer, Rect};
+///
+/// let mut tree = TreeView::new(vec![
+///     TreeNode::new("src", "src")
+///         .children(vec![TreeNode::leaf("main", "main.rs")])
+///         .expanded(true),
+/// ]);
+/// let mut layer = Layer::default();
+///
+/// tree.render(&mut layer, Rect(20, 2).annotate((0, 0)));
+///
+/// let chars = layer.inner()[1].inner().to_vec().iter().map(|c| c.ch).collect::<String>();
+///
+/// assert_eq!(chars, "└─  main.rs");
+/// ```
+pub struct TreeView {
+    roots: Vec<TreeNode>,
+    selected: Option<Vec<String>>,
+    offset: usize,
+    loader: Option<Box<dyn TreeLoader>>,
+    style: Style,
+    highlight_style: Style,
+    guide_style: Style,
+}
+
+impl TreeView {
+    /// Create new tree view.
+    pub fn new(roots: Vec<TreeNode>) -> Self {
+        Self {
+            selected: roots.first().map(|n| vec![n.id.clone()]),
+            roots,
+            offset: 0,
+            loader: None,
+            style: Style::new(),
+            highlight_style: HIGHLIGHT_STYLE,
+            guide_style: GUIDE_STYLE,
+        }
+    }
+
+    /// Sets loader of lazy children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eired::TreeView;
+    /// use eired::TreeNode;
+    ///
+    /// let mut tree = TreeView::new(vec![TreeNode::new("root", "root")]);
+    ///
+    /// tree.set_loader(|node: &TreeNode| vec![TreeNode::leaf("a", format!("{}/a", node.id()))]);
+    /// tree.select(&["root"]);
+    /// tree.expand();
+    ///
+    /// assert_eq!(tree.node(&["root", "a"]).unwrap().label(), "root/a");
+    /// ```
+    pub fn set_loader<L: TreeLoader + 'static>(&mut self, loader: L) {
+        self.loader = Some(Box::new(loader));
+    }
+
+    /// Returns roots ref.
+    pub fn roots(&self) -> &[TreeNode] {
+        &self.roots
+    }
+
+    /// Replaces roots, the selection is kept when the path is still visible.
+    pub fn set_roots(&mut self, roots: Vec<TreeNode>) {
+        self.roots = roots;
+        self.normalize_selection();
+    }
+
+    /// Returns node of `path`.
+    pub fn node<S: AsRef<str>>(&self, path: &[S]) -> Option<&TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.iter().find(|n| n.id == first.as_ref())?;
+
+        for id in rest {
+            node = node
+                .children
+                .as_ref()?
+                .iter()
+                .find(|n| n.id == id.as_ref())?;
+        }
+
+        Some(node)
+    }
+
+    fn node_mut(&mut self, path: &[String]) -> Option<&mut TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.iter_mut().find(|n| &n.id == first)?;
+
+        for id in rest {
+            node = node.children.as_mut()?.iter_mut().find(|n| &n.id == id)?;
+        }
+
+        Some(node)
+    }
+
+    /// Returns path of selected node.
+    pub fn selected(&self) -> Option<&[String]> {
+        self.selected.as_deref()
+    }
+
+    /// Returns selected node.
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        let rows = self.rows();
+        let idx = self.selected_index(&rows)?;
+
+        self.node(&rows[idx].path)
+    }
+
+    /// Selects node of `path`, returns `false` was the node is not visible.
+    pub fn select<S: AsRef<str>>(&mut self, path: &[S]) -> bool {
+        let path = path.iter().map(|s| s.as_ref().to_string()).collect();
+        let is_visible = self.rows().iter().any(|r| r.path == path);
+
+        if is_visible {
+            self.selected = Some(path);
+        }
+
+        is_visible
+    }
+
+    /// Selects next visible node.
+    pub fn select_next(&mut self) -> bool {
+        self.select_by(|idx, _| idx.map_or(0, |i| i + 1))
+    }
+
+    /// Selects previous visible node.
+    pub fn select_previous(&mut self) -> bool {
+        self.select_by(|idx, _| idx.map_or(0, |i| i.saturating_sub(1)))
+    }
+
+    /// Selects first visible node.
+    pub fn select_first(&mut self) -> bool {
+        self.select_by(|_, _| 0)
+    }
+
+    /// Selects last visible node.
+    pub fn select_last(&mut self) -> bool {
+        self.select_by(|_, len| len.saturating_sub(1))
+    }
+
+    /// Selects parent of selected node.
+    pub fn select_parent(&mut self) -> bool {
+        let rows = self.rows();
+        let Some(idx) = self.selected_index(&rows) else {
+            return false;
+        };
+        let path = &rows[idx].path;
+
+        if path.len() < 2 {
+            return false;
+        }
+
+        self.selected = Some(path[..path.len() - 1].to_vec());
+
+        true
+    }
+
+    fn select_by<F: FnOnce(Option<usize>, usize) -> usize>(&mut self, f: F) -> bool {
+        let rows = self.rows();
+        let current = self.selected_index(&rows);
+        let next = f(current, rows.len());
+
+        match rows.get(next) {
+            Some(row) if Some(next) != current => {
+                self.selected = Some(row.path.clone());
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Expands selected node, children are loaded when not loaded yet.
+    ///
+    /// Returns `false` was the node is a leaf or already expanded.
+    pub fn expand(&mut self) -> bool {
+        let Some(path) = self.selected().map(|p| p.to_vec()) else {
+            return false;
+        };
+
+        let mut loader = self.loader.take();
+        let expanded = self.node_mut(&path).is_some_and(|node| {
+            if node.is_leaf || node.is_expanded {
+                return false;
+            }
+
+            if node.children.is_none() {
+                let children = loader.as_mut().map_or(vec![], |l| l.load(node));
+
+                node.children = Some(children);
+            }
+
+            node.is_expanded = true;
+
+            true
+        });
+
+        self.loader = loader;
+
+        expanded
+    }
+
+    /// Collapses selected node, returns `false` was the node is not expanded.
+    pub fn collapse(&mut self) -> bool {
+        let Some(path) = self.selected().map(|p| p.to_vec()) else {
+            return false;
+        };
+
+        self.node_mut(&path).is_some_and(|node| {
+            let is_expanded = node.is_expanded;
+
+            node.is_expanded = false;
+
+            is_expanded
+        })
+    }
+
+    /// Toggles expanded state of selected node.
+    pub fn toggle(&mut self) -> bool {
+        self.collapse() || self.expand()
+    }
+
+    /// Handles key event, returns `true` was the key consumed.
+    ///
+    /// | Key | Action |
+    /// | --- | --- |
+    /// | Up, Down, Home, End | Moves selection |
+    /// | Right | Expands, or selects the first child |
+    /// | Left | Collapses, or selects the parent |
+    /// | Enter, Space | Toggles |
+    pub fn input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::Home => self.select_first(),
+            KeyCode::End => self.select_last(),
+            KeyCode::Right => self.expand() || self.select_first_child(),
+            KeyCode::Left => self.collapse() || self.select_parent(),
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle(),
+            _ => false,
+        }
+    }
+
+    fn select_first_child(&mut self) -> bool {
+        let is_expanded = self.selected_node().is_some_and(|n| n.is_expanded);
+
+        is_expanded && self.select_next()
+    }
+
+    /// Sets style of labels.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets style of selected label.
+    pub fn set_highlight_style(&mut self, style: Style) {
+        self.highlight_style = style;
+    }
+
+    /// Sets style of indentation guides and markers.
+    pub fn set_guide_style(&mut self, style: Style) {
+        self.guide_style = style;
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = vec![];
+
+        collect_rows(&self.roots, &mut vec![], &mut vec![], &mut rows);
+
+        rows
+    }
+
+    /// Moves selection to the nearest visible ancestor, or the first row.
+    fn normalize_selection(&mut self) -> Option<usize> {
+        let rows = self.rows();
+        let idx = self
+            .selected_index(&rows)
+            .or((!rows.is_empty()).then_some(0));
+
+        self.selected = idx.map(|i| rows[i].path.clone());
+
+        idx
+    }
+
+    /// Returns index of selected row or the nearest visible ancestor.
+    fn selected_index(&self, rows: &[Row]) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        (1..=selected.len())
+            .rev()
+            .find_map(|len| rows.iter().position(|r| r.path[..] == selected[..len]))
+    }
+}
+
+fn collect_rows(
+    nodes: &[TreeNode],
+    path: &mut Vec<String>,
+    guides: &mut Vec<bool>,
+    rows: &mut Vec<Row>,
+) {
+    for (i, node) in nodes.iter().enumerate() {
+        let is_last = i + 1 == nodes.len();
+
+        path.push(node.id.clone());
+        rows.push(Row {
+            path: path.clone(),
+            guides: guides.clone(),
+            is_last,
+            is_leaf: node.is_leaf,
+            is_expanded: node.is_expanded,
+            label: node.label.clone(),
+        });
+
+        if node.is_expanded
+            && let Some(children) = &node.children
+        {
+            guides.push(!is_last);
+            collect_rows(children, path, guides, rows);
+            guides.pop();
+        }
+
+        path.pop();
+    }
+}
+
+impl Widget for TreeView {
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+        let mut grid = Grid::new(area.width(), area.height());
+        let selected = self.normalize_selection();
+        let rows = self.rows();
+        let height = grid.height() as usize;
+
+        if let Some(idx) = selected {
+            if idx < self.offset {
+                self.offset = idx;
+            } else if height > 0 && idx >= self.offset + height {
+                self.offset = idx + 1 - height;
+            }
+        }
+
+        self.offset = self.offset.min(rows.len().saturating_sub(height));
+
+        for (y, (idx, row)) in rows
+            .iter()
+            .enumerate()
+            .skip(self.offset)
+            .take(height)
+            .enumerate()
+        {
+            let y = y as u16;
+            let mut x = 0u16;
+
+            for has_next in row.guides.iter().skip(1) {
+                let guide = if *has_next { "│ " } else { "  " };
+
+                x = x.saturating_add(grid.set_str(x, y, guide, self.guide_style));
+            }
+
+            if !row.guides.is_empty() {
+                let connector = if row.is_last { "└─" } else { "├─" };
+
+                x = x.saturating_add(grid.set_str(x, y, connector, self.guide_style));
+            }
+
+            let marker = match (row.is_leaf, row.is_expanded) {
+                (true, _) => "  ",
+                (false, true) => "▼ ",
+                (false, false) => "▶ ",
+            };
+
+            x = x.saturating_add(grid.set_str(x, y, marker, self.guide_style));
+
+            let style = if Some(idx) == selected {
+                self.style.patch(self.highlight_style)
+            } else {
+                self.style
+            };
+
+            grid.set_str(x, y, &row.label, style);
+        }
+
+        grid.write_to(layer, area.base_pos());
+    }
+}
+
+impl Focusable for TreeView {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.input(key)
+    }
+
+    /// Renders without the highlight of selected row while unfocused.
+    fn render_focus(&mut self, layer: &mut Layer, area: Annot<Rect>, is_focused: bool) {
+        if is_focused {
+            return self.render(layer, area);
+        }
+
+        let highlight_style = std::mem::take(&mut self.highlight_style);
+
+        self.render(layer, area);
+        self.highlight_style = highlight_style;
+    }
+}
+
+impl Themed for TreeView {
+    fn apply_theme(&mut self, theme: &Theme) {
+        theme.assign(&mut self.style, &["tree", "text"], Style::new());
+        theme.assign(
+            &mut self.highlight_style,
+            &["tree.selected", "selection"],
+            HIGHLIGHT_STYLE,
+        );
+        theme.assign(&mut self.guide_style, &["tree.guide", "guide"], GUIDE_STYLE);
+    }
+}
+
+impl Debug for TreeView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeView")
+            .field("roots", &self.roots)
+            .field("selected", &self.selected)
+            .field("offset", &self.offset)
+            .field("has_loader", &self.loader.is_some())
+            .finish()
+    }
+}
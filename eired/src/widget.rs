@@ -0,0 +1,194 @@
+use eired_display::{Annot, Annotate, Cell, Layer, Rect, Span};
+
+use crate::Style;
+
+/// A trait for renders self into a [Layer].
+///
+/// `area` is the annotated rect on `layer`, implementors should not write outside of it.
+///
+/// # Examples
+///
+/// ```
+/// # use eired::Widget;
+/// use eired_display::{Annot, Annotate, Layer, Rect, Span};
+///
+/// struct Label(&'static str);
+///
+/// impl Widget for Label {
+///     fn render(&mut self, layer: &mut Layer, area: Annot<Rect>) {
+///         let mut span = Span::from(self.0);
+///
+///         span.truncate_back(span.len().saturating_sub(area.width()));
+///         layer.push_span_write(span.annotate(area.base_pos()));
+///     }
+/// }
+///
+/// let mut layer = Layer::default();
+///
+/// Label("Hello, World!").render(&mut layer, Rect(5, 1).annotate((1, 1)));
+///
+/// assert_eq!(layer.inner()[0], Span::from("Hello").annotate((1, 1)));
+/// ```
+pub trait Widget {
+    /// Renders self into `area` of `layer`.
+    fn render(&mut self, layer: &mut Layer, area: Annot<Rect>);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// A direction of widget layout.
+pub enum Orientation {
+    /// Top to bottom.
+    #[default]
+    Vertical,
+
+    /// Left to right.
+    Horizontal,
+}
+
+/// Writes `span` at `pos` of `layer` that truncated by `area` right side.
+pub(crate) fn write_span(layer: &mut Layer, area: &Annot<Rect>, pos: (u16, u16), mut span: Span) {
+    let (area_x, area_y) = area.base_pos();
+    let (area_end_x, area_end_y) = area.outer_apex_pos();
+
+    if pos.0 < area_x || pos.1 < area_y || pos.0 >= area_end_x || pos.1 >= area_end_y {
+        return;
+    }
+
+    let visible = area_end_x - pos.0;
+
+    span.truncate_back(span.len().saturating_sub(visible));
+    layer.push_span_write(span.annotate(pos));
+}
+
+/// A rectangle buffer of cells for widgets that draw in 2D.
+///
+/// Empty cells are not written, so the layer keeps the spans below them.
+pub(crate) struct Grid {
+    width: u16,
+    height: u16,
+    cells: Vec<Option<Cell>>,
+}
+
+impl Grid {
+    pub(crate) fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![None; width as usize * height as usize],
+        }
+    }
+
+    pub(crate) fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub(crate) fn get(&self, x: u16, y: u16) -> Option<Cell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.cells[y as usize * self.width as usize + x as usize]
+    }
+
+    pub(crate) fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut Option<Cell>> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.cells
+            .get_mut(y as usize * self.width as usize + x as usize)
+    }
+
+    pub(crate) fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if let Some(target) = self.get_mut(x, y) {
+            *target = Some(cell);
+        }
+    }
+
+    /// Sets chars of `s` from `(x, y)` that truncated by right side, returns written length.
+    pub(crate) fn set_str(&mut self, x: u16, y: u16, s: &str, style: Style) -> u16 {
+        let mut written = 0;
+
+        for (i, ch) in s.chars().enumerate() {
+            let Some(cx) = x.checked_add(i as u16).filter(|cx| *cx < self.width) else {
+                break;
+            };
+
+            self.set(cx, y, style.cell(ch));
+            written += 1;
+        }
+
+        written
+    }
+
+    /// Draws a box border of `size` from `pos`, inner cells are not changed.
+    pub(crate) fn draw_border(&mut self, pos: (u16, u16), size: (u16, u16), style: Style) {
+        let (x, y) = pos;
+        let (width, height) = size;
+
+        if width < 2 || height < 2 {
+            return;
+        }
+
+        let (right, bottom) = (x + width - 1, y + height - 1);
+
+        for cx in x + 1..right {
+            self.set(cx, y, style.cell('─'));
+            self.set(cx, bottom, style.cell('─'));
+        }
+
+        for cy in y + 1..bottom {
+            self.set(x, cy, style.cell('│'));
+            self.set(right, cy, style.cell('│'));
+        }
+
+        self.set(x, y, style.cell('┌'));
+        self.set(right, y, style.cell('┐'));
+        self.set(x, bottom, style.cell('└'));
+        self.set(right, bottom, style.cell('┘'));
+    }
+
+    /// Fills cells of `size` from `pos` by `cell`.
+    pub(crate) fn fill(&mut self, pos: (u16, u16), size: (u16, u16), cell: Cell) {
+        for cy in pos.1..pos.1.saturating_add(size.1) {
+            for cx in pos.0..pos.0.saturating_add(size.0) {
+                self.set(cx, cy, cell);
+            }
+        }
+    }
+
+    /// Writes filled cells to `layer` with `base` position.
+    pub(crate) fn write_to(self, layer: &mut Layer, base: (u16, u16)) {
+        let width = self.width as usize;
+
+        if width == 0 {
+            return;
+        }
+
+        for (y, row) in self.cells.chunks(width).enumerate() {
+            let mut x = 0;
+
+            while x < width {
+                if row[x].is_none() {
+                    x += 1;
+
+                    continue;
+                }
+
+                let begin = x;
+
+                while x < width && row[x].is_some() {
+                    x += 1;
+                }
+
+                let span = Span::from_iter(row[begin..x].iter().flatten().copied());
+
+                layer.push_span_write(span.annotate((base.0 + begin as u16, base.1 + y as u16)));
+            }
+        }
+    }
+}
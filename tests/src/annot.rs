@@ -1,4 +1,4 @@
-use eired_display::{Annotate, Rect};
+use eired_display::{Annot, Annotate, Rect};
 
 #[test]
 fn get_inner_apex() {
@@ -53,3 +53,47 @@ fn contains_pos() {
     assert!(annot.contains_pos(49, 49));
     assert!(!annot.contains_pos(50, 50));
 }
+
+#[test]
+fn intersection() {
+    let rect = Rect(10, 4).annotate((2, 2));
+
+    assert_eq!(
+        rect.intersection(&Rect(4, 4).annotate((0, 0))),
+        Some(Rect(2, 2).annotate((2, 2)))
+    );
+    assert_eq!(
+        rect.intersection(&Rect(2, 2).annotate((5, 3))),
+        Some(Rect(2, 2).annotate((5, 3)))
+    );
+    assert_eq!(
+        rect.intersection(&Rect(20, 1).annotate((0, 5))),
+        Some(Rect(10, 1).annotate((2, 5)))
+    );
+    assert_eq!(rect.intersection(&Rect(2, 2).annotate((0, 0))), None);
+    assert_eq!(rect.intersection(&Rect(0, 0).annotate((3, 3))), None);
+}
+
+#[test]
+fn signed_pos() {
+    let mut rect = Rect(4, 3).annotate_signed((-2, -5));
+
+    assert_eq!(rect.base_pos(), (0, 0));
+    assert_eq!(rect.outer_apex_pos(), (2, 0));
+    assert_eq!(rect.signed_outer_apex_pos(), (2, -2));
+    assert!(!rect.contains_pos(0, 0));
+
+    rect.translate((0, 3));
+
+    assert!(rect.contains_pos(1, 0));
+    assert!(!rect.contains_pos(2, 0));
+    assert_eq!(
+        rect.intersection(&Rect(10, 10).annotate((0, 0))),
+        Some(Rect(2, 1).annotate((0, 0)))
+    );
+
+    // Rebase starts from clamped coords, so negative coords are lost
+    rect.rebase(|x, _| *x += 1);
+
+    assert_eq!(rect, Annot::new_signed((1, 0), Rect(4, 3)));
+}
@@ -0,0 +1,48 @@
+use eired::{Bar, BarChart, BarGroup, Orientation, Widget};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::screen;
+
+#[test]
+fn vertical_with_labels() {
+    let mut chart = BarChart::new(vec![
+        BarGroup::new(vec![Bar::new(2).label("a"), Bar::new(4).label("b")]).label("G1"),
+        BarGroup::new(vec![Bar::new(1).label("c")]).label("G2"),
+    ]);
+    let mut layer = Layer::default();
+
+    chart.set_bar_width(1);
+    chart.set_value_visible(false);
+    chart.render(&mut layer, Rect(6, 4).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec!["  █   ", "█ █  ▄", "a b  c", "G1   G"]);
+}
+
+#[test]
+fn vertical_value_text() {
+    let mut chart = BarChart::new(vec![BarGroup::new(vec![Bar::new(5), Bar::new(10)])]);
+    let mut layer = Layer::default();
+
+    chart.set_bar_width(2);
+    chart.render(&mut layer, Rect(5, 2).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec!["   ██", "5█ 10"]);
+}
+
+#[test]
+fn horizontal() {
+    let mut chart = BarChart::new(vec![BarGroup::new(vec![
+        Bar::new(4).label("x"),
+        Bar::new(9).label("yy"),
+    ])]);
+    let mut layer = Layer::default();
+
+    chart.set_orientation(Orientation::Horizontal);
+    chart.set_bar_width(1);
+    chart.set_bar_gap(0);
+    chart.set_max(Some(4));
+    chart.set_value_visible(false);
+    chart.render(&mut layer, Rect(7, 2).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec!["x  ████", "yy ████"]);
+}
@@ -0,0 +1,81 @@
+use crossterm::style::Color;
+use eired::{BrailleCanvas, Widget};
+use eired_display::{Annotate, Canvas, Layer, Rect};
+
+use crate::helper::screen;
+
+#[test]
+fn diagonal_line() {
+    let mut canvas = BrailleCanvas::new(2, 2);
+
+    canvas.line((0.0, 0.0), (3.0, 7.0), Color::Reset);
+
+    assert_eq!(screen(canvas.to_layer()), vec![" ⡜", "⡜ "]);
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut canvas = BrailleCanvas::new(2, 1);
+
+    canvas.point(-1.0, 0.0, Color::Reset);
+    canvas.point(100.0, 0.0, Color::Reset);
+    canvas.line((-100.0, 0.0), (100.0, 0.0), Color::Blue);
+
+    let layer = canvas.to_layer();
+
+    assert_eq!(screen(canvas.to_layer()), vec!["⣀⣀"]);
+    assert!(
+        layer.inner()[0]
+            .inner()
+            .to_vec()
+            .iter()
+            .all(|c| c.fg == Color::Blue)
+    );
+}
+
+#[test]
+fn circle() {
+    let mut canvas = BrailleCanvas::new(3, 2);
+
+    canvas.set_x_bounds(-1.0, 1.0);
+    canvas.set_y_bounds(-1.0, 1.0);
+    canvas.circle((0.0, 0.0), 1.0, Color::Reset);
+
+    let rows = screen(canvas.to_layer());
+
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|r| r.chars().all(|c| c != ' ')));
+}
+
+#[test]
+fn per_shape_color() {
+    let mut canvas = BrailleCanvas::new(2, 1);
+
+    canvas.point(0.0, 0.0, Color::Red);
+    canvas.point(3.0, 0.0, Color::Green);
+
+    let layer = canvas.to_layer();
+    let span = layer.inner()[0].inner();
+
+    assert_eq!(span.get(0).unwrap().fg, Color::Red);
+    assert_eq!(span.get(1).unwrap().fg, Color::Green);
+}
+
+#[test]
+fn composite_with_canvas() {
+    let mut braille = BrailleCanvas::new(2, 1);
+    let mut layer = Layer::default();
+
+    braille.rectangle(0.0, 0.0, 3.0, 3.0, Color::Reset);
+    braille.render(&mut layer, Rect(1, 1).annotate((1, 0)));
+
+    assert_eq!(layer.inner()[0].base_pos(), (1, 0));
+
+    let mut canvas = Canvas::default();
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    let view = canvas.create_view();
+
+    assert_eq!(view.iter().nth(1).unwrap().unwrap().ch, '⣏');
+}
@@ -1,4 +1,5 @@
-use eired_display::{Annotate, Canvas, Cell, Layer, Span};
+use crossterm::style::Color;
+use eired_display::{Annotate, Blend, Canvas, Cell, Layer, Rect, Span};
 
 #[test]
 fn apply_layer() {
@@ -40,11 +41,11 @@ fn merge_layer() {
     assert!(canvas.inner_vec().len() == 1);
     assert_eq!(
         canvas.inner_vec()[0].1.inner().inner()[0].inner(),
-        &Span::from(" World!")
+        &Span::from("Hello,")
     );
     assert_eq!(
         canvas.inner_vec()[0].1.inner().inner()[1].inner(),
-        &Span::from("Hello,")
+        &Span::from(" World!")
     );
 }
 
@@ -120,3 +121,497 @@ fn create_view_with_multi_layer() {
         expected.iter().collect::<Vec<_>>()
     );
 }
+
+fn line_layer(s: &str) -> Layer {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from(s).annotate((0, 0)));
+
+    layer
+}
+
+fn rows(canvas: &Canvas) -> Vec<String> {
+    let view = canvas.create_view();
+
+    (0..view.get_size().1)
+        .map(|y| {
+            view.get_line(y)
+                .iter()
+                .map(|c| c.map_or('~', |c| c.ch))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn dirty_regions_by_changes() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("....").annotate((0, 0)));
+    canvas.insert(1, line_layer("OO").annotate((1, 1)));
+    canvas.create_view();
+
+    assert!(canvas.dirty_regions().is_empty());
+
+    // Replaced layer marks both old and new areas
+    canvas.insert(1, line_layer("X").annotate((3, 0)));
+
+    assert_eq!(
+        canvas.dirty_regions(),
+        &[Rect(2, 1).annotate((1, 1)), Rect(1, 1).annotate((3, 0))]
+    );
+
+    canvas.merge(0, line_layer("-").annotate((0, 1)));
+
+    assert_eq!(canvas.dirty_regions().len(), 4);
+    assert_eq!(canvas.dirty_regions()[3], Rect(4, 2).annotate((0, 0)));
+}
+
+#[test]
+fn incremental_view_equals_full() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("......").annotate((0, 0)));
+    canvas.insert(0, line_layer("......").annotate((0, 1)));
+    canvas.insert(2, line_layer("OO").annotate((2, 1)));
+
+    assert_eq!(rows(&canvas), vec!["~~~~~~", "..OO.."]);
+
+    // Old cells of the replaced layer are cleared
+    canvas.insert(2, line_layer("X").annotate((0, 0)));
+    canvas.insert_or_merge(1, line_layer("ab").annotate((4, 0)));
+    canvas.overlap_layer(line_layer("#").annotate((5, 1)));
+
+    assert_eq!(rows(&canvas), vec!["X~~~ab", ".....#"]);
+
+    let mut full = Canvas::default();
+
+    full.insert(0, line_layer("......").annotate((0, 1)));
+    full.insert(1, line_layer("ab").annotate((4, 0)));
+    full.insert(2, line_layer("X").annotate((0, 0)));
+    full.insert(3, line_layer("#").annotate((5, 1)));
+
+    assert_eq!(canvas.create_view(), full.create_view());
+}
+
+#[test]
+fn view_grows_with_layers() {
+    let mut canvas = Canvas::default();
+
+    canvas.overlap_layer(line_layer("ab").annotate((0, 0)));
+
+    assert_eq!(rows(&canvas), vec!["ab"]);
+
+    canvas.overlap_layer(line_layer("c").annotate((2, 1)));
+
+    assert_eq!(rows(&canvas), vec!["ab~", "~~c"]);
+
+    canvas.invalidate();
+
+    assert_eq!(rows(&canvas), vec!["ab~", "~~c"]);
+}
+
+#[test]
+fn borrowed_view_matches_created() {
+    let mut canvas = Canvas::default();
+
+    canvas.overlap_layer(line_layer("ab").annotate((0, 0)));
+
+    let shared = &canvas;
+    let created = shared.create_view();
+
+    assert!(shared.dirty_regions().is_empty());
+
+    canvas.overlap_layer(line_layer("c").annotate((1, 1)));
+
+    assert_eq!(canvas.dirty_regions(), &[Rect(1, 1).annotate((1, 1))]);
+    assert_ne!(canvas.view(), &created);
+    assert!(canvas.dirty_regions().is_empty());
+
+    let mut full = Canvas::default();
+
+    full.overlap_layer(line_layer("ab").annotate((0, 0)));
+    full.overlap_layer(line_layer("c").annotate((1, 1)));
+
+    assert_eq!(canvas.view(), &full.create_view());
+    assert_eq!(canvas.create_view(), full.create_view());
+}
+
+#[test]
+fn many_dirty_regions_merged() {
+    let mut canvas = Canvas::default();
+
+    canvas.create_view();
+
+    for x in 0..20 {
+        canvas.overlap_layer(line_layer("x").annotate((x, x % 3)));
+    }
+
+    assert!(canvas.dirty_regions().len() <= 16);
+    assert_eq!(rows(&canvas)[0], "x~~x~~x~~x~~x~~x~~x~");
+}
+
+#[test]
+fn remove_layer_shrinks() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("....").annotate((0, 0)));
+    canvas.insert(3, line_layer("POPUP").annotate((2, 1)));
+
+    assert_eq!(rows(&canvas), vec!["....~~~", "~~POPUP"]);
+
+    assert!(canvas.remove(3));
+    assert!(!canvas.remove(3));
+    assert_eq!(rows(&canvas), vec!["...."]);
+
+    // `front` follows the remaining layers
+    canvas.overlap_layer(line_layer("X").annotate((0, 0)));
+
+    assert!(canvas.is_visible(1));
+    assert_eq!(rows(&canvas), vec!["X..."]);
+
+    let layer = canvas.take(0).unwrap();
+
+    assert_eq!(layer.base_pos(), (0, 0));
+    assert_eq!(rows(&canvas), vec!["X"]);
+}
+
+#[test]
+fn hide_and_show_layer() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("....").annotate((0, 0)));
+    canvas.insert(1, line_layer("OO").annotate((1, 0)));
+    canvas.insert(2, line_layer("##").annotate((3, 1)));
+
+    assert!(canvas.hide(1));
+    assert!(canvas.hide(2));
+    assert!(!canvas.hide(5));
+    assert_eq!(rows(&canvas), vec!["...."]);
+    assert_eq!(canvas.hits(1, 0), vec![0]);
+
+    // Hidden layers are still replaced
+    canvas.insert(1, line_layer("XO").annotate((1, 0)));
+
+    assert_eq!(rows(&canvas), vec!["...."]);
+
+    assert!(canvas.show(1));
+    assert!(canvas.show(2));
+    assert!(canvas.is_visible(2));
+    assert_eq!(rows(&canvas), vec![".XO.~", "~~~##"]);
+}
+
+#[test]
+fn move_layer_z() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("AAA").annotate((0, 0)));
+    canvas.insert(1, line_layer("B").annotate((1, 0)));
+
+    assert_eq!(rows(&canvas), vec!["ABA"]);
+
+    assert_eq!(canvas.bring_to_front(0), Some(2));
+    assert_eq!(rows(&canvas), vec!["AAA"]);
+
+    assert!(canvas.hide(2));
+    assert!(canvas.move_layer(2, 0));
+    assert!(!canvas.is_visible(0));
+    assert!(canvas.show(0));
+    assert_eq!(rows(&canvas), vec!["ABA"]);
+    assert_eq!(canvas.bring_to_front(7), None);
+}
+
+#[test]
+fn set_layer_position() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("....").annotate((0, 0)));
+    canvas.insert(1, line_layer("[]").annotate((4, 2)));
+
+    assert_eq!(rows(&canvas), vec!["....~~", "~~~~~~", "~~~~[]"]);
+
+    assert!(canvas.set_position(1, (1, 0)));
+    assert!(!canvas.set_position(2, (0, 0)));
+    assert_eq!(rows(&canvas), vec![".[]."]);
+
+    let mut full = Canvas::default();
+
+    full.insert(0, line_layer("....").annotate((0, 0)));
+    full.insert(1, line_layer("[]").annotate((1, 0)));
+
+    assert_eq!(canvas, full);
+    assert_eq!(canvas.create_view(), full.create_view());
+}
+
+#[test]
+fn stable_layer_ids() {
+    let mut canvas = Canvas::default();
+
+    let base = canvas.insert(0, line_layer("....").annotate((0, 0)));
+    let popup = canvas.overlap_layer(line_layer("[]").annotate((1, 0)));
+
+    assert_ne!(base, popup);
+    assert_eq!(canvas.layer_ids(), vec![base, popup]);
+
+    // Inserting layers below does not change handles
+    canvas.move_layer(0, 5);
+    canvas.move_layer(1, 6);
+
+    let bottom = canvas.insert(0, line_layer("______").annotate((0, 0)));
+
+    assert_eq!(canvas.z_index(base), Some(5));
+    assert_eq!(canvas.z_index(popup), Some(6));
+    assert_eq!(canvas.layer_ids(), vec![bottom, base, popup]);
+    assert_eq!(canvas.insert(5, line_layer("::").annotate((0, 0))), base);
+    assert_eq!(rows(&canvas), vec![":[]___"]);
+
+    assert!(canvas.take_layer(popup).is_some());
+    assert_eq!(canvas.z_index(popup), None);
+    assert!(canvas.get(popup).is_none());
+    assert!(
+        canvas
+            .replace(popup, line_layer("X").annotate((0, 0)))
+            .is_none()
+    );
+
+    // Handles are not reused
+    let next = canvas.overlap_layer(line_layer("X").annotate((0, 0)));
+
+    assert_ne!(next, popup);
+    assert_eq!(canvas.layer_id(6), Some(next));
+}
+
+#[test]
+fn named_layers() {
+    let mut canvas = Canvas::default();
+
+    let status = canvas.insert_named(1, "status", line_layer("NORMAL").annotate((0, 1)));
+    let editor = canvas.insert_named(0, "editor", line_layer("fn main").annotate((0, 0)));
+
+    assert_eq!(canvas.find("status"), Some(status));
+    assert_eq!(canvas.find("editor"), Some(editor));
+    assert_eq!(canvas.find("popup"), None);
+
+    let old = canvas
+        .replace(
+            canvas.find("status").unwrap(),
+            line_layer("INSERT").annotate((0, 1)),
+        )
+        .unwrap();
+
+    assert_eq!(old.base_pos(), (0, 1));
+    assert_eq!(rows(&canvas), vec!["fn main", "INSERT~"]);
+
+    // Names are moved, and released with layers
+    assert!(canvas.set_name(editor, "status"));
+    assert_eq!(canvas.find("status"), Some(editor));
+    assert_eq!(canvas.name_of(status), None);
+    assert!(canvas.set_name(status, "bar"));
+
+    canvas.take_layer(status);
+
+    assert_eq!(canvas.find("bar"), None);
+    assert!(!canvas.set_name(status, "bar"));
+    assert_eq!(rows(&canvas), vec!["fn main"]);
+}
+
+#[test]
+fn transparent_cells_composite() {
+    let mut canvas = Canvas::default();
+    let mut highlight = Layer::default();
+
+    canvas.insert(0, line_layer("hello").annotate((0, 0)));
+    highlight.push_span_write(Span::new_overlay_bg(3, Color::Blue).annotate((0, 0)));
+    canvas.insert(1, highlight.annotate((1, 0)));
+
+    let mut cursor = Layer::default();
+    let mut span = Span::from("  _");
+
+    span.set_blend(Blend::TRANSPARENT);
+    span.get_mut(2).unwrap().blend.ch = false;
+    cursor.push_span_write(span.annotate((0, 0)));
+    canvas.insert(2, cursor.annotate((0, 0)));
+
+    let view = canvas.create_view();
+    let line = view.get_line(0);
+
+    assert_eq!(rows(&canvas), vec!["he_lo"]);
+    assert_eq!(line[0], Some(Cell::new('h')));
+    assert_eq!(line[1], Some(Cell::new_bg('e', Color::Blue)));
+    assert_eq!(line[2].unwrap().bg, Color::Blue);
+    assert_eq!(line[4], Some(Cell::new('o')));
+
+    // Re-stacked regions blend again from lower layers
+    canvas.insert(0, line_layer("HELLO").annotate((0, 0)));
+
+    assert_eq!(rows(&canvas), vec!["HE_LO"]);
+    assert_eq!(
+        canvas.create_view().get_line(0)[3],
+        Some(Cell::new_bg('L', Color::Blue))
+    );
+}
+
+#[test]
+fn layer_opacity() {
+    let grey = Color::Rgb {
+        r: 200,
+        g: 100,
+        b: 0,
+    };
+    let mut canvas = Canvas::default();
+    let mut base = Layer::default();
+    let mut dialog = Layer::default();
+
+    base.push_span_write(Span::new_with_bg("....", grey).annotate((0, 0)));
+    dialog
+        .push_span_write(Span::new_with_bg("OK", Color::Rgb { r: 0, g: 0, b: 0 }).annotate((0, 0)));
+    canvas.insert(0, base.annotate((0, 0)));
+    canvas.insert(1, dialog.annotate((1, 0)));
+
+    assert!(canvas.set_opacity(1, 64));
+    assert!(!canvas.set_opacity(2, 64));
+    assert_eq!(rows(&canvas), vec!["...."]);
+    assert_eq!(
+        canvas.create_view().get_line(0)[1].unwrap().bg,
+        Color::Rgb {
+            r: 150,
+            g: 75,
+            b: 0
+        }
+    );
+
+    // Opacity moves with the layer
+    canvas.move_layer(1, 3);
+
+    assert_eq!(canvas.opacity(3), 64);
+    assert!(canvas.set_opacity(3, 192));
+    assert_eq!(rows(&canvas), vec![".OK."]);
+
+    assert!(canvas.set_opacity(3, 0));
+    assert_eq!(rows(&canvas), vec!["...."]);
+    assert_eq!(
+        canvas.create_view().get_line(0)[1],
+        Some(Cell::new_bg('.', grey))
+    );
+
+    canvas.remove(3);
+    canvas.insert(3, line_layer("X").annotate((0, 0)));
+
+    assert_eq!(canvas.opacity(3), u8::MAX);
+    assert_eq!(rows(&canvas), vec!["X..."]);
+}
+
+#[test]
+fn canvas_clip() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("......").annotate((0, 0)));
+    canvas.insert(1, line_layer("......").annotate((0, 1)));
+    canvas.insert(2, line_layer("OOOO").annotate((1, 1)));
+    canvas.set_clip(Rect(3, 2).annotate((2, 0)));
+
+    assert_eq!(rows(&canvas), vec!["~~...~", "~~OOO~"]);
+    assert_eq!(canvas.hits(2, 1), vec![2, 1]);
+    assert!(canvas.hits(1, 1).is_empty());
+
+    // Layers are kept, and changes are trimmed too
+    canvas.insert(2, line_layer("XXXXXX").annotate((0, 1)));
+
+    assert_eq!(rows(&canvas), vec!["~~...~", "~~XXX~"]);
+
+    canvas.clear_clip();
+
+    assert_eq!(rows(&canvas), vec!["......", "XXXXXX"]);
+}
+
+#[test]
+fn offscreen_layers() {
+    let mut canvas = Canvas::default();
+
+    canvas.insert(0, line_layer("......").annotate((0, 0)));
+    canvas.insert(0, line_layer("......").annotate((0, 0)));
+    canvas.insert(1, line_layer("[pop]").annotate_signed((-5, 0)));
+
+    assert_eq!(rows(&canvas), vec!["......"]);
+
+    // Slides in from the left
+    for (x, expected) in [(-3, "p]...."), (-1, "pop]..")] {
+        assert!(canvas.set_signed_position(1, (x, 0)));
+        assert_eq!(rows(&canvas), vec![expected]);
+    }
+
+    assert_eq!(canvas.hits(0, 0), vec![1, 0]);
+    assert_eq!(canvas.hits(4, 0), vec![0]);
+
+    assert!(canvas.set_signed_position(1, (2, -1)));
+    assert_eq!(rows(&canvas), vec!["......"]);
+
+    let mut full = Canvas::default();
+
+    full.insert(0, line_layer("......").annotate((0, 0)));
+    full.insert(1, line_layer("[pop]").annotate_signed((2, -1)));
+
+    assert_eq!(canvas.create_view(), full.create_view());
+}
+
+#[test]
+fn create_viewport() {
+    let mut canvas = Canvas::default();
+    let mut text = Layer::default();
+
+    for y in 0..10_000 {
+        text.push_span_write(Span::from(format!("{y:04}....")).annotate((0, y)));
+    }
+
+    canvas.insert(0, text.annotate((0, 0)));
+    canvas.insert(1, line_layer("[pop]").annotate((2, 5000)));
+
+    assert_eq!(canvas.size(), (8, 10_000));
+
+    let full = canvas.create_view();
+
+    // Scrolled in both axes, the same as the slice of full view
+    for (x, y) in [(0, 0), (3, 4998), (5, 5001), (0, 9998)] {
+        let view = canvas.create_viewport(Rect(3, 2).annotate((x, y)));
+
+        assert_eq!(view.get_size(), (3, 2));
+
+        for row in 0..2 {
+            let begin = x as usize;
+
+            assert_eq!(
+                view.get_line(row),
+                &full.get_line(y + row)[begin..begin + 3]
+            );
+        }
+    }
+
+    // Outside of the canvas is empty
+    let view = canvas.create_viewport(Rect(3, 2).annotate((6, 9999)));
+    let cells = view
+        .iter()
+        .map(|c| c.map_or('~', |c| c.ch))
+        .collect::<String>();
+
+    assert_eq!(cells, "..~~~~");
+
+    let view = canvas.create_viewport(Rect(2, 1).annotate_signed((-1, 5000)));
+    let cells = view
+        .iter()
+        .map(|c| c.map_or('~', |c| c.ch))
+        .collect::<String>();
+
+    assert_eq!(cells, "~5");
+
+    // The cache of create_view is not changed
+    canvas.set_clip(Rect(4, 10_000).annotate((0, 0)));
+
+    let view = canvas.create_viewport(Rect(6, 1).annotate((0, 5000)));
+    let cells = view
+        .iter()
+        .map(|c| c.map_or('~', |c| c.ch))
+        .collect::<String>();
+
+    assert_eq!(cells, "50[p~~");
+    assert!(!canvas.dirty_regions().is_empty());
+}
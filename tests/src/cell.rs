@@ -1,5 +1,5 @@
-use crossterm::style::Color;
-use eired_display::Cell;
+use crossterm::style::{Attributes, Color};
+use eired_display::{Blend, Cell};
 
 #[test]
 fn default_cell() {
@@ -10,7 +10,91 @@ fn default_cell() {
         Cell {
             ch: ' ',
             fg: Color::Reset,
-            bg: Color::Reset
+            bg: Color::Reset,
+            attrs: Attributes::none(),
+            blend: Blend::OPAQUE,
         }
     )
 }
+
+#[test]
+fn blend_over() {
+    let base = Cell::new_fg('A', Color::Green);
+
+    assert_eq!(Cell::new('B').blend_over(Some(base)), Some(Cell::new('B')));
+    assert_eq!(Cell::transparent().blend_over(Some(base)), Some(base));
+
+    let mut fg_only = Cell::new_fg(' ', Color::Red);
+
+    fg_only.blend = Blend {
+        ch: true,
+        fg: false,
+        bg: true,
+    };
+
+    let mut expected = Cell::new_fg('A', Color::Red);
+
+    assert_eq!(fg_only.blend_over(Some(base)), Some(expected));
+
+    // Transparent parts on nothing stay transparent
+    expected = fg_only.blend_over(None).unwrap();
+
+    assert_eq!(expected.blend, fg_only.blend);
+    assert_eq!(
+        expected.blend_over(Some(base)),
+        fg_only.blend_over(Some(base))
+    );
+}
+
+#[test]
+fn blend_over_alpha() {
+    let black = Color::Rgb { r: 0, g: 0, b: 0 };
+    let white = Color::Rgb {
+        r: 255,
+        g: 255,
+        b: 255,
+    };
+    let upper = Cell::new_fg('A', white);
+    let below = Cell::new_bg('B', white);
+
+    assert_eq!(upper.blend_over_alpha(Some(below), 255), Some(upper));
+    assert_eq!(upper.blend_over_alpha(Some(below), 0), Some(below));
+
+    // Reset is not RGB, so switched at half
+    let cell = upper.blend_over_alpha(Some(below), 127).unwrap();
+
+    assert_eq!((cell.ch, cell.fg, cell.bg), ('B', Color::Reset, white));
+
+    let cell = Cell::new_bg('A', black)
+        .blend_over_alpha(Some(below), 128)
+        .unwrap();
+
+    assert_eq!(cell.ch, 'A');
+    assert_eq!(
+        cell.bg,
+        Color::Rgb {
+            r: 127,
+            g: 127,
+            b: 127
+        }
+    );
+
+    // Only the background is mixed by overlay cells
+    let cell = Cell::new_overlay_bg(black)
+        .blend_over_alpha(Some(below), 51)
+        .unwrap();
+
+    assert_eq!(cell.ch, 'B');
+    assert_eq!(
+        cell.bg,
+        Color::Rgb {
+            r: 204,
+            g: 204,
+            b: 204
+        }
+    );
+    assert_eq!(
+        Cell::new('A').blend_over_alpha(None, 128),
+        Some(Cell::new('A'))
+    );
+}
@@ -0,0 +1,120 @@
+use crossterm::style::Color;
+use eired::{Axis, Chart, Dataset, GraphType, Marker, Widget};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::screen;
+
+#[test]
+fn axes_and_block_line() {
+    let mut chart = Chart::new(vec![
+        Dataset::new("up", vec![(0.0, 0.0), (10.0, 10.0)]).marker(Marker::Block),
+    ]);
+    let mut layer = Layer::default();
+
+    chart.set_x_axis(Axis::default().bounds(0.0, 10.0).ticks(2));
+    chart.set_y_axis(Axis::default().bounds(0.0, 10.0).ticks(2));
+    chart.set_legend_visible(false);
+    chart.render(&mut layer, Rect(8, 5).annotate((0, 0)));
+
+    assert_eq!(
+        screen(layer),
+        vec!["10┤    █", "  │  ██ ", " 0┤██   ", "  └────┬", "   0  10"]
+    );
+}
+
+#[test]
+fn titles_and_legend() {
+    let mut chart = Chart::new(vec![
+        Dataset::new("a", vec![(0.0, 0.0)])
+            .marker(Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .color(Color::Red),
+    ]);
+    let mut layer = Layer::default();
+
+    chart.set_x_axis(
+        Axis::default()
+            .title("x")
+            .bounds(0.0, 1.0)
+            .labels(vec!["L", "R"]),
+    );
+    chart.set_y_axis(
+        Axis::default()
+            .title("y")
+            .bounds(0.0, 1.0)
+            .labels(vec!["lo", "hi"]),
+    );
+    chart.render(&mut layer, Rect(10, 7).annotate((0, 0)));
+
+    assert_eq!(
+        screen(layer),
+        vec![
+            "y         ",
+            "hi┤  ┌───┐",
+            "  │  │• a│",
+            "lo┤• └───┘",
+            "  └──────┬",
+            "   L     R",
+            "         x",
+        ]
+    );
+}
+
+#[test]
+fn braille_datasets_merge() {
+    let mut chart = Chart::new(vec![
+        Dataset::new("", vec![(0.0, 0.0)]).graph_type(GraphType::Scatter),
+        Dataset::new("", vec![(1.0, 1.0)]).graph_type(GraphType::Scatter),
+    ]);
+    let mut layer = Layer::default();
+
+    chart.set_x_axis(
+        Axis::default()
+            .bounds(0.0, 1.0)
+            .labels(Vec::<String>::new()),
+    );
+    chart.set_y_axis(
+        Axis::default()
+            .bounds(0.0, 1.0)
+            .labels(Vec::<String>::new()),
+    );
+    chart.render(&mut layer, Rect(2, 3).annotate((0, 0)));
+
+    assert_eq!(screen(layer)[0], "│⡈");
+}
+
+#[test]
+fn auto_ticks() {
+    let axis = Axis::default().ticks(5);
+
+    assert_eq!(
+        axis.tick_labels((0.0, 1.0)),
+        vec!["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"]
+    );
+    assert_eq!(
+        axis.tick_labels((-3.0, 97.0)),
+        vec!["0", "20", "40", "60", "80"]
+    );
+}
+
+#[test]
+fn ticks_of_large_bounds() {
+    let min = 2f64.powi(60);
+    let labels = Axis::default()
+        .bounds(min, min + 256.0)
+        .tick_labels((min, min + 256.0));
+
+    assert!(!labels.is_empty());
+    assert!(labels.len() <= 256);
+
+    let labels = Axis::default().ticks(usize::MAX).tick_labels((0.0, 1e9));
+
+    assert!(labels.len() <= 256);
+    assert_eq!(labels.last().unwrap(), "1000000000");
+
+    let labels = Axis::default().ticks(1000).tick_labels((0.0, 300.0));
+
+    assert_eq!(labels.len(), 151);
+    assert_eq!(labels[..3], ["0", "2", "4"]);
+    assert_eq!(labels.last().unwrap(), "300");
+}
@@ -0,0 +1,183 @@
+use crossterm::event::KeyCode;
+use crossterm::style::Color;
+use eired::{FocusManager, Focusable, TextArea, TreeNode, TreeView};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::key;
+
+#[test]
+fn ring_traversal() {
+    let mut focus = FocusManager::default();
+
+    focus.register("a");
+    focus.register("b");
+    focus.register("c");
+
+    assert!(focus.focus_next());
+    assert_eq!(focus.focused(), Some("b"));
+
+    focus.focus_next();
+    focus.focus_next();
+
+    assert_eq!(focus.focused(), Some("a"));
+
+    focus.focus_previous();
+
+    assert_eq!(focus.focused(), Some("c"));
+}
+
+#[test]
+fn tab_order() {
+    let mut focus = FocusManager::default();
+
+    focus.register("a");
+    focus.register("b");
+    focus.register("c");
+    focus.register_with_order("first", -1);
+    focus.register_with_order("b", 10);
+
+    assert_eq!(focus.ids(), vec!["first", "a", "c", "b"]);
+}
+
+#[test]
+fn skip_disabled() {
+    let mut focus = FocusManager::default();
+
+    focus.register("a");
+    focus.register("b");
+    focus.register("c");
+
+    focus.set_enabled("b", false);
+    focus.focus_next();
+
+    assert_eq!(focus.focused(), Some("c"));
+    assert!(!focus.focus("b"));
+
+    focus.set_enabled("c", false);
+
+    assert_eq!(focus.focused(), Some("a"));
+}
+
+#[test]
+fn unregister_focused() {
+    let mut focus = FocusManager::default();
+
+    focus.register("a");
+    focus.register("b");
+    focus.register("c");
+
+    focus.focus("c");
+
+    assert!(focus.unregister("c"));
+    assert_eq!(focus.focused(), Some("a"));
+
+    focus.unregister("a");
+    focus.unregister("b");
+
+    assert_eq!(focus.focused(), None);
+    assert!(!focus.unregister("x"));
+}
+
+#[test]
+fn shift_tab() {
+    let mut focus = FocusManager::default();
+
+    focus.register("a");
+    focus.register("b");
+    focus.register("c");
+
+    assert!(focus.input(key(KeyCode::BackTab), |_, _| false));
+    assert_eq!(focus.focused(), Some("c"));
+    assert!(!focus.input(key(KeyCode::Enter), |_, _| false));
+}
+
+#[test]
+fn focused_widget_consumes() {
+    let mut focus = FocusManager::default();
+    let mut text = TextArea::default();
+    let mut tree = TreeView::new(vec![TreeNode::leaf("a", "a"), TreeNode::leaf("b", "b")]);
+
+    focus.register("text");
+    focus.register("tree");
+
+    focus.dispatch(
+        key(KeyCode::Char('x')),
+        &mut [("text", &mut text), ("tree", &mut tree)],
+    );
+    focus.dispatch(
+        key(KeyCode::Tab),
+        &mut [("text", &mut text), ("tree", &mut tree)],
+    );
+    focus.dispatch(
+        key(KeyCode::Down),
+        &mut [("text", &mut text), ("tree", &mut tree)],
+    );
+
+    assert_eq!(text.text(), "x");
+    assert_eq!(focus.focused(), Some("tree"));
+    assert_eq!(tree.selected().unwrap(), &["b"]);
+}
+
+#[test]
+fn render_unfocused() {
+    let mut focus = FocusManager::default();
+    let mut text = TextArea::from("ab");
+
+    focus.register("other");
+    focus.register("text");
+
+    let mut layer = Layer::default();
+
+    focus.render("text", &mut text, &mut layer, Rect(4, 1).annotate((0, 0)));
+
+    let cells = layer
+        .inner()
+        .iter()
+        .flat_map(|s| s.inner().to_vec())
+        .collect::<Vec<_>>();
+
+    assert!(cells.iter().all(|c| c.bg == Color::Reset));
+
+    let mut layer = Layer::default();
+
+    text.render_focus(&mut layer, Rect(4, 1).annotate((0, 0)), true);
+
+    let cells = layer
+        .inner()
+        .iter()
+        .flat_map(|s| s.inner().to_vec())
+        .collect::<Vec<_>>();
+
+    assert!(cells.iter().any(|c| c.bg == Color::White));
+}
+
+#[test]
+fn render_unfocused_tree() {
+    let mut focus = FocusManager::default();
+    let mut tree = TreeView::new(vec![TreeNode::leaf("a", "a"), TreeNode::leaf("b", "b")]);
+    let highlights = |layer: Layer| {
+        layer
+            .inner()
+            .iter()
+            .flat_map(|s| s.inner().to_vec())
+            .filter(|c| c.bg == Color::White)
+            .count()
+    };
+
+    focus.register("other");
+    focus.register("tree");
+
+    let mut layer = Layer::default();
+
+    focus.render("tree", &mut tree, &mut layer, Rect(4, 2).annotate((0, 0)));
+
+    assert_eq!(highlights(layer), 0);
+
+    focus.focus("tree");
+
+    let mut layer = Layer::default();
+
+    focus.render("tree", &mut tree, &mut layer, Rect(4, 2).annotate((0, 0)));
+
+    assert_eq!(highlights(layer), 1);
+}
@@ -0,0 +1,62 @@
+use crossterm::style::Color;
+use eired::{Gauge, ProgressBar, Style, Widget};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::{render, screen};
+
+#[test]
+fn sub_cell_precision() {
+    let mut gauge = Gauge::new(0.0);
+
+    gauge.set_label("");
+
+    for (ratio, expected) in [
+        (0.0, "  "),
+        (0.0625, "▏ "),
+        (0.5, "█ "),
+        (0.9375, "█▉"),
+        (1.0, "██"),
+    ] {
+        gauge.set_ratio(ratio);
+
+        assert_eq!(render(&mut gauge, Rect(2, 1)), vec![expected]);
+    }
+}
+
+#[test]
+fn without_sub_cell() {
+    let mut gauge = Gauge::new(0.3);
+
+    gauge.set_label("");
+    gauge.set_sub_cell(false);
+
+    assert_eq!(render(&mut gauge, Rect(10, 1)), vec!["███       "]);
+}
+
+#[test]
+fn label_overlay() {
+    let mut gauge = Gauge::new(0.5);
+    let mut layer = Layer::default();
+
+    gauge.set_filled_style(Style::new().fg(Color::Green));
+    gauge.render(&mut layer, Rect(8, 1).annotate((0, 0)));
+
+    let line = layer.inner()[0].inner();
+
+    assert_eq!(render(&mut gauge, Rect(8, 1)), vec!["██50%   "]);
+    assert_eq!(line.get(3).unwrap().bg, Color::Green);
+    assert_eq!(line.get(4).unwrap().bg, Color::Reset);
+}
+
+#[test]
+fn progress_bar() {
+    let mut bar = ProgressBar::new(10);
+    let mut layer = Layer::default();
+
+    bar.inc(4);
+    bar.set_label("");
+    bar.render(&mut layer, Rect(5, 1).annotate((2, 1)));
+
+    assert_eq!(layer.inner()[0].base_pos(), (2, 1));
+    assert_eq!(screen(layer), vec!["       ", "  ██   "]);
+}
@@ -0,0 +1,56 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use eired::Widget;
+use eired_display::{Annotate, Canvas, Layer, Rect, Window, create_virtual_terminal};
+
+/// Create new key event of `code` without modifiers.
+pub fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+/// Returns rows of layer chars, blank cells are `' '`.
+pub fn screen(layer: Layer) -> Vec<String> {
+    let mut canvas = Canvas::default();
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    rows(&canvas)
+}
+
+/// Returns rows of canvas chars, empty cells are `' '`.
+pub fn rows(canvas: &Canvas) -> Vec<String> {
+    let view = canvas.create_view();
+
+    (0..view.height())
+        .map(|y| {
+            view.get_line(y)
+                .iter()
+                .map(|c| c.map_or(' ', |c| c.ch))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Returns rows of `widget` rendered in `area` at the left top.
+pub fn render(widget: &mut impl Widget, area: Rect) -> Vec<String> {
+    let mut layer = Layer::default();
+
+    widget.render(&mut layer, area.annotate((0, 0)));
+
+    screen(layer)
+}
+
+/// Returns rows of a window of `size` drawn by `draw`, blank cells are `' '`.
+pub fn window_rows(size: Rect, draw: impl FnOnce(&mut Window)) -> Vec<String> {
+    let mut window = Window::new(size.0, size.1);
+
+    draw(&mut window);
+
+    let vterm = create_virtual_terminal(window.annotate((0, 0)));
+
+    vterm
+        .inner()
+        .to_vec()
+        .chunks(size.0 as usize)
+        .map(|row| row.iter().map(|c| c.map_or(' ', |c| c.ch)).collect())
+        .collect()
+}
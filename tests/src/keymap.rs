@@ -0,0 +1,190 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use eired::{Key, KeyResult, Keymap, KeymapError, Widget, parse_keys};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::{key, screen};
+
+fn ctrl(ch: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(ch), KeyModifiers::CONTROL)
+}
+
+#[test]
+fn parse_specs() {
+    let spec = |s: &str| s.parse::<Key>().unwrap();
+
+    assert_eq!(
+        spec("ctrl-alt-del"),
+        Key::new(KeyCode::Delete, KeyModifiers::CONTROL | KeyModifiers::ALT)
+    );
+    assert_eq!(
+        spec("C-x"),
+        Key::new(KeyCode::Char('x'), KeyModifiers::CONTROL)
+    );
+    assert_eq!(
+        spec("ctrl--"),
+        Key::new(KeyCode::Char('-'), KeyModifiers::CONTROL)
+    );
+    assert_eq!(spec("f12"), Key::new(KeyCode::F(12), KeyModifiers::NONE));
+    assert_eq!(spec("shift-tab"), spec("backtab"));
+    assert_eq!(spec("space").to_string(), "space");
+    assert_eq!(spec("alt-Enter").to_string(), "alt-enter");
+
+    assert_eq!(
+        "ctrl-foo".parse::<Key>(),
+        Err(KeymapError::InvalidSpec("ctrl-foo".to_string()))
+    );
+    assert!(parse_keys("f25").is_err());
+    assert_eq!(parse_keys("g g").unwrap().len(), 2);
+}
+
+#[test]
+fn shifted_chars_from_events() {
+    let event = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+
+    assert_eq!(Key::from(event), "G".parse().unwrap());
+    assert_eq!(Key::from(event), "shift-g".parse().unwrap());
+}
+
+#[test]
+fn conflicts() {
+    let mut keymap = Keymap::default();
+
+    keymap.bind("normal", "g g", 1).unwrap();
+
+    assert!(matches!(
+        keymap.bind("normal", "g", 2),
+        Err(KeymapError::Conflict { existing, .. }) if existing == "g g"
+    ));
+    assert!(keymap.bind("normal", "g g x", 3).is_err());
+    assert!(keymap.bind("normal", "g g", 4).is_err());
+    assert!(keymap.bind("insert", "g", 5).is_ok());
+    assert!(keymap.bind("normal", "g h", 6).is_ok());
+
+    assert_eq!(keymap.unbind("normal", "g g"), Some(1));
+    assert!(keymap.bind("normal", "g", 2).is_err());
+    assert_eq!(keymap.unbind("normal", "g h"), Some(6));
+    assert_eq!(keymap.modes(), vec!["insert"]);
+    assert!(keymap.bind("normal", "g", 2).is_ok());
+    assert_eq!(keymap.modes(), vec!["insert", "normal"]);
+
+    assert_eq!(keymap.unbind("visual", "g"), None);
+    assert_eq!(keymap.modes(), vec!["insert", "normal"]);
+    assert_eq!(keymap.unbind("insert", "g"), Some(5));
+    assert_eq!(keymap.modes(), vec!["normal"]);
+    assert!(keymap.bindings("insert").is_empty());
+}
+
+#[test]
+fn chords_and_modes() {
+    let mut keymap = Keymap::default();
+
+    keymap.bind("normal", "ctrl-x ctrl-s", "save").unwrap();
+    keymap.bind("normal", "ctrl-x ctrl-c", "quit").unwrap();
+    keymap.bind("normal", "i", "insert").unwrap();
+    keymap.bind("insert", "esc", "normal").unwrap();
+
+    assert_eq!(keymap.input(ctrl('x')), KeyResult::Pending);
+    assert_eq!(keymap.pending().len(), 1);
+    assert_eq!(keymap.input(ctrl('c')), KeyResult::Action("quit"));
+    assert!(keymap.pending().is_empty());
+
+    assert_eq!(
+        keymap.input(key(KeyCode::Char('i'))),
+        KeyResult::Action("insert")
+    );
+
+    keymap.set_mode("insert");
+
+    assert_eq!(
+        keymap.input(key(KeyCode::Char('i'))),
+        KeyResult::Unbound(vec![Key::from(key(KeyCode::Char('i')))])
+    );
+    assert_eq!(keymap.input(key(KeyCode::Esc)), KeyResult::Action("normal"));
+    assert_eq!(keymap.mode(), "insert");
+}
+
+#[test]
+fn broken_chord_restarts() {
+    let mut keymap = Keymap::default();
+
+    keymap.bind("normal", "ctrl-x ctrl-s", "save").unwrap();
+    keymap.bind("normal", "i", "insert").unwrap();
+
+    keymap.input(ctrl('x'));
+
+    // `i` breaks the chord, and starts a new one
+    assert_eq!(
+        keymap.input(key(KeyCode::Char('i'))),
+        KeyResult::Action("insert")
+    );
+
+    keymap.input(ctrl('x'));
+
+    assert_eq!(
+        keymap.input(key(KeyCode::Char('z'))),
+        KeyResult::Unbound(parse_keys("ctrl-x z").unwrap())
+    );
+    assert!(keymap.pending().is_empty());
+}
+
+#[test]
+fn chord_timeout() {
+    let mut keymap = Keymap::default();
+    let now = Instant::now();
+
+    keymap.set_timeout(Duration::from_millis(500));
+    keymap.bind("normal", "ctrl-x ctrl-s", "save").unwrap();
+    keymap.bind("normal", "ctrl-f", "find").unwrap();
+
+    assert_eq!(keymap.input_at(ctrl('x'), now), KeyResult::Pending);
+    assert_eq!(
+        keymap.input_at(ctrl('s'), now + Duration::from_millis(400)),
+        KeyResult::Action("save")
+    );
+
+    keymap.input_at(ctrl('x'), now);
+
+    assert_eq!(
+        keymap.input_at(ctrl('s'), now + Duration::from_millis(600)),
+        KeyResult::Unbound(parse_keys("ctrl-s").unwrap())
+    );
+
+    keymap.input_at(ctrl('x'), now);
+
+    assert!(!keymap.expire(now + Duration::from_millis(100)));
+    assert!(keymap.expire(now + Duration::from_secs(1)));
+    assert!(keymap.pending().is_empty());
+}
+
+#[test]
+fn help_view() {
+    let mut keymap = Keymap::default();
+    let mut layer = Layer::default();
+
+    keymap
+        .bind_with_help("normal", "ctrl-x ctrl-s", (), "Save")
+        .unwrap();
+    keymap.bind_with_help("normal", "q", (), "Quit").unwrap();
+    keymap.bind("normal", "g g", ()).unwrap();
+
+    let mut help = keymap.help("normal");
+
+    assert_eq!(help.rows()[2], ("g g".to_string(), String::new()));
+
+    help.render(&mut layer, Rect(18, 2).annotate((0, 0)));
+
+    assert_eq!(
+        screen(layer),
+        vec!["ctrl-x ctrl-s  Sav", "q              Qui"]
+    );
+
+    help.scroll_mut().scroll_down(1);
+
+    let mut layer = Layer::default();
+
+    help.render(&mut layer, Rect(18, 2).annotate((0, 0)));
+
+    assert_eq!(screen(layer)[1], "g g               ");
+}
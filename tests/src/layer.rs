@@ -1,4 +1,4 @@
-use eired_display::{Annotate, Layer, Span};
+use eired_display::{Annotate, Canvas, Cell, Layer, Rect, Span};
 
 #[test]
 fn push_write_begin() {
@@ -8,8 +8,8 @@ fn push_write_begin() {
     layer.push_span_write(Span::from("Hello").annotate((0, 0)));
 
     assert!(layer.inner().len() == 2);
-    assert_eq!(layer.inner()[0].inner(), &Span::from("orld!"));
-    assert_eq!(layer.inner()[1].inner(), &Span::from("Hello"));
+    assert_eq!(layer.inner()[0].inner(), &Span::from("Hello"));
+    assert_eq!(layer.inner()[1].inner(), &Span::from("orld!"));
 }
 
 #[test]
@@ -21,8 +21,8 @@ fn push_write_include() {
 
     assert!(layer.inner().len() == 3);
     assert_eq!(layer.inner()[0], Span::from("Hello").annotate((0, 0)));
-    assert_eq!(layer.inner()[1], Span::from(" World!").annotate((6, 0)));
-    assert_eq!(layer.inner()[2], Span::from("!").annotate((5, 0)));
+    assert_eq!(layer.inner()[1], Span::from("!").annotate((5, 0)));
+    assert_eq!(layer.inner()[2], Span::from(" World!").annotate((6, 0)));
 }
 
 #[test]
@@ -57,8 +57,8 @@ fn push_fixed_include() {
     layer.push_span_fixed(Span::from("Hello, World!").annotate((0, 0)));
 
     assert!(layer.inner().len() == 3);
-    assert_eq!(layer.inner()[0], Span::from("llo, W").annotate((2, 0)));
-    assert_eq!(layer.inner()[1], Span::from("He").annotate((0, 0)));
+    assert_eq!(layer.inner()[0], Span::from("He").annotate((0, 0)));
+    assert_eq!(layer.inner()[1], Span::from("llo, W").annotate((2, 0)));
     assert_eq!(layer.inner()[2], Span::from("orld!").annotate((8, 0)));
 }
 
@@ -70,8 +70,8 @@ fn push_fixed_end() {
     layer.push_span_fixed(Span::from("Hello, What?").annotate((0, 0)));
 
     assert!(layer.inner().len() == 2);
-    assert_eq!(layer.inner()[0], Span::from("World!").annotate((7, 0)));
-    assert_eq!(layer.inner()[1], Span::from("Hello, ").annotate((0, 0)));
+    assert_eq!(layer.inner()[0], Span::from("Hello, ").annotate((0, 0)));
+    assert_eq!(layer.inner()[1], Span::from("World!").annotate((7, 0)));
 }
 
 #[test]
@@ -118,11 +118,11 @@ fn overlap_layer() {
     assert_eq!(layer.inner().inner()[0], Span::from("He").annotate((0, 0)));
     assert_eq!(
         layer.inner().inner()[1],
-        Span::from("ld!").annotate((10, 0))
+        Span::from("________").annotate((2, 0))
     );
     assert_eq!(
         layer.inner().inner()[2],
-        Span::from("________").annotate((2, 0))
+        Span::from("ld!").annotate((10, 0))
     );
 }
 
@@ -162,3 +162,260 @@ fn margin_addr() {
         Span::from("Hello, World!").annotate((5, 1))
     );
 }
+
+#[test]
+fn push_fixed_between() {
+    let mut layer = Layer::default();
+
+    layer.push_span_fixed(Span::from("b").annotate((2, 0)));
+    layer.push_span_fixed(Span::from("d").annotate((5, 0)));
+    layer.push_span_fixed(Span::from("__________").annotate((0, 0)));
+
+    assert_eq!(
+        layer.inner(),
+        &[
+            Span::from("__").annotate((0, 0)),
+            Span::from("b").annotate((2, 0)),
+            Span::from("__").annotate((3, 0)),
+            Span::from("d").annotate((5, 0)),
+            Span::from("____").annotate((6, 0)),
+        ]
+    );
+}
+
+#[test]
+fn push_write_across() {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("aa").annotate((0, 0)));
+    layer.push_span_write(Span::from("bb").annotate((3, 0)));
+    layer.push_span_write(Span::from("cc").annotate((6, 0)));
+    layer.push_span_write(Span::from("XXXXX").annotate((1, 0)));
+
+    assert_eq!(
+        layer.inner(),
+        &[
+            Span::from("a").annotate((0, 0)),
+            Span::from("XXXXX").annotate((1, 0)),
+            Span::from("cc").annotate((6, 0)),
+        ]
+    );
+}
+
+#[test]
+fn sorted_by_rows() {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("C").annotate((0, 2)));
+    layer.push_span_write(Span::from("B").annotate((4, 1)));
+    layer.push_span_write(Span::from("A").annotate((1, 1)));
+    layer.push_span_only_valid(Span::from("D").annotate((0, 0)));
+
+    let pos = layer
+        .inner()
+        .iter()
+        .map(|s| s.base_pos())
+        .collect::<Vec<_>>();
+
+    assert_eq!(pos, vec![(0, 0), (1, 1), (4, 1), (0, 2)]);
+    assert_eq!(layer.span_at(4, 1), Some(&Span::from("B").annotate((4, 1))));
+    assert_eq!(layer.span_at(2, 1), None);
+    assert_eq!(layer.span_at(0, 3), None);
+}
+
+#[test]
+fn push_clipped() {
+    let mut layer = Layer::default();
+
+    layer.set_clip(Rect(4, 2).annotate((2, 1)));
+
+    layer.push_span_write(Span::from("0123456789").annotate((0, 1)));
+    layer.push_span_write(Span::from("ab").annotate((1, 2)));
+    layer.push_span_write(Span::from("above").annotate((0, 0)));
+    layer.push_span_write(Span::from("below").annotate((0, 3)));
+    layer.push_span_fixed(Span::from("xyz").annotate((4, 2)));
+    layer.push_span_only_valid(Span::from("right").annotate((6, 2)));
+
+    assert_eq!(
+        layer.inner(),
+        &[
+            Span::from("2345").annotate((2, 1)),
+            Span::from("b").annotate((2, 2)),
+            Span::from("xy").annotate((4, 2)),
+        ]
+    );
+    assert_eq!(layer.get_size(), (6, 3));
+    assert_eq!(layer.clip(), Some(Rect(4, 2).annotate((2, 1))));
+
+    // Clip moves with margin
+    layer.add_margin((1, 0));
+    layer.push_span_write(Span::from("--------").annotate((0, 2)));
+
+    assert_eq!(layer.inner()[1], Span::from("----").annotate((3, 2)));
+
+    layer.clear_clip();
+    layer.push_span_write(Span::from("free").annotate((0, 5)));
+
+    assert_eq!(
+        layer.inner().last(),
+        Some(&Span::from("free").annotate((0, 5)))
+    );
+}
+
+#[test]
+fn set_clip_trims_spans() {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("XXXXX").annotate((0, 0)));
+    layer.push_span_write(Span::from("XXXXX").annotate((0, 1)));
+    layer.push_span_write(Span::from("XXXXX").annotate((0, 2)));
+    layer.set_clip(Rect(3, 1).annotate((1, 1)));
+
+    assert_eq!(layer.inner(), &[Span::from("XXX").annotate((1, 1))]);
+    assert_eq!(layer.get_size(), (4, 2));
+}
+
+#[test]
+fn push_negative_clipped() {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("Hello").annotate_signed((-2, 0)));
+    layer.push_span_write(Span::from("Above").annotate_signed((0, -1)));
+    layer.push_span_fixed(Span::from("ab").annotate_signed((-2, 1)));
+
+    assert_eq!(layer.inner(), &[Span::from("llo").annotate((0, 0))]);
+    assert_eq!(layer.get_size(), (3, 1));
+}
+
+#[test]
+fn shift_layer() {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("abc").annotate((0, 0)));
+    layer.push_span_write(Span::from("def").annotate((2, 1)));
+    layer.set_clip(Rect(4, 2).annotate((0, 0)));
+    layer.shift((1, 1));
+
+    assert_eq!(
+        layer.inner(),
+        &[
+            Span::from("abc").annotate((1, 1)),
+            Span::from("de").annotate((3, 2))
+        ]
+    );
+    assert_eq!(layer.clip(), Some(Rect(4, 2).annotate((1, 1))));
+
+    layer.shift((-3, -1));
+
+    assert_eq!(
+        layer.inner(),
+        &[
+            Span::from("c").annotate((0, 0)),
+            Span::from("de").annotate((0, 1))
+        ]
+    );
+    assert_eq!(layer.get_size(), (2, 2));
+}
+
+#[test]
+fn overlap_negative() {
+    let mut below = Layer::default();
+    let mut upper = Layer::default();
+
+    below.push_span_write(Span::from("....").annotate((0, 0)));
+    upper.push_span_write(Span::from("XYZ").annotate((0, 0)));
+
+    let layer = below.overlap((0, 0), upper.annotate_signed((-1, 0)));
+
+    assert_eq!(layer.base_pos(), (0, 0));
+    assert_eq!(
+        layer.inner().inner(),
+        &[
+            Span::from("YZ").annotate((0, 0)),
+            Span::from("..").annotate((2, 0))
+        ]
+    );
+}
+
+#[test]
+fn far_positions_saturate() {
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("end").annotate((u16::MAX - 1, 0)));
+
+    assert_eq!(
+        layer.inner(),
+        &[Span::from("e").annotate((u16::MAX - 1, 0))]
+    );
+    assert_eq!(layer.get_size(), (u16::MAX, 1));
+
+    let mut span = Span::from("far").annotate_signed((i32::MAX - 1, 0));
+
+    span.translate((10, i32::MIN));
+
+    assert_eq!(span.signed_pos(), (i32::MAX, i32::MIN));
+    assert_eq!(span.outer_apex_pos(), (u16::MAX, 0));
+
+    layer.push_span_write(span);
+
+    assert_eq!(layer.inner().len(), 1);
+
+    // Spans past the far edge are trimmed before conflicts are resolved
+    let mut far = Layer::default();
+
+    far.push_span_write(Span::from("0123456789").annotate((65530, 0)));
+    far.push_span_write(Span::from("AB").annotate((65533, 0)));
+
+    assert_eq!(
+        far.inner(),
+        &[
+            Span::from("012").annotate((65530, 0)),
+            Span::from("AB").annotate((65533, 0))
+        ]
+    );
+    assert_eq!(far.get_size(), (u16::MAX, 1));
+
+    // Margins move spans over the far edge
+    layer.add_margin((10, 10));
+
+    assert_eq!(layer.get_size(), (u16::MAX, 11));
+    assert!(layer.inner().is_empty());
+}
+
+#[test]
+fn far_layer_positions_saturate() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+
+    layer.push_span_write(Span::from("far").annotate((0, 0)));
+    canvas.insert(0, layer.annotate_signed((-1, 0)));
+
+    assert!(canvas.set_signed_position(0, (i32::MAX, 0)));
+    assert_eq!(
+        canvas
+            .get(canvas.layer_id(0).unwrap())
+            .unwrap()
+            .signed_pos(),
+        (i32::MAX, 0)
+    );
+
+    assert!(canvas.set_signed_position(0, (i32::MIN, i32::MIN)));
+    assert!(canvas.hits(0, 0).is_empty());
+
+    let rect = Rect(10, 10).annotate_signed((i32::MAX - 1, i32::MAX));
+
+    assert_eq!(rect.signed_outer_apex_pos(), (i32::MAX, i32::MAX));
+
+    // Rows far above the canvas are not wrapped into it
+    let mut canvas = Canvas::default();
+    let mut base = Layer::default();
+    let mut far = Layer::default();
+
+    base.push_span_write(Span::from(".").annotate((0, 0)));
+    far.push_span_write(Span::from("far").annotate((0, 0)));
+    canvas.insert(0, base.annotate((0, 0)));
+    canvas.insert(1, far.annotate_signed((0, -65536)));
+
+    assert_eq!(canvas.create_view().get_line(0), &[Some(Cell::new('.'))]);
+    assert_eq!(canvas.hits(0, 0), vec![0]);
+}
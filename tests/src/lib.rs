@@ -16,8 +16,62 @@ mod canvas;
 #[cfg(test)]
 mod view;
 
+#[cfg(test)]
+mod viewport;
+
 #[cfg(test)]
 mod window;
 
 #[cfg(test)]
 mod draw_cmd;
+
+#[cfg(test)]
+mod textarea;
+
+#[cfg(test)]
+mod gauge;
+
+#[cfg(test)]
+mod helper;
+
+#[cfg(test)]
+mod sparkline;
+
+#[cfg(test)]
+mod barchart;
+
+#[cfg(test)]
+mod braille;
+
+#[cfg(test)]
+mod chart;
+
+#[cfg(test)]
+mod tabs;
+
+#[cfg(test)]
+mod tree;
+
+#[cfg(test)]
+mod scrollbar;
+
+#[cfg(test)]
+mod popup;
+
+#[cfg(test)]
+mod menu;
+
+#[cfg(test)]
+mod palette;
+
+#[cfg(test)]
+mod focus;
+
+#[cfg(test)]
+mod mouse;
+
+#[cfg(test)]
+mod keymap;
+
+#[cfg(test)]
+mod theme;
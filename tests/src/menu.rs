@@ -0,0 +1,169 @@
+use crossterm::event::KeyCode;
+use eired::{Menu, MenuItem};
+use eired_display::{Canvas, Rect};
+
+use crate::helper::{key, rows};
+
+#[test]
+fn skip_disabled_and_separator() {
+    let mut menu = Menu::new(vec![
+        MenuItem::new("new", "New").accelerator('n'),
+        MenuItem::new("open", "Open").disabled(true),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            "Export",
+            vec![MenuItem::new("pdf", "PDF"), MenuItem::new("png", "PNG")],
+        )
+        .accelerator('e'),
+    ]);
+
+    menu.open_at((0, 0));
+    menu.input(key(KeyCode::Down));
+
+    assert_eq!(menu.selected_path(), &[3]);
+
+    menu.input(key(KeyCode::Down));
+
+    assert_eq!(menu.selected_path(), &[0]);
+
+    menu.input(key(KeyCode::Up));
+
+    assert_eq!(menu.selected_path(), &[3]);
+}
+
+#[test]
+fn nested_submenu() {
+    let mut menu = Menu::new(vec![
+        MenuItem::new("new", "New").accelerator('n'),
+        MenuItem::new("open", "Open").disabled(true),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            "Export",
+            vec![MenuItem::new("pdf", "PDF"), MenuItem::new("png", "PNG")],
+        )
+        .accelerator('e'),
+    ]);
+
+    menu.open_at((0, 0));
+    menu.input(key(KeyCode::Char('e')));
+
+    assert_eq!(menu.selected_path(), &[3, 0]);
+
+    menu.input(key(KeyCode::Left));
+
+    assert_eq!(menu.selected_path(), &[3]);
+
+    menu.input(key(KeyCode::Right));
+    menu.input(key(KeyCode::Down));
+    menu.input(key(KeyCode::Enter));
+
+    assert_eq!(menu.take_chosen(), Some("png".to_string()));
+    assert!(!menu.is_open());
+    assert!(!menu.input(key(KeyCode::Enter)));
+}
+
+#[test]
+fn disabled_accelerator() {
+    let mut menu = Menu::new(vec![
+        MenuItem::new("a", "A").accelerator('a').disabled(true),
+    ]);
+
+    menu.open_at((0, 0));
+
+    assert!(menu.input(key(KeyCode::Char('a'))));
+    assert!(menu.is_open());
+    assert!(menu.input(key(KeyCode::Esc)));
+    assert!(!menu.is_open());
+    assert_eq!(menu.take_chosen(), None);
+}
+
+#[test]
+fn render_levels() {
+    let mut menu = Menu::new(vec![
+        MenuItem::new("new", "New").accelerator('n'),
+        MenuItem::new("open", "Open").disabled(true),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            "Export",
+            vec![MenuItem::new("pdf", "PDF"), MenuItem::new("png", "PNG")],
+        )
+        .accelerator('e'),
+    ]);
+    let mut canvas = Canvas::default();
+
+    menu.open_at((0, 0));
+    menu.input(key(KeyCode::Char('e')));
+
+    let ids = menu.show(&mut canvas, Rect(20, 6));
+
+    assert_eq!(ids.len(), 2);
+    assert_eq!(canvas.z_index(ids[1]), Some(1));
+    assert_eq!(
+        rows(&canvas),
+        vec![
+            "┌───────────┐       ",
+            "│ New     n │       ",
+            "│ Open      │┌─────┐",
+            "├───────────┤│ PDF │",
+            "│ Export  ▶ ││ PNG │",
+            "└───────────┘└─────┘",
+        ]
+    );
+
+    for id in ids {
+        assert!(canvas.take_layer(id).is_some());
+    }
+
+    assert!(canvas.inner_vec().is_empty());
+}
+
+#[test]
+fn flip_on_overflow() {
+    let mut menu = Menu::new(vec![
+        MenuItem::new("new", "New").accelerator('n'),
+        MenuItem::new("open", "Open").disabled(true),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            "Export",
+            vec![MenuItem::new("pdf", "PDF"), MenuItem::new("png", "PNG")],
+        )
+        .accelerator('e'),
+    ]);
+
+    menu.open_at((15, 5));
+    menu.input(key(KeyCode::Char('e')));
+
+    let areas = menu.areas(Rect(20, 8));
+
+    assert_eq!(areas[0].base_pos(), (3, 0));
+    assert_eq!(areas[1].base_pos(), (0, 3));
+}
+
+#[test]
+fn empty_menu_and_screen() {
+    let mut empty = Menu::new(vec![]);
+    let mut canvas = Canvas::default();
+
+    empty.open_at((0, 0));
+
+    assert!(!empty.is_open());
+    assert!(!empty.input(key(KeyCode::Right)));
+    assert!(!empty.input(key(KeyCode::Enter)));
+    assert!(empty.show(&mut canvas, Rect(20, 6)).is_empty());
+
+    let mut menu = Menu::new(vec![
+        MenuItem::new("new", "New").accelerator('n'),
+        MenuItem::new("open", "Open").disabled(true),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            "Export",
+            vec![MenuItem::new("pdf", "PDF"), MenuItem::new("png", "PNG")],
+        )
+        .accelerator('e'),
+    ]);
+
+    menu.open_at((0, 0));
+
+    assert!(menu.input(key(KeyCode::Right)));
+    assert_eq!(menu.show(&mut canvas, Rect(0, 6)).len(), 1);
+}
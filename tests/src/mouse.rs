@@ -0,0 +1,247 @@
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use eired::{Hit, HitMap, MouseAction, MouseRouter};
+use eired_display::{Annotate, Canvas, Layer, Span, View, Viewport, Window};
+
+fn event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+#[test]
+fn canvas_hits_by_z_index() {
+    let mut canvas = Canvas::default();
+    let mut back = Layer::default();
+    let mut front = Layer::default();
+
+    back.push_span_write(Span::from("aaaaaa").annotate((0, 0)));
+    back.push_span_write(Span::from("aaaaaa").annotate((0, 1)));
+    front.push_span_write(Span::from("bb").annotate((0, 0)));
+
+    canvas.overlap_layer(back.annotate((0, 0)));
+    canvas.overlap_layer(front.annotate((2, 1)));
+
+    assert_eq!(canvas.hits(2, 1), vec![1, 0]);
+    assert_eq!(canvas.hit_test(2, 1), Some(1));
+    assert_eq!(canvas.hit_test(0, 1), Some(0));
+    assert_eq!(canvas.hit_test(7, 0), None);
+}
+
+#[test]
+fn window_hits_top_view() {
+    let window = Window::from_views(
+        8,
+        4,
+        vec![
+            View::new(8, 4, vec![None; 32]).annotate((0, 0)),
+            View::new(3, 2, vec![None; 6]).annotate((4, 1)),
+        ],
+    );
+
+    assert_eq!(window.hit_test(5, 2), Some((1, (1, 1))));
+    assert_eq!(window.hit_test(3, 2), Some((0, (3, 2))));
+    assert_eq!(window.hit_test(8, 0), None);
+}
+
+#[test]
+fn hit_map_layers_and_views() {
+    let mut canvas = Canvas::default();
+    let mut back = Layer::default();
+    let mut front = Layer::default();
+    let mut window = Window::new(10, 4);
+
+    back.push_span_write(Span::from("aaaaaa").annotate((0, 0)));
+    back.push_span_write(Span::from("aaaaaa").annotate((0, 1)));
+    front.push_span_write(Span::from("bb").annotate((0, 0)));
+
+    canvas.overlap_layer(back.annotate((0, 0)));
+    canvas.overlap_layer(front.annotate((2, 1)));
+
+    window.overlap(View::new(10, 4, vec![None; 40]).annotate((0, 0)));
+    window.overlap(canvas.create_view().annotate((1, 1)));
+
+    let mut hits = HitMap::default();
+
+    hits.register_view(0, "screen");
+    hits.register_view(1, "panel");
+    hits.register_canvas(1, &canvas, (0, 0), vec![(1, "button")]);
+
+    assert_eq!(
+        hits.hit_test(&window, 3, 2),
+        Some(Hit {
+            id: "button",
+            pos: (2, 1)
+        })
+    );
+    // The back layer is not registered, falls back to the view
+    assert_eq!(hits.hit_test(&window, 1, 1).unwrap().id, "panel");
+    assert_eq!(hits.hit_test(&window, 0, 0).unwrap().id, "screen");
+
+    hits.clear();
+
+    assert_eq!(hits.hit_test(&window, 3, 2), None);
+}
+
+#[test]
+fn hit_map_scrolled_canvas() {
+    let mut canvas = Canvas::default();
+
+    for y in 0..10 {
+        let mut layer = Layer::default();
+
+        layer.push_span_write(Span::from("row").annotate((0, 0)));
+        canvas.insert(y as usize, layer.annotate((0, y)));
+    }
+
+    let mut viewport = Viewport::new(3, 2);
+
+    viewport.set_content_size(canvas.size());
+    viewport.scroll_by((0, 6));
+
+    let mut window = Window::new(5, 3);
+
+    window.overlap(viewport.create_view(&canvas).annotate((1, 1)));
+
+    let mut hits = HitMap::default();
+
+    hits.register_canvas(
+        0,
+        &canvas,
+        viewport.offset(),
+        vec![(0, "first"), (6, "sixth"), (7, "seventh")],
+    );
+
+    assert_eq!(
+        hits.hit_test(&window, 2, 1),
+        Some(Hit {
+            id: "sixth",
+            pos: (1, 0)
+        })
+    );
+    assert_eq!(hits.hit_test(&window, 1, 2).unwrap().id, "seventh");
+}
+
+#[test]
+fn router_click_and_hover() {
+    let window = Window::from_views(
+        6,
+        1,
+        vec![
+            View::new(3, 1, vec![None; 3]).annotate((0, 0)),
+            View::new(3, 1, vec![None; 3]).annotate((3, 0)),
+        ],
+    );
+    let mut hits = HitMap::default();
+    let mut router = MouseRouter::default();
+
+    hits.register_view(0, "left");
+    hits.register_view(1, "right");
+
+    assert_eq!(
+        router.route(event(MouseEventKind::Moved, 1, 0), &hits, &window),
+        vec![
+            MouseAction::Enter("left"),
+            MouseAction::Hover("left", (1, 0))
+        ]
+    );
+    assert_eq!(
+        router.route(event(MouseEventKind::Moved, 4, 0), &hits, &window),
+        vec![
+            MouseAction::Leave("left"),
+            MouseAction::Enter("right"),
+            MouseAction::Hover("right", (1, 0))
+        ]
+    );
+    assert_eq!(router.hovered(), Some(&"right"));
+
+    let left = MouseButton::Left;
+
+    router.route(event(MouseEventKind::Down(left), 4, 0), &hits, &window);
+
+    assert_eq!(
+        router.route(event(MouseEventKind::Up(left), 5, 0), &hits, &window),
+        vec![
+            MouseAction::Release("right", left, (2, 0)),
+            MouseAction::Click("right", left, (2, 0))
+        ]
+    );
+}
+
+#[test]
+fn router_drag_keeps_capture() {
+    let window = Window::from_views(
+        6,
+        1,
+        vec![
+            View::new(3, 1, vec![None; 3]).annotate((0, 0)),
+            View::new(3, 1, vec![None; 3]).annotate((3, 0)),
+        ],
+    );
+    let mut hits = HitMap::default();
+    let mut router = MouseRouter::default();
+    let left = MouseButton::Left;
+
+    hits.register_view(0, "left");
+    hits.register_view(1, "right");
+
+    router.route(event(MouseEventKind::Down(left), 4, 0), &hits, &window);
+
+    assert!(router.is_dragging());
+
+    let actions = router.route(event(MouseEventKind::Drag(left), 1, 0), &hits, &window);
+
+    assert!(actions.contains(&MouseAction::Drag("right", left, (1, 0), (0, 0))));
+
+    let actions = router.route(event(MouseEventKind::Up(left), 1, 0), &hits, &window);
+
+    assert!(actions.contains(&MouseAction::Release("right", left, (0, 0))));
+    assert!(!actions.iter().any(|a| matches!(a, MouseAction::Click(..))));
+    assert!(!router.is_dragging());
+}
+
+#[test]
+fn router_scroll() {
+    let window = Window::from_views(3, 1, vec![View::new(3, 1, vec![None; 3]).annotate((0, 0))]);
+    let mut hits = HitMap::default();
+    let mut router = MouseRouter::default();
+
+    hits.register_view(0, "list");
+
+    let actions = router.route(event(MouseEventKind::ScrollDown, 0, 0), &hits, &window);
+
+    assert!(actions.contains(&MouseAction::Scroll("list", (0, 1))));
+
+    let actions = router.route(event(MouseEventKind::ScrollUp, 0, 0), &hits, &window);
+
+    assert_eq!(actions, vec![MouseAction::Scroll("list", (0, -1))]);
+}
+
+#[test]
+fn router_offscreen_view() {
+    let window = Window::from_views(
+        10,
+        2,
+        vec![View::new(20, 1, vec![None; 20]).annotate_signed((-5, 0))],
+    );
+    let mut hits = HitMap::default();
+    let mut router = MouseRouter::default();
+    let left = MouseButton::Left;
+
+    hits.register_view(0, "wide");
+
+    let actions = router.route(event(MouseEventKind::Down(left), 0, 0), &hits, &window);
+
+    assert!(actions.contains(&MouseAction::Press("wide", left, (5, 0))));
+
+    let actions = router.route(event(MouseEventKind::Drag(left), 3, 1), &hits, &window);
+
+    assert!(actions.contains(&MouseAction::Drag("wide", left, (5, 0), (8, 1))));
+
+    let actions = router.route(event(MouseEventKind::Up(left), 0, 0), &hits, &window);
+
+    assert!(actions.contains(&MouseAction::Release("wide", left, (5, 0))));
+    assert!(actions.contains(&MouseAction::Click("wide", left, (5, 0))));
+}
@@ -0,0 +1,172 @@
+use crossterm::event::KeyCode;
+use crossterm::style::Color;
+use eired::{Command, CommandPalette, Widget, fuzzy_match};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::{key, screen};
+
+#[test]
+fn match_case_insensitive() {
+    let matched = fuzzy_match("OF", "open file").unwrap();
+
+    assert_eq!(matched.indices(), &[0, 5]);
+    assert!(fuzzy_match("", "anything").unwrap().indices().is_empty());
+    assert!(fuzzy_match("abc", "ab").is_none());
+}
+
+#[test]
+fn prefer_consecutive() {
+    let consecutive = fuzzy_match("abc", "xabcx").unwrap();
+    let spread = fuzzy_match("abc", "xaxbxc").unwrap();
+
+    assert_eq!(consecutive.indices(), &[1, 2, 3]);
+    assert!(consecutive.score() > spread.score());
+}
+
+#[test]
+fn prefer_camel_case() {
+    let camel = fuzzy_match("gc", "getConfig").unwrap();
+    let inner = fuzzy_match("gc", "magic").unwrap();
+
+    assert_eq!(camel.indices(), &[0, 3]);
+    assert!(camel.score() > inner.score());
+}
+
+#[test]
+fn rank_results() {
+    let mut palette = CommandPalette::default();
+
+    palette.register(Command::new("open", "Open File").key_hint("C-o"));
+    palette.register(Command::new("save", "Save File").key_hint("C-s"));
+    palette.register(Command::new("fmt", "Format Document"));
+    palette.register(Command::new("quit", "Quit"));
+    palette.open();
+
+    palette.set_query("f");
+
+    let ids = palette
+        .results()
+        .iter()
+        .map(|c| c.id().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, vec!["fmt", "open", "save"]);
+}
+
+#[test]
+fn edit_query() {
+    let mut palette = CommandPalette::default();
+
+    palette.register(Command::new("open", "Open File").key_hint("C-o"));
+    palette.register(Command::new("save", "Save File").key_hint("C-s"));
+    palette.register(Command::new("fmt", "Format Document"));
+    palette.register(Command::new("quit", "Quit"));
+    palette.open();
+
+    for c in "sve".chars() {
+        palette.input(key(KeyCode::Char(c)));
+    }
+
+    palette.input(key(KeyCode::Left));
+    palette.input(key(KeyCode::Left));
+    palette.input(key(KeyCode::Char('a')));
+
+    assert_eq!(palette.query(), "save");
+
+    palette.input(key(KeyCode::Backspace));
+
+    assert_eq!(palette.query(), "sve");
+    assert_eq!(palette.selected().unwrap().id(), "save");
+}
+
+#[test]
+fn choose_and_close() {
+    let mut palette = CommandPalette::default();
+
+    palette.register(Command::new("open", "Open File").key_hint("C-o"));
+    palette.register(Command::new("save", "Save File").key_hint("C-s"));
+    palette.register(Command::new("fmt", "Format Document"));
+    palette.register(Command::new("quit", "Quit"));
+    palette.open();
+
+    palette.input(key(KeyCode::Down));
+    palette.input(key(KeyCode::Down));
+    palette.input(key(KeyCode::Up));
+    palette.input(key(KeyCode::Enter));
+
+    assert_eq!(palette.take_chosen(), Some("save".to_string()));
+    assert!(!palette.is_open());
+    assert!(!palette.input(key(KeyCode::Enter)));
+
+    palette.open();
+    palette.set_query("zzz");
+    palette.input(key(KeyCode::Enter));
+
+    assert!(palette.is_open());
+    assert_eq!(palette.take_chosen(), None);
+}
+
+#[test]
+fn render_list() {
+    let mut palette = CommandPalette::default();
+    let mut layer = Layer::default();
+
+    palette.register(Command::new("open", "Open File").key_hint("C-o"));
+    palette.register(Command::new("save", "Save File").key_hint("C-s"));
+    palette.register(Command::new("fmt", "Format Document"));
+    palette.register(Command::new("quit", "Quit"));
+    palette.open();
+
+    palette.set_query("file");
+    palette.render(&mut layer, Rect(16, 4).annotate((0, 0)));
+
+    assert_eq!(
+        screen(layer),
+        vec![
+            "> file          ",
+            "────────────────",
+            " Open File  C-o ",
+            " Save File  C-s ",
+        ]
+    );
+}
+
+#[test]
+fn scroll_list() {
+    let mut palette = CommandPalette::default();
+    let mut layer = Layer::default();
+
+    palette.register(Command::new("open", "Open File").key_hint("C-o"));
+    palette.register(Command::new("save", "Save File").key_hint("C-s"));
+    palette.register(Command::new("fmt", "Format Document"));
+    palette.register(Command::new("quit", "Quit"));
+    palette.open();
+
+    palette.render(&mut Layer::default(), Rect(16, 3).annotate((0, 0)));
+    palette.input(key(KeyCode::PageDown));
+    palette.input(key(KeyCode::PageDown));
+    palette.input(key(KeyCode::PageDown));
+    palette.input(key(KeyCode::PageDown));
+    palette.render(&mut layer, Rect(16, 3).annotate((0, 0)));
+
+    let rows = screen(layer);
+
+    assert_eq!(rows[2], " Quit           ");
+    assert_eq!(layer_color(&mut palette), Color::Yellow);
+}
+
+fn layer_color(palette: &mut CommandPalette) -> Color {
+    let mut layer = Layer::default();
+
+    palette.set_query("q");
+    palette.render(&mut layer, Rect(16, 3).annotate((0, 0)));
+
+    layer
+        .inner()
+        .iter()
+        .filter(|s| s.base_pos().1 == 2)
+        .flat_map(|s| s.inner().to_vec())
+        .find(|c| c.ch == 'Q')
+        .unwrap()
+        .fg
+}
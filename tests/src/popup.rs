@@ -0,0 +1,177 @@
+use crossterm::event::KeyCode;
+use crossterm::style::Color;
+use eired::{Dialog, DialogResult, Placement, Popup};
+use eired_display::{Annotate, Canvas, Layer, Rect, Span};
+
+use crate::helper::{key, rows};
+
+#[test]
+fn clear_beneath() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+    let popup = Popup::new(4, 3);
+
+    for y in 0..5 {
+        layer.push_span_write(Span::from("........").annotate((0, y)));
+    }
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    assert_eq!(popup.show(&mut canvas, Rect(8, 5), |_, _| {}).len(), 1);
+    assert_eq!(
+        rows(&canvas),
+        vec!["........", "..┌──┐..", "..│  │..", "..└──┘..", "........"]
+    );
+}
+
+#[test]
+fn title_and_shadow() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+    let mut popup = Popup::new(5, 3);
+
+    for y in 0..5 {
+        layer.push_span_write(Span::from("........").annotate((0, y)));
+    }
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    popup.set_title(Some("T"));
+    popup.set_shadow(true);
+    popup.set_placement(Placement::Anchor(Rect(1, 1).annotate((0, 0))));
+
+    assert_eq!(popup.show(&mut canvas, Rect(8, 5), |_, _| {}).len(), 2);
+    assert_eq!(
+        rows(&canvas),
+        vec!["┌ T ┐...", "│   │...", "└───┘...", "........", "........"]
+    );
+
+    let view = canvas.create_view();
+
+    assert_eq!(view.get_line(1)[5].unwrap().bg, Color::Black);
+    assert_eq!(view.get_line(3)[1].unwrap().bg, Color::Black);
+    assert_eq!(view.get_line(3)[0].unwrap().bg, Color::Reset);
+}
+
+#[test]
+fn dim_screen() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+    let mut popup = Popup::new(2, 2);
+
+    for y in 0..5 {
+        layer.push_span_write(Span::from("........").annotate((0, y)));
+    }
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    popup.set_dim(true);
+    popup.show(&mut canvas, Rect(8, 5), |_, _| {});
+
+    let view = canvas.create_view();
+
+    assert_eq!(view.get_line(0)[0].unwrap().fg, Color::DarkGrey);
+    assert_eq!(view.get_line(0)[0].unwrap().ch, '.');
+    assert_eq!(view.get_line(1)[3].unwrap().ch, '┌');
+}
+
+#[test]
+fn dismiss_layers() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+    let mut popup = Popup::new(4, 3);
+
+    for y in 0..5 {
+        layer.push_span_write(Span::from("........").annotate((0, y)));
+    }
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    popup.set_dim(true);
+    popup.set_shadow(true);
+
+    let before = rows(&canvas);
+
+    for _ in 0..3 {
+        let ids = popup.show(&mut canvas, Rect(8, 5), |_, _| {});
+
+        assert_eq!(ids.len(), 2);
+        assert_eq!(canvas.inner_vec().len(), 3);
+
+        for id in ids {
+            assert!(canvas.take_layer(id).is_some());
+        }
+    }
+
+    assert_eq!(rows(&canvas), before);
+    assert_eq!(
+        canvas.create_view().get_line(0)[0].unwrap().fg,
+        Color::Reset
+    );
+}
+
+#[test]
+fn closed_popup() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+    let mut popup = Popup::new(2, 2);
+
+    for y in 0..5 {
+        layer.push_span_write(Span::from("........").annotate((0, y)));
+    }
+
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    popup.close();
+
+    assert!(popup.show(&mut canvas, Rect(8, 5), |_, _| {}).is_empty());
+    assert_eq!(canvas.inner_vec().len(), 1);
+}
+
+#[test]
+fn dialog_layout() {
+    let mut canvas = Canvas::default();
+    let mut dialog = Dialog::new("Quit?", vec!["Y", "N"]);
+
+    dialog.popup_mut().set_shadow(false);
+    dialog.show(&mut canvas, Rect(9, 5));
+
+    assert_eq!(
+        rows(&canvas),
+        vec![
+            "┌───────┐",
+            "│ Quit? │",
+            "│       │",
+            "│[Y] [N]│",
+            "└───────┘"
+        ]
+    );
+}
+
+#[test]
+fn dialog_captures_input() {
+    let mut dialog = Dialog::new("Quit?", vec!["Yes", "No"]);
+
+    assert!(dialog.input(key(KeyCode::Char('q'))));
+    assert!(dialog.input(key(KeyCode::Left)));
+    assert_eq!(dialog.selected(), 1);
+    assert!(dialog.input(key(KeyCode::Esc)));
+    assert_eq!(dialog.take_result(), Some(DialogResult::Dismissed));
+    assert!(!dialog.input(key(KeyCode::Enter)));
+
+    dialog.open();
+
+    assert!(dialog.is_open());
+    assert_eq!(dialog.take_result(), None);
+}
+
+#[test]
+fn oversized_dialog() {
+    let mut canvas = Canvas::default();
+    let wide = Dialog::new("x".repeat(70_000), vec!["Y"]);
+    let tall = Dialog::new("x\n".repeat(70_000), vec!["Y"]);
+
+    assert_eq!(wide.popup().size(), (u16::MAX, 5));
+    assert_eq!(tall.popup().size(), (7, u16::MAX));
+    assert_eq!(wide.show(&mut canvas, Rect(9, 5)).len(), 2);
+}
@@ -0,0 +1,91 @@
+use eired::{Orientation, ScrollState, Scrollbar};
+use eired_display::Rect;
+
+use crate::helper::render;
+
+#[test]
+fn state_clamp() {
+    let mut state = ScrollState::new(10, 4);
+
+    state.set_offset(100);
+    assert_eq!(state.offset(), 6);
+    assert!(state.is_at_bottom());
+
+    state.set_content_len(8);
+    assert_eq!(state.offset(), 4);
+
+    state.set_viewport_len(20);
+    assert_eq!(state.offset(), 0);
+    assert!(state.is_at_top() && state.is_at_bottom());
+}
+
+#[test]
+fn state_paging() {
+    let mut state = ScrollState::new(10, 4);
+
+    state.page_down();
+    assert_eq!(state.offset(), 4);
+
+    state.page_down();
+    assert_eq!(state.offset(), 6);
+
+    state.page_up();
+    assert_eq!(state.offset(), 2);
+
+    state.ensure_visible(9);
+    assert_eq!(state.offset(), 6);
+
+    state.ensure_visible(7);
+    assert_eq!(state.offset(), 6);
+
+    state.scroll_to_top();
+    assert_eq!(state.visible_range(), 0..4);
+}
+
+#[test]
+fn vertical_thumb() {
+    let mut scrollbar = Scrollbar::new(Orientation::Vertical);
+
+    scrollbar.set_state(ScrollState::new(12, 3));
+
+    assert_eq!(
+        render(&mut scrollbar, Rect(1, 6)),
+        vec!["▲", "█", "│", "│", "│", "▼"]
+    );
+
+    scrollbar.state_mut().scroll_down(5);
+
+    assert_eq!(
+        render(&mut scrollbar, Rect(1, 6)),
+        vec!["▲", "│", "│", "█", "│", "▼"]
+    );
+
+    scrollbar.state_mut().scroll_to_bottom();
+
+    assert_eq!(
+        render(&mut scrollbar, Rect(1, 6)),
+        vec!["▲", "│", "│", "│", "█", "▼"]
+    );
+}
+
+#[test]
+fn content_fits() {
+    let mut scrollbar = Scrollbar::new(Orientation::Horizontal);
+
+    scrollbar.set_state(ScrollState::new(3, 10));
+    scrollbar.set_arrows(None);
+
+    assert_eq!(render(&mut scrollbar, Rect(4, 2)), vec!["████"]);
+}
+
+#[test]
+fn custom_symbols() {
+    let mut scrollbar = Scrollbar::new(Orientation::Horizontal);
+
+    scrollbar.set_state(ScrollState::new(8, 4));
+    scrollbar.set_track_symbol('.');
+    scrollbar.set_thumb_symbol('#');
+    scrollbar.set_arrows(Some(('<', '>')));
+
+    assert_eq!(render(&mut scrollbar, Rect(6, 1)), vec!["<##..>"]);
+}
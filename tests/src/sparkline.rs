@@ -0,0 +1,35 @@
+use eired::{Sparkline, Widget};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::screen;
+
+#[test]
+fn multi_row() {
+    let mut sparkline = Sparkline::new(vec![1, 4, 2]);
+    let mut layer = Layer::default();
+
+    sparkline.render(&mut layer, Rect(3, 2).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec![" █ ", "▄██"]);
+}
+
+#[test]
+fn latest_values() {
+    let mut sparkline = Sparkline::new(vec![8, 8, 0, 4, 8]);
+    let mut layer = Layer::default();
+
+    sparkline.render(&mut layer, Rect(3, 1).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec![" ▄█"]);
+}
+
+#[test]
+fn fixed_max() {
+    let mut sparkline = Sparkline::new(vec![1, 2, 100]);
+    let mut layer = Layer::default();
+
+    sparkline.set_max(Some(2));
+    sparkline.render(&mut layer, Rect(3, 1).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec!["▄██"]);
+}
@@ -0,0 +1,132 @@
+use crossterm::style::Color;
+use eired::{Style, TabContainer, Tabs, Widget};
+use eired_display::{Annotate, Canvas, Layer, Rect, Span};
+
+use crate::helper::{render, window_rows};
+
+#[test]
+fn fit_in_width() {
+    let mut tabs = Tabs::new(vec!["A", "B"]);
+
+    tabs.set_divider("|");
+
+    assert_eq!(render(&mut tabs, Rect(10, 1)), vec![" A | B "]);
+}
+
+#[test]
+fn highlight_selected() {
+    let mut tabs = Tabs::new(vec!["A", "B"]);
+    let mut layer = Layer::default();
+
+    tabs.set_highlight_style(Style::new().fg(Color::Red));
+    tabs.select(1);
+    tabs.render(&mut layer, Rect(10, 1).annotate((0, 0)));
+
+    let cells = layer.inner()[0].inner().to_vec();
+
+    assert_eq!(cells[0].fg, Color::Reset);
+    assert_eq!(cells[5].ch, 'B');
+    assert_eq!(cells[5].fg, Color::Red);
+}
+
+#[test]
+fn scroll_to_selected() {
+    let mut tabs = Tabs::new(vec!["Alpha", "Beta", "Gamma", "Delta"]);
+
+    tabs.select(3);
+
+    assert_eq!(render(&mut tabs, Rect(12, 1)), vec!["◀a │ Delta "]);
+}
+
+#[test]
+fn scroll_back() {
+    let mut tabs = Tabs::new(vec!["Alpha", "Beta", "Gamma", "Delta"]);
+
+    tabs.select(3);
+    tabs.render(&mut Layer::default(), Rect(12, 1).annotate((0, 0)));
+    tabs.select(0);
+
+    assert_eq!(render(&mut tabs, Rect(12, 1)), vec!["  Alpha │ B▶"]);
+}
+
+#[test]
+fn remove_keeps_selection() {
+    let mut tabs = Tabs::new(vec!["Alpha", "Beta", "Gamma", "Delta"]);
+
+    tabs.select(3);
+
+    assert_eq!(tabs.remove(1), Some("Beta".to_string()));
+    assert_eq!(tabs.selected(), 2);
+    assert_eq!(tabs.remove(2), Some("Delta".to_string()));
+    assert_eq!(tabs.selected(), 1);
+    assert_eq!(tabs.remove(5), None);
+}
+
+fn page(text: &str) -> Canvas {
+    let mut layer = Layer::default();
+    let mut canvas = Canvas::default();
+
+    layer.push_span_write(Span::from(text).annotate((0, 0)));
+    canvas.overlap_layer(layer.annotate((0, 0)));
+
+    canvas
+}
+
+#[test]
+fn composite_active_page() {
+    let mut container = TabContainer::default();
+
+    container.push("A", page("first"));
+    container.push("B", page("second"));
+    container.tabs_mut().set_divider("|");
+
+    let area = Rect(10, 3).annotate((0, 0));
+
+    assert_eq!(
+        window_rows(Rect(10, 3), |window| container.composite(window, area)),
+        vec![" A | B    ", "first     ", "          "]
+    );
+
+    container.next();
+
+    assert_eq!(
+        window_rows(Rect(10, 3), |window| container.composite(window, area)),
+        vec![" A | B    ", "second    ", "          "]
+    );
+}
+
+#[test]
+fn oversized_page() {
+    let mut container = TabContainer::default();
+    let mut content = page("0123456789ABC");
+    let mut layer = Layer::default();
+
+    for y in 1..6 {
+        layer.push_span_write(Span::from("xxxxxxxx").annotate((0, y)));
+    }
+
+    content.insert(1, layer.annotate((0, 0)));
+    container.push("A", content);
+
+    let area = Rect(6, 2).annotate((2, 0));
+
+    assert_eq!(
+        window_rows(Rect(10, 3), |window| container.composite(window, area)),
+        vec!["   A      ", "  012345  ", "          "]
+    );
+}
+
+#[test]
+fn remove_page() {
+    let mut container = TabContainer::default();
+
+    container.push("A", page("first"));
+    container.push("B", page("second"));
+    container.select(1);
+
+    let (title, _) = container.remove(1).unwrap();
+
+    assert_eq!(title, "B");
+    assert_eq!(container.selected(), 0);
+    assert!(container.page(1).is_none());
+}
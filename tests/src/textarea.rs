@@ -0,0 +1,205 @@
+use crossterm::style::Color;
+use eired::{CursorMove, Style, TextArea, Widget};
+use eired_display::{Annotate, Layer, Rect};
+
+use crate::helper::screen;
+
+#[test]
+fn edit_multi_line() {
+    let mut textarea = TextArea::new();
+
+    textarea.insert_str("Hello\nWorld");
+    textarea.move_cursor(CursorMove::Head);
+    textarea.delete_char();
+
+    assert_eq!(textarea.text(), "HelloWorld");
+    assert_eq!(textarea.cursor(), (0, 5));
+
+    textarea.insert_newline();
+    textarea.delete_next_char();
+
+    assert_eq!(textarea.lines(), vec!["Hello", "orld"]);
+}
+
+#[test]
+fn cut_and_paste() {
+    let mut textarea = TextArea::from("Hello, World!");
+
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::WordForward);
+    textarea.cut();
+
+    assert_eq!(textarea.register(), "Hello, ");
+    assert_eq!(textarea.text(), "World!");
+
+    textarea.move_cursor(CursorMove::End);
+    textarea.paste();
+
+    assert_eq!(textarea.text(), "World!Hello, ");
+}
+
+#[test]
+fn undo_redo() {
+    let mut textarea = TextArea::new();
+
+    textarea.insert_str("A");
+    textarea.insert_str("B");
+    textarea.undo();
+
+    assert_eq!(textarea.text(), "A");
+
+    textarea.redo();
+
+    assert_eq!(textarea.text(), "AB");
+
+    textarea.undo();
+    textarea.insert_str("C");
+
+    assert!(!textarea.redo());
+    assert_eq!(textarea.text(), "AC");
+}
+
+#[test]
+fn undo_typed_chars_at_once() {
+    let mut textarea = TextArea::new();
+
+    for c in "Hi".chars() {
+        textarea.insert_char(c);
+    }
+
+    textarea.insert_char('\n');
+
+    for c in "there".chars() {
+        textarea.insert_char(c);
+    }
+
+    assert_eq!(textarea.text(), "Hi\nthere");
+    assert!(textarea.undo());
+    assert_eq!(textarea.text(), "Hi\n");
+    assert!(textarea.undo());
+    assert_eq!(textarea.text(), "Hi");
+
+    // A cursor move splits typing
+    textarea.move_cursor(CursorMove::Head);
+    textarea.insert_char('>');
+    textarea.move_cursor(CursorMove::End);
+    textarea.insert_char('!');
+    textarea.insert_char('!');
+    textarea.undo();
+
+    assert_eq!(textarea.text(), ">Hi");
+    assert!(textarea.undo());
+    assert!(textarea.undo());
+    assert!(!textarea.undo());
+    assert_eq!(textarea.text(), "");
+}
+
+#[test]
+fn insert_empty_str() {
+    let mut textarea = TextArea::from("A");
+
+    textarea.insert_str("");
+
+    assert!(!textarea.undo());
+
+    textarea.select_all();
+    textarea.insert_str("");
+
+    assert_eq!(textarea.text(), "");
+    assert!(textarea.undo());
+    assert_eq!(textarea.text(), "A");
+}
+
+#[test]
+fn render_wrap() {
+    let mut textarea = TextArea::from("Hello, World!");
+    let mut layer = Layer::default();
+
+    textarea.set_wrap(true);
+    textarea.render(&mut layer, Rect(5, 3).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec!["Hello", ", Wor", "ld!  "]);
+}
+
+#[test]
+fn render_horizontal_scroll() {
+    let mut textarea = TextArea::from("Hello, World!");
+    let mut layer = Layer::default();
+
+    textarea.move_cursor(CursorMove::End);
+    textarea.render(&mut layer, Rect(5, 1).annotate((0, 0)));
+
+    assert_eq!(screen(layer), vec!["rld! "]);
+}
+
+#[test]
+fn render_selection() {
+    let mut textarea = TextArea::from("Hello");
+    let mut layer = Layer::default();
+
+    textarea.set_selection_style(Style::new().bg(Color::Blue));
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::Forward);
+    textarea.move_cursor(CursorMove::Forward);
+    textarea.render(&mut layer, Rect(5, 1).annotate((0, 0)));
+
+    let line = layer.inner()[0].inner();
+
+    assert_eq!(line.get(0).unwrap().bg, Color::Blue);
+    assert_eq!(line.get(1).unwrap().bg, Color::Blue);
+    assert_ne!(line.get(2).unwrap().bg, Color::Blue);
+}
+
+#[test]
+fn render_line_numbers() {
+    let text = (1..=10)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut textarea = TextArea::from(text.as_str());
+    let mut layer = Layer::default();
+
+    textarea.set_line_numbers(true);
+    textarea.set_line_number_style(Style::new().fg(Color::Green));
+    textarea.set_cursor(9, 0);
+    textarea.render(&mut layer, Rect(6, 2).annotate((0, 0)));
+
+    let line = layer.inner()[1].inner().clone();
+
+    assert_eq!(line.get(0).unwrap().fg, Color::Green);
+    assert_eq!(line.get(1).unwrap().fg, Color::Green);
+    assert_eq!(line.get(4).unwrap().fg, Color::Reset);
+    assert_eq!(screen(layer), vec![" 9 9  ", "10 10 "]);
+}
+
+#[test]
+fn render_highlights() {
+    let mut textarea = TextArea::from("let x = 1;\nfn f() {}");
+    let mut layer = Layer::default();
+
+    textarea.set_cursor(1, 9);
+    textarea.add_highlight((0, 0), (0, 3), Style::new().fg(Color::Blue));
+    textarea.add_highlight((0, 8), (1, 2), Style::new().fg(Color::Yellow));
+    textarea.add_highlight((0, 2), (0, 5), Style::new().bg(Color::Red));
+    textarea.render(&mut layer, Rect(10, 2).annotate((0, 0)));
+
+    let first = layer.inner()[0].inner().clone();
+    let second = layer.inner()[1].inner().clone();
+
+    assert_eq!(first.get(0).unwrap().fg, Color::Blue);
+    assert_eq!(first.get(2).unwrap().fg, Color::Blue);
+    assert_eq!(first.get(2).unwrap().bg, Color::Red);
+    assert_eq!(first.get(4).unwrap().bg, Color::Red);
+    assert_eq!(first.get(5).unwrap().bg, Color::Reset);
+    assert_eq!(first.get(8).unwrap().fg, Color::Yellow);
+    assert_eq!(second.get(1).unwrap().fg, Color::Yellow);
+    assert_eq!(second.get(2).unwrap().fg, Color::Reset);
+
+    textarea.clear_highlights();
+
+    let mut layer = Layer::default();
+
+    textarea.render(&mut layer, Rect(10, 2).annotate((0, 0)));
+
+    assert_eq!(layer.inner()[0].inner().get(0).unwrap().fg, Color::Reset);
+}
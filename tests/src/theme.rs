@@ -0,0 +1,206 @@
+use crossterm::style::{Attribute, Color};
+use eired::{
+    CommandPalette, Dialog, Gauge, Keymap, Style, Tabs, Theme, ThemeError, ThemeRegistry, Themed,
+    Widget,
+};
+use eired_display::{Annotate, Canvas, Cell, DrawableSpan, Layer, Rect};
+
+const DARK: &str = "
+# Dark theme
+text           = fg:grey bg:black
+error          = fg:red bold underlined
+selection      = fg:black bg:#ffcc00
+border         = fg:dark-grey
+border.focused = fg:cyan bg:236
+";
+
+#[test]
+fn parse_theme() {
+    let theme = Theme::parse("dark", DARK).unwrap();
+
+    assert_eq!(theme.name(), "dark");
+    assert_eq!(
+        theme.names(),
+        vec!["border", "border.focused", "error", "selection", "text"]
+    );
+    assert_eq!(
+        theme.get("error"),
+        Some(
+            Style::new()
+                .fg(Color::Red)
+                .attr(Attribute::Bold)
+                .attr(Attribute::Underlined)
+        )
+    );
+    assert_eq!(
+        theme.get("selection"),
+        Some(Style::new().fg(Color::Black).bg(Color::Rgb {
+            r: 0xff,
+            g: 0xcc,
+            b: 0x00
+        }))
+    );
+    assert_eq!(
+        theme.style("border.focused.title"),
+        Style::new().fg(Color::Cyan).bg(Color::AnsiValue(236))
+    );
+    assert_eq!(theme.get("border.focused.title"), None);
+    assert_eq!(theme.style("border.disabled").fg, Some(Color::DarkGrey));
+}
+
+#[test]
+fn parse_errors() {
+    let error = |text: &str| match Theme::parse("bad", text) {
+        Err(ThemeError::Parse { line, message }) => (line, message),
+        e => panic!("unexpected: {e:?}"),
+    };
+
+    assert_eq!(error("a = fg:red\nb fg:red").0, 2);
+    assert_eq!(error("a = fg:purple").1, "invalid color: `purple`");
+    assert_eq!(error("a = bg:#12345").1, "invalid color: `#12345`");
+    assert_eq!(error("\n\na = shiny").0, 3);
+    assert_eq!(error("a b = bold").1, "invalid name: `a b`");
+}
+
+#[test]
+fn load_theme_file() {
+    let path = std::env::temp_dir().join(format!("eired-theme-{}.theme", std::process::id()));
+
+    std::fs::write(&path, DARK).unwrap();
+
+    let theme = Theme::load(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(theme.name(), path.file_stem().unwrap().to_str().unwrap());
+    assert_eq!(
+        theme.style("text"),
+        Style::new().fg(Color::Grey).bg(Color::Black)
+    );
+    assert!(matches!(Theme::load(&path), Err(ThemeError::Io(_))));
+}
+
+#[test]
+fn styled_spans() {
+    let theme = Theme::parse("dark", DARK).unwrap();
+    let span = theme.span("error", "E");
+    let cell = span.get(0).unwrap();
+
+    assert_eq!(cell.fg, Color::Red);
+    assert!(cell.attrs.has(Attribute::Bold));
+    assert_eq!(theme.cell("missing", 'x'), Cell::new('x'));
+
+    let cmd = DrawableSpan::new((0, 0), vec![*cell]);
+
+    assert!(cmd.styled_content().contains("\u{1b}[1m"));
+}
+
+#[test]
+fn switch_and_rerender() {
+    let mut themes = ThemeRegistry::default();
+    let mut tabs = Tabs::new(vec!["A", "B"]);
+    let render = |tabs: &mut Tabs| {
+        let mut layer = Layer::default();
+
+        tabs.render(&mut layer, Rect(10, 1).annotate((0, 0)));
+
+        layer.inner()[0].inner().to_vec()
+    };
+
+    themes.register(Theme::parse("dark", DARK).unwrap());
+    themes.register(Theme::parse("light", "selection = fg:white bg:blue").unwrap());
+
+    assert_eq!(themes.names(), vec!["default", "dark", "light"]);
+    assert!(themes.take_changed());
+    assert!(!themes.select("missing"));
+    assert!(themes.select("dark"));
+    assert!(themes.take_changed());
+
+    themes.apply(&mut [&mut tabs]);
+
+    let cells = render(&mut tabs);
+
+    assert_eq!(
+        cells[1].bg,
+        Color::Rgb {
+            r: 0xff,
+            g: 0xcc,
+            b: 0x00
+        }
+    );
+    assert_eq!(cells[4].fg, Color::Grey);
+
+    themes.select("light");
+    themes.take_changed();
+    tabs.apply_theme(themes.active());
+
+    let cells = render(&mut tabs);
+
+    assert_eq!(cells[1].bg, Color::Blue);
+    // `text` is not defined in light theme, resets to the default style of widget
+    assert_eq!(cells[4].fg, Color::Reset);
+
+    assert!(themes.select("light"));
+    assert!(!themes.take_changed());
+
+    themes.register(Theme::parse("partial", "text = fg:red").unwrap());
+    themes.select("partial");
+    themes.apply(&mut [&mut tabs]);
+
+    let cells = render(&mut tabs);
+
+    // `selection` is not defined, so the colors of light theme don't remain
+    assert_eq!(cells[1].bg, Color::White);
+    assert_eq!(cells[1].fg, Color::Black);
+    assert_eq!(cells[4].fg, Color::Red);
+}
+
+#[test]
+fn apply_and_reset_styles() {
+    let mut themes = ThemeRegistry::default();
+    let mut gauge = Gauge::new(1.0);
+    let mut keymap = Keymap::default();
+    let mut palette = CommandPalette::default();
+    let mut dialog = Dialog::new("Quit?", vec!["Y", "N"]);
+
+    keymap.bind_with_help("normal", "q", (), "Quit").unwrap();
+
+    let mut help = keymap.help("normal");
+    let cells = |widget: &mut dyn Widget| {
+        let mut layer = Layer::default();
+
+        widget.render(&mut layer, Rect(8, 1).annotate((0, 0)));
+
+        layer.inner()[0].inner().to_vec()
+    };
+    let button_cell = |dialog: &Dialog| {
+        let mut canvas = Canvas::default();
+
+        dialog.show(&mut canvas, Rect(7, 5));
+        canvas.create_view().get_line(3)[1].unwrap()
+    };
+
+    themes.register(
+        Theme::parse(
+            "bright",
+            "progress = fg:red\nkey = fg:magenta\ncursor = bg:red\nselection = bg:blue",
+        )
+        .unwrap(),
+    );
+    themes.select("bright");
+    themes.apply(&mut [&mut gauge, &mut help, &mut palette, &mut dialog]);
+
+    assert_eq!(cells(&mut gauge)[0].fg, Color::Red);
+    assert_eq!(cells(&mut help)[0].fg, Color::Magenta);
+    assert_eq!(cells(&mut palette)[2].bg, Color::Red);
+    assert_eq!(button_cell(&dialog).bg, Color::Blue);
+
+    // Undefined names reset to the widget defaults
+    themes.select("default");
+    themes.apply(&mut [&mut gauge, &mut help, &mut palette, &mut dialog]);
+
+    assert_eq!(cells(&mut gauge)[0].fg, Color::Green);
+    assert_eq!(cells(&mut help)[0].fg, Color::Cyan);
+    assert_eq!(cells(&mut palette)[2].bg, Color::White);
+    assert_eq!(button_cell(&dialog).bg, Color::White);
+}
@@ -0,0 +1,166 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crossterm::event::KeyCode;
+use eired::{TreeNode, TreeView};
+use eired_display::Rect;
+
+use crate::helper::{key, render};
+
+#[test]
+fn guides_and_markers() {
+    let mut tree = TreeView::new(vec![
+        TreeNode::new("src", "src")
+            .children(vec![
+                TreeNode::new("bin", "bin")
+                    .children(vec![TreeNode::leaf("main", "main.rs")])
+                    .expanded(true),
+                TreeNode::leaf("lib", "lib.rs"),
+            ])
+            .expanded(true),
+        TreeNode::leaf("toml", "Cargo.toml"),
+    ]);
+
+    assert_eq!(
+        render(&mut tree, Rect(16, 5)),
+        vec![
+            "▼ src        ",
+            "├─▼ bin      ",
+            "│ └─  main.rs",
+            "└─  lib.rs   ",
+            "  Cargo.toml ",
+        ]
+    );
+}
+
+#[test]
+fn keyboard_navigation() {
+    let mut tree = TreeView::new(vec![
+        TreeNode::new("src", "src")
+            .children(vec![
+                TreeNode::new("bin", "bin")
+                    .children(vec![TreeNode::leaf("main", "main.rs")])
+                    .expanded(true),
+                TreeNode::leaf("lib", "lib.rs"),
+            ])
+            .expanded(true),
+        TreeNode::leaf("toml", "Cargo.toml"),
+    ]);
+
+    assert!(tree.input(key(KeyCode::Down)));
+    assert_eq!(tree.selected().unwrap(), &["src", "bin"]);
+
+    assert!(tree.input(key(KeyCode::Right)));
+    assert_eq!(tree.selected().unwrap(), &["src", "bin", "main"]);
+
+    assert!(tree.input(key(KeyCode::Left)));
+    assert_eq!(tree.selected().unwrap(), &["src", "bin"]);
+
+    assert!(tree.input(key(KeyCode::Left)));
+    assert!(!tree.node(&["src", "bin"]).unwrap().is_expanded());
+
+    assert!(tree.input(key(KeyCode::End)));
+    assert_eq!(tree.selected().unwrap(), &["toml"]);
+    assert!(!tree.input(key(KeyCode::Down)));
+}
+
+#[test]
+fn selection_survives_collapse() {
+    let mut tree = TreeView::new(vec![
+        TreeNode::new("src", "src")
+            .children(vec![
+                TreeNode::new("bin", "bin")
+                    .children(vec![TreeNode::leaf("main", "main.rs")])
+                    .expanded(true),
+                TreeNode::leaf("lib", "lib.rs"),
+            ])
+            .expanded(true),
+        TreeNode::leaf("toml", "Cargo.toml"),
+    ]);
+
+    assert!(tree.select(&["src", "bin", "main"]));
+
+    tree.select(&["src"]);
+    tree.collapse();
+
+    assert!(!tree.select(&["src", "bin", "main"]));
+
+    tree.select(&["src"]);
+    tree.expand();
+    tree.select(&["src", "bin", "main"]);
+    tree.select_parent();
+    tree.select_parent();
+    tree.collapse();
+
+    render(&mut tree, Rect(16, 2));
+
+    assert_eq!(tree.selected().unwrap(), &["src"]);
+}
+
+#[test]
+fn selection_survives_set_roots() {
+    let mut tree = TreeView::new(vec![
+        TreeNode::new("src", "src")
+            .children(vec![
+                TreeNode::new("bin", "bin")
+                    .children(vec![TreeNode::leaf("main", "main.rs")])
+                    .expanded(true),
+                TreeNode::leaf("lib", "lib.rs"),
+            ])
+            .expanded(true),
+        TreeNode::leaf("toml", "Cargo.toml"),
+    ]);
+
+    tree.select(&["toml"]);
+    tree.set_roots(vec![
+        TreeNode::leaf("readme", "README.md"),
+        TreeNode::leaf("toml", "Cargo.toml"),
+    ]);
+
+    assert_eq!(tree.selected().unwrap(), &["toml"]);
+}
+
+#[test]
+fn scroll_to_selected() {
+    let mut tree = TreeView::new(vec![
+        TreeNode::new("src", "src")
+            .children(vec![
+                TreeNode::new("bin", "bin")
+                    .children(vec![TreeNode::leaf("main", "main.rs")])
+                    .expanded(true),
+                TreeNode::leaf("lib", "lib.rs"),
+            ])
+            .expanded(true),
+        TreeNode::leaf("toml", "Cargo.toml"),
+    ]);
+
+    tree.select(&["toml"]);
+
+    assert_eq!(
+        render(&mut tree, Rect(16, 2)),
+        vec!["└─  lib.rs  ", "  Cargo.toml"]
+    );
+}
+
+#[test]
+fn lazy_children() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let mut tree = TreeView::new(vec![TreeNode::new("root", "root")]);
+
+    tree.set_loader(move |node: &TreeNode| {
+        counter.set(counter.get() + 1);
+
+        vec![TreeNode::leaf("child", format!("{}-child", node.label()))]
+    });
+    tree.select(&["root"]);
+
+    assert!(tree.node(&["root"]).unwrap().loaded_children().is_none());
+    assert!(tree.input(key(KeyCode::Enter)));
+    assert_eq!(tree.node(&["root", "child"]).unwrap().label(), "root-child");
+
+    tree.toggle();
+    tree.toggle();
+
+    assert_eq!(calls.get(), 1);
+}
@@ -1,4 +1,4 @@
-use eired_display::View;
+use eired_display::{Error, View};
 
 #[test]
 fn get_line() {
@@ -9,3 +9,33 @@ fn get_line() {
     assert_eq!(view.get_line(2), &[None, None, None]);
     assert_eq!(view.get_line(3), &[]);
 }
+
+#[test]
+fn try_new_view() {
+    assert!(View::try_new(0, 0, vec![]).is_ok());
+    assert_eq!(
+        View::try_new(3, 2, vec![None; 5]),
+        Err(Error::InvalidLength {
+            width: 3,
+            height: 2,
+            len: 5
+        })
+    );
+    assert_eq!(
+        Error::InvalidLength {
+            width: 3,
+            height: 2,
+            len: 5
+        }
+        .to_string(),
+        "expected 3x2 cells, but got 5 cells"
+    );
+}
+
+#[test]
+fn get_line_of_short_view() {
+    let view = View::new(4, 2, vec![None; 5]);
+
+    assert_eq!(view.get_line(0).len(), 4);
+    assert!(view.get_line(1).is_empty());
+}
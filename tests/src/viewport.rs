@@ -0,0 +1,88 @@
+use eired_display::{Annotate, Canvas, Cell, Layer, Rect, Span, Viewport};
+
+#[test]
+fn clamp_offset() {
+    let mut viewport = Viewport::new(10, 5);
+
+    assert_eq!(viewport.offset(), (0, 0));
+    assert_eq!(viewport.max_offset(), (0, 0));
+
+    viewport.set_content_size((30, 100));
+    viewport.set_offset((50, 50));
+
+    assert_eq!(viewport.max_offset(), (20, 95));
+    assert_eq!(viewport.offset(), (20, 50));
+
+    viewport.scroll_by((i32::MIN, i32::MAX));
+
+    assert_eq!(viewport.offset(), (0, 95));
+
+    viewport.set_size((10, 20));
+
+    assert_eq!(viewport.offset(), (0, 80));
+
+    viewport.set_content_size((5, 5));
+
+    assert_eq!(viewport.offset(), (0, 0));
+}
+
+#[test]
+fn ensure_visible() {
+    let mut viewport = Viewport::new(4, 3);
+
+    viewport.set_content_size((20, 20));
+    viewport.ensure_visible((10, 2));
+
+    assert_eq!(viewport.offset(), (7, 0));
+
+    viewport.ensure_visible((8, 10));
+
+    assert_eq!(viewport.offset(), (7, 8));
+
+    viewport.ensure_visible((2, 9));
+
+    assert_eq!(viewport.offset(), (2, 8));
+    assert_eq!(viewport.area(), Rect(4, 3).annotate((2, 8)));
+}
+
+#[test]
+fn scroll_canvas() {
+    let mut canvas = Canvas::default();
+    let mut layer = Layer::default();
+
+    for y in 0..10_000 {
+        layer.push_span_write(Span::from(format!("{y:04}")).annotate((0, y)));
+    }
+
+    canvas.insert(0, layer.annotate((0, 0)));
+
+    let mut viewport = Viewport::new(2, 2);
+
+    viewport.set_content_size(canvas.size());
+    viewport.scroll_by((1, 1234));
+
+    let view = viewport.create_view(&canvas);
+
+    assert_eq!(
+        view.get_line(0),
+        &[Some(Cell::new('2')), Some(Cell::new('3'))]
+    );
+    assert_eq!(
+        view.get_line(1),
+        &[Some(Cell::new('2')), Some(Cell::new('3'))]
+    );
+
+    viewport.scroll_by((2, 8765));
+
+    let view = viewport.create_view(&canvas);
+
+    assert_eq!(viewport.offset(), (2, 9998));
+    assert_eq!(
+        view.get_line(0),
+        &[Some(Cell::new('9')), Some(Cell::new('8'))]
+    );
+    assert_eq!(
+        view.get_line(1),
+        &[Some(Cell::new('9')), Some(Cell::new('9'))]
+    );
+}
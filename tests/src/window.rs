@@ -1,4 +1,5 @@
-use eired_display::{Annotate, Cell, View, Window};
+use crossterm::style::Color;
+use eired_display::{Annotate, Blend, Cell, VTerm, View, Window};
 
 #[test]
 fn create_vterm() {
@@ -59,3 +60,152 @@ fn create_vterm() {
         ]
     )
 }
+
+#[test]
+fn create_vterm_with_blend() {
+    let mut bar = Cell::new('|');
+
+    bar.blend.bg = true;
+
+    let window = Window::from_views(
+        3,
+        1,
+        vec![
+            View::new(
+                3,
+                1,
+                vec![
+                    Some(Cell::new_bg('a', Color::Blue)),
+                    Some(Cell::new_bg('b', Color::Blue)),
+                    Some(Cell::new_bg('c', Color::Blue)),
+                ],
+            )
+            .annotate((0, 0)),
+            View::new(
+                3,
+                1,
+                vec![
+                    Some(Cell::new_overlay_bg(Color::Red)),
+                    Some(bar),
+                    Some(Cell::transparent()),
+                ],
+            )
+            .annotate((0, 0)),
+        ],
+    );
+
+    let res = eired_display::create_virtual_terminal(window.annotate((0, 0))).into_inner();
+
+    assert_eq!(
+        res.to_vec(),
+        vec![
+            Some(Cell::new_bg('a', Color::Red)),
+            Some(Cell::new_bg('|', Color::Blue)),
+            Some(Cell::new_bg('c', Color::Blue)),
+        ]
+    );
+    assert!(res.iter().flatten().all(|cell| cell.blend == Blend::OPAQUE));
+}
+
+#[test]
+fn create_vterm_with_opacity() {
+    let red = Color::Rgb { r: 255, g: 0, b: 0 };
+    let window = Window::from_views(
+        2,
+        1,
+        vec![
+            View::new(
+                2,
+                1,
+                vec![Some(Cell::new_bg('a', red)), Some(Cell::new('b'))],
+            )
+            .annotate((0, 0)),
+            View::new(
+                2,
+                1,
+                vec![
+                    Some(Cell::new_bg('x', Color::Rgb { r: 0, g: 0, b: 255 })),
+                    Some(Cell::new_bg('y', Color::Blue)),
+                ],
+            )
+            .with_opacity(153)
+            .annotate((0, 0)),
+        ],
+    );
+
+    let res = eired_display::create_virtual_terminal(window.annotate((0, 0))).into_inner();
+
+    assert_eq!(
+        res.to_vec(),
+        vec![
+            Some(Cell::new_bg(
+                'x',
+                Color::Rgb {
+                    r: 102,
+                    g: 0,
+                    b: 153
+                }
+            )),
+            Some(Cell::new_bg('y', Color::Blue)),
+        ]
+    );
+}
+
+#[test]
+fn create_vterm_offscreen() {
+    let view = |ch: char| View::new(3, 2, vec![Some(Cell::new(ch)); 6]);
+    let window = Window::from_views(
+        3,
+        2,
+        vec![
+            view('.').annotate((0, 0)),
+            view('L').annotate_signed((-2, 0)),
+            view('T').annotate_signed((2, -1)),
+            view('X').annotate_signed((-3, -3)),
+        ],
+    );
+
+    assert_eq!(window.hit_test(0, 1), Some((1, (2, 1))));
+    assert_eq!(window.hit_test(2, 0), Some((2, (0, 1))));
+
+    let res = eired_display::create_virtual_terminal(window.annotate((0, 0))).into_inner();
+
+    assert_eq!(
+        res.iter().map(|c| c.unwrap().ch).collect::<String>(),
+        "L.TL.."
+    );
+}
+
+#[test]
+fn try_new_vterm() {
+    assert!(VTerm::try_new(2, 2, vec![None; 4]).is_ok());
+    assert!(VTerm::try_new(2, 2, vec![None; 3]).is_err());
+}
+
+#[test]
+fn large_vterm() {
+    let (width, height) = (400, 300);
+    let mut cells = vec![None; width as usize * height as usize];
+
+    cells[width as usize * 299 + 399] = Some(Cell::new('$'));
+
+    let window = Window::from_views(
+        width,
+        height,
+        vec![
+            View::try_new(width, height, cells)
+                .unwrap()
+                .annotate((0, 0)),
+            // Partly broken view is skipped, not panicked
+            View::new(4, 2, vec![Some(Cell::new('#')); 5]).annotate((396, 298)),
+        ],
+    );
+
+    let vterm = eired_display::create_virtual_terminal(window.annotate((0, 0)));
+
+    assert_eq!(vterm.inner().len(), 120_000);
+
+    let spans = eired_display::convert_to_spans(vterm);
+
+    assert_eq!(spans.len(), 2);
+}